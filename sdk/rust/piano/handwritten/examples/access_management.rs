@@ -3,8 +3,9 @@
 /// This example shows how to:
 /// - Grant access to users for specific resources
 /// - Check if users have access to resources
-/// - Revoke access from users
-/// - List access grants with filtering
+/// - Update and revoke accesses
+/// - List access grants
+/// - Grant access to many users at once
 use piano_handwritten_api::{publisher::access::*, PianoAPI};
 
 #[tokio::main]
@@ -21,21 +22,18 @@ async fn main() -> Result<(), piano_handwritten_api::Error> {
 
     // Example 1: Grant access to a user
     println!("\n1. Granting access to user...");
-    let grant_request = GrantAccessRequest::new(user_uid, resource_id)
-        .with_resource_type("article")
-        .with_expires(1999999999) // Set expiration timestamp
-        .with_custom_params(r#"{"source": "subscription", "plan": "premium"}"#);
+    let grant_request = GrantAccessRequest::new(user_uid, resource_id).with_expire_date(1999999999); // Set expiration timestamp
 
     match api.grant_access(&grant_request).await {
-        Ok(access_grant) => {
+        Ok(accesses) => {
             println!("✓ Access granted successfully!");
-            println!("  Grant ID: {}", access_grant.access_grant_id());
-            println!("  User UID: {}", access_grant.uid());
-            println!("  Resource: {}", access_grant.resource_id());
-            println!("  Type: {}", access_grant.resource_type().unwrap_or("N/A"));
-            println!("  Status: {}", access_grant.status().unwrap_or("N/A"));
-            if let Some(expires) = access_grant.expires_date() {
-                println!("  Expires: {}", expires);
+            for access in &accesses {
+                println!("  Access ID: {}", access.access_id());
+                println!("  User UID: {}", access.user().uid());
+                println!("  Resource: {}", access.resource().rid());
+                if let Some(expires) = access.expire_date() {
+                    println!("  Expires: {}", expires);
+                }
             }
         }
         Err(e) => println!("✗ Failed to grant access: {}", e),
@@ -58,8 +56,7 @@ async fn main() -> Result<(), piano_handwritten_api::Error> {
     println!("\n3. Listing user's access grants...");
     let list_request = ListAccessRequest::new()
         .with_limit(10)
-        .with_resource_type("article")
-        .with_status("ACTIVE");
+        .with_expand_bundled(true);
 
     match api.list_user_access(user_uid, &list_request).await {
         Ok(results) => {
@@ -67,60 +64,31 @@ async fn main() -> Result<(), piano_handwritten_api::Error> {
             println!("  Total grants: {}", results.total);
             println!("  Showing {} grants:", results.count);
 
-            for (i, grant) in results.value.access_grants.iter().enumerate() {
+            for (i, access) in results.value.accesses.iter().enumerate() {
                 println!(
                     "    {}. Resource: {} ({})",
                     i + 1,
-                    grant.resource_id(),
-                    grant.resource_type().unwrap_or("unknown")
+                    access.resource().rid(),
+                    access.resource().name().unwrap_or("unknown")
                 );
                 println!(
-                    "       Status: {}, Active: {}",
-                    grant.status().unwrap_or("unknown"),
-                    grant.is_active()
+                    "       Granted: {}, Active: {}",
+                    access.is_granted(),
+                    access.is_active()
                 );
             }
         }
         Err(e) => println!("✗ Failed to list access grants: {}", e),
     }
 
-    // Example 4: List all access grants with filtering
-    println!("\n4. Listing all access grants...");
-    let list_all_request = ListAllAccessRequest::new()
-        .with_limit(20)
-        .with_resource_type("article")
-        .with_status("ACTIVE");
-
-    match api.list_all_access(&list_all_request).await {
-        Ok(results) => {
-            println!("✓ All access grants retrieved!");
-            println!("  Total grants: {}", results.total);
-            println!("  Active article grants: {}", results.count);
-
-            // Group by user for better display
-            let mut user_grants: std::collections::HashMap<String, Vec<&AccessGrant>> =
-                std::collections::HashMap::new();
-
-            for grant in &results.value.access_grants {
-                user_grants
-                    .entry(grant.uid().to_string())
-                    .or_insert_with(Vec::new)
-                    .push(grant);
-            }
-
-            for (uid, grants) in user_grants.iter().take(5) {
-                // Show first 5 users
-                println!("    User {}: {} grants", uid, grants.len());
-                for grant in grants.iter().take(3) {
-                    // Show first 3 grants per user
-                    println!("      - {}", grant.resource_id());
-                }
-            }
-        }
-        Err(e) => println!("✗ Failed to list all access grants: {}", e),
+    // Example 4: Count active accesses
+    println!("\n4. Counting active accesses...");
+    match api.count_active_access(resource_id).await {
+        Ok(count) => println!("✓ {} users with access to {}", count, resource_id),
+        Err(e) => println!("✗ Failed to count active accesses: {}", e),
     }
 
-    // Example 5: Grant temporary access
+    // Example 5: Grant temporary access, then extend it
     println!("\n5. Granting temporary access...");
     let temp_resource = "premium_article_456";
     let expires_in_hour = std::time::SystemTime::now()
@@ -129,43 +97,70 @@ async fn main() -> Result<(), piano_handwritten_api::Error> {
         .as_secs() as i64
         + 3600; // 1 hour from now
 
-    let temp_grant_request = GrantAccessRequest::new(user_uid, temp_resource)
-        .with_resource_type("premium_article")
-        .with_expires(expires_in_hour)
-        .with_custom_params(r#"{"type": "trial", "duration": "1h"}"#);
+    let temp_grant_request =
+        GrantAccessRequest::new(user_uid, temp_resource).with_expire_date(expires_in_hour);
 
-    match api.grant_access(&temp_grant_request).await {
-        Ok(access_grant) => {
+    let temp_access = match api.grant_access(&temp_grant_request).await {
+        Ok(accesses) => {
             println!("✓ Temporary access granted!");
-            println!("  Resource: {}", access_grant.resource_id());
-            println!("  Expires in 1 hour");
-            println!("  Is currently active: {}", access_grant.is_active());
+            accesses.into_iter().next()
+        }
+        Err(e) => {
+            println!("✗ Failed to grant temporary access: {}", e);
+            None
+        }
+    };
+
+    if let Some(access) = &temp_access {
+        match api
+            .update_access(access.access_id(), Some(expires_in_hour + 86400))
+            .await
+        {
+            Ok(access) => println!("✓ Access extended until {:?}", access.expire_date()),
+            Err(e) => println!("✗ Failed to extend access: {}", e),
         }
-        Err(e) => println!("✗ Failed to grant temporary access: {}", e),
     }
 
     // Example 6: Revoke access
     println!("\n6. Revoking access...");
-    let revoke_request =
-        RevokeAccessRequest::new(user_uid, temp_resource).with_resource_type("premium_article");
-
-    match api.revoke_access(&revoke_request).await {
-        Ok(()) => {
-            println!("✓ Access revoked successfully!");
-
-            // Verify access was revoked
-            match api.check_access(user_uid, temp_resource).await {
-                Ok(has_access) => {
-                    if has_access {
-                        println!("  ⚠ User still has access (may take time to propagate)");
-                    } else {
-                        println!("  ✓ Access successfully removed");
+    if let Some(access) = temp_access {
+        match api.revoke_access(access.access_id()).await {
+            Ok(_) => {
+                println!("✓ Access revoked successfully!");
+
+                // Verify access was revoked
+                match api.check_access(user_uid, temp_resource).await {
+                    Ok(has_access) => {
+                        if has_access {
+                            println!("  ⚠ User still has access (may take time to propagate)");
+                        } else {
+                            println!("  ✓ Access successfully removed");
+                        }
                     }
+                    Err(e) => println!("  ? Could not verify revocation: {}", e),
                 }
-                Err(e) => println!("  ? Could not verify revocation: {}", e),
             }
+            Err(e) => println!("✗ Failed to revoke access: {}", e),
         }
-        Err(e) => println!("✗ Failed to revoke access: {}", e),
+    }
+
+    // Example 7: Grant access to many users, sending them an invitation
+    println!("\n7. Granting access to many users...");
+    let resources = [resource_id];
+    let emails = ["jane@example.com", "john@example.com"];
+    let bulk_request = GrantAccessToUsersRequest::by_emails(&resources, &emails)
+        .with_send_email(true)
+        .with_message("Your company gave you access to our premium content");
+
+    let report = api.grant_access_to_users(&bulk_request).await;
+    println!("✓ {} accesses granted", report.granted());
+    for failure in report.failures() {
+        println!(
+            "✗ Failed to grant {} to {}: {:?}",
+            failure.rid(),
+            failure.user(),
+            failure.outcome()
+        );
     }
 
     println!("\n✓ Example completed!");
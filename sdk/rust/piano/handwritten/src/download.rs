@@ -0,0 +1,99 @@
+//! Helpers for files that Piano makes available through a download URL,
+//! such as bulk user import reports and exports.
+
+use std::pin::Pin;
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::PianoAPI;
+
+/// Stream of raw file chunks.
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, crate::Error>> + Send>>;
+
+impl PianoAPI {
+    /// Stream the file at a download URL returned by the Piano API.
+    ///
+    /// Download URLs are pre-signed, so the API token is not sent along.
+    pub(crate) async fn download_stream(&self, url: &str) -> Result<ByteStream, crate::Error> {
+        let response = self
+            .download_client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(response
+            .bytes_stream()
+            .map_err(|e| Box::new(e) as crate::Error)
+            .boxed())
+    }
+
+    /// Download the file at a download URL returned by the Piano API and parse it as CSV.
+    pub(crate) async fn download_csv<T: DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<Vec<T>, crate::Error> {
+        let body = self
+            .download_client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        parse_csv(&body)
+    }
+}
+
+/// Parse CSV with a header row into typed rows.
+///
+/// Columns are matched to fields by header name, so rows can also be read as
+/// `HashMap<String, String>` when the columns are not known in advance.
+pub(crate) fn parse_csv<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<T>, crate::Error> {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(data)
+        .deserialize()
+        .map(|row| row.map_err(|e| Box::new(e) as crate::Error))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::parse_csv;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        email: String,
+        status: String,
+    }
+
+    #[test]
+    fn sanity_check_parse_csv() {
+        let data = "\u{feff}email,status\na@example.com,created\nb@example.com, failed \n";
+        let rows = parse_csv::<Row>(data.as_bytes()).expect("OK");
+        assert_eq!(
+            rows,
+            vec![
+                Row {
+                    email: "a@example.com".to_string(),
+                    status: "created".to_string()
+                },
+                Row {
+                    email: "b@example.com".to_string(),
+                    status: "failed".to_string()
+                },
+            ]
+        );
+
+        let rows = parse_csv::<HashMap<String, String>>(data.as_bytes()).expect("OK");
+        assert_eq!(rows[1]["status"], "failed");
+    }
+}
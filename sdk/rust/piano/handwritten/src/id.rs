@@ -0,0 +1,260 @@
+//! # Piano ID API
+//!
+//! Piano ID is the identity provider of Piano. It is served under its own base URL
+//! (e.g. `https://sandbox.piano.io/id/api/v1`), but authenticates with the application ID
+//! and API token of the Publisher API, so [`PianoIdAPI`] is created from a [`PianoAPI`].
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{id::{identity::*, user::*}, PianoAPI};
+//!
+//! # async fn example(access_token: &str) -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//! let id = api.id();
+//!
+//! // Log a user in and keep the refresh token
+//! let token = id.login(&LoginRequest::new("user@example.com", "password")).await?;
+//! let refresh_token = token.refresh_token().map(ToString::to_string);
+//!
+//! // Check the access token sent by a browser
+//! let token = id.verify_token(access_token).await?;
+//!
+//! // Load the profile of a user
+//! let user = id.get_user(&GetIdUserRequest::by_email("user@example.com")).await?;
+//! println!("{:?}", user.map(|user| user.uid().to_string()));
+//! # Ok(())
+//! # }
+//! ```
+
+/// Custom fields
+///
+/// - Create and update custom field definitions
+/// - Update custom field values of a user
+pub mod custom_field;
+
+/// Password login, registration and password management
+///
+/// - Log in users by password and log them out
+/// - Register users
+/// - Create, set and reset passwords
+pub mod identity;
+
+/// Offline verification of access tokens
+///
+/// - Verify the signature of access tokens with configured keys
+/// - Check the expiration, issuer and audience claims
+/// - Fall back to Piano ID for the tokens signed with unknown keys
+pub mod jwt;
+
+/// Social login
+///
+/// - Initiate social login
+/// - Exchange social codes for access tokens
+/// - Merge social and Piano ID accounts
+pub mod social;
+
+/// Access tokens
+///
+/// - Verify and refresh access tokens
+/// - Exchange OAuth codes
+pub mod token;
+
+/// User profiles
+///
+/// - Get user profiles
+/// - Get and set user aliases
+/// - Get the audit log of a user
+pub mod user;
+
+use std::io::ErrorKind;
+
+use serde::{Deserialize, Serializer};
+
+use crate::PianoAPI;
+
+/// Client of the Piano ID API sharing the configuration of a [`PianoAPI`].
+#[derive(Clone)]
+pub struct PianoIdAPI {
+    app_id: String,
+    endpoint: String,
+    token: String,
+    client: reqwest::Client,
+}
+
+impl PianoIdAPI {
+    /// Create a Piano ID client from a Publisher API client and the Piano ID base URL
+    /// (e.g. `https://id.piano.io/id/api/v1`).
+    pub fn new(api: &PianoAPI, endpoint: &str) -> Self {
+        Self {
+            app_id: api.app_id.clone(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            token: api.token.clone(),
+            client: api.client.clone(),
+        }
+    }
+
+    /// Create a Piano ID client from the environment variables of [`PianoAPI::from_env`].
+    ///
+    /// The Piano ID base URL is read from `PIANO_ID_ENDPOINT`, or derived from
+    /// `PIANO_ENDPOINT` if it is not set.
+    pub fn from_env() -> Self {
+        let api = PianoAPI::from_env();
+        match std::env::var("PIANO_ID_ENDPOINT") {
+            Ok(endpoint) => Self::new(&api, &endpoint),
+            Err(_) => api.id(),
+        }
+    }
+
+    /// Get the Piano ID base URL
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+impl std::fmt::Debug for PianoIdAPI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PianoIdAPI")
+            .field("app_id", &self.app_id)
+            .field("endpoint", &self.endpoint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PianoAPI {
+    /// Create a Piano ID client sharing the configuration of this client.
+    ///
+    /// The Piano ID base URL is derived from the Publisher API endpoint: `/api/v3` becomes
+    /// `/id/api/v1` and a host starting with `api` (e.g. `api-eu.piano.io`) is replaced by
+    /// the matching `id` host (e.g. `id-eu.piano.io`). Use [`PianoIdAPI::new`] if your
+    /// Piano ID base URL does not follow this scheme.
+    pub fn id(&self) -> PianoIdAPI {
+        PianoIdAPI::new(self, &id_endpoint(&self.endpoint))
+    }
+}
+
+/// Derive the Piano ID base URL from the Publisher API endpoint
+fn id_endpoint(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    let (scheme, rest) = endpoint.split_once("://").unwrap_or(("https", endpoint));
+    let (host, _) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = match host.strip_prefix("api") {
+        Some(suffix) if suffix.starts_with('.') || suffix.starts_with('-') => {
+            format!("id{suffix}")
+        }
+        _ => host.to_string(),
+    };
+    format!("{scheme}://{host}/id/api/v1")
+}
+
+/// Error returned by Piano ID
+#[derive(Debug, Deserialize, Clone)]
+struct IdError {
+    code: i64,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default, rename = "localizedMessage")]
+    localized_message: Option<String>,
+}
+
+/// Check the response of an endpoint without response body.
+///
+/// Piano ID answers these endpoints with an empty body, or with an error object
+/// (`{"code": 403, "message": "..."}`) on failure.
+async fn expect_empty(response: reqwest::Response) -> Result<(), crate::Error> {
+    let status = response.status();
+    let body = response.text().await?;
+    check_error(status, &body)
+}
+
+/// Read the response of an endpoint whose response body is not documented.
+async fn expect_json(response: reqwest::Response) -> Result<serde_json::Value, crate::Error> {
+    let status = response.status();
+    let body = response.text().await?;
+    check_error(status, &body)?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+fn check_error(status: reqwest::StatusCode, body: &str) -> Result<(), crate::Error> {
+    let error = serde_json::from_str::<IdError>(body)
+        .ok()
+        .filter(|error| error.code != 0 && error.code != 200);
+    match error {
+        Some(error) => {
+            let message = error
+                .message
+                .or(error.localized_message)
+                .unwrap_or_default();
+            Err(Box::new(std::io::Error::new(
+                ErrorKind::Other,
+                format!("{}: {message}", error.code),
+            )))
+        }
+        None if status.is_success() => Ok(()),
+        None => Err(Box::new(std::io::Error::new(
+            ErrorKind::Other,
+            format!("{}: {body}", status.as_u16()),
+        ))),
+    }
+}
+
+/// Serialize custom field values as the JSON dictionary Piano ID expects in a form parameter
+fn serialize_custom_field_values<S>(
+    values: &Option<&[(&str, &str)]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match values {
+        Some(values) => {
+            let map: serde_json::Map<String, serde_json::Value> = values
+                .iter()
+                .map(|(key, value)| (key.to_string(), serde_json::Value::from(*value)))
+                .collect();
+            serializer.serialize_str(&serde_json::Value::Object(map).to_string())
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_endpoint() {
+        assert_eq!(
+            id_endpoint("https://sandbox.piano.io/api/v3"),
+            "https://sandbox.piano.io/id/api/v1"
+        );
+        assert_eq!(
+            id_endpoint("https://api.piano.io/api/v3/"),
+            "https://id.piano.io/id/api/v1"
+        );
+        assert_eq!(
+            id_endpoint("https://api-eu.piano.io/api/v3"),
+            "https://id-eu.piano.io/id/api/v1"
+        );
+    }
+
+    #[test]
+    fn test_debug_omits_token() {
+        let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "AID", "secret");
+        assert!(!format!("{api:?}").contains("secret"));
+        assert!(!format!("{:?}", api.id()).contains("secret"));
+    }
+
+    #[test]
+    fn test_check_error() {
+        assert!(check_error(reqwest::StatusCode::OK, "").is_ok());
+        assert!(check_error(reqwest::StatusCode::OK, r#"{"LogoutResponse":{"jti":"J"}}"#).is_ok());
+        assert!(check_error(reqwest::StatusCode::OK, r#"{"code":0}"#).is_ok());
+        assert!(check_error(
+            reqwest::StatusCode::OK,
+            r#"{"code":403,"message":"Invalid token"}"#
+        )
+        .is_err());
+        assert!(check_error(reqwest::StatusCode::BAD_GATEWAY, "").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{
+    id::{expect_empty, PianoIdAPI},
+    PianoRequest,
+};
+
+impl PianoIdAPI {
+    /// Update custom field values of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fform&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_custom_field_values(
+        &self,
+        params: &UpdateCustomFieldValuesRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let response = self
+            .client
+            .post(format!("{}/publisher/form", self.endpoint))
+            .json(&req)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+
+    /// Create or update custom field definitions.
+    ///
+    /// With `global_validation`, field names are validated across all the applications of
+    /// the global mode.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FcustomField&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn upsert_custom_field_definitions(
+        &self,
+        definitions: &[CustomFieldDefinition],
+        global_validation: bool,
+    ) -> Result<(), crate::Error> {
+        let response = self
+            .client
+            .post(format!("{}/publisher/customField", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(&[("global_validation", global_validation)])
+            .json(definitions)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+}
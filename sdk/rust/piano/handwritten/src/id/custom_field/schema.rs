@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to update custom field values of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fform&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub struct UpdateCustomFieldValuesRequest<'a> {
+    /// The user's ID
+    pub uid: &'a str,
+    /// Custom field values by field name. Multi-select values are JSON arrays.
+    #[serde(serialize_with = "crate::id::serialize_custom_field_values")]
+    pub custom_fields: Option<&'a [(&'a str, &'a str)]>,
+    /// The public ID of the form, whose validators are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_id: Option<&'a str>,
+}
+
+impl<'a> UpdateCustomFieldValuesRequest<'a> {
+    /// Create a new update custom field values request
+    pub fn new(uid: &'a str, custom_fields: &'a [(&'a str, &'a str)]) -> Self {
+        Self {
+            uid,
+            custom_fields: Some(custom_fields),
+            form_id: None,
+        }
+    }
+
+    /// Set the public ID of the form
+    pub fn with_form_id(mut self, form_id: &'a str) -> Self {
+        self.form_id = Some(form_id);
+        self
+    }
+}
+
+/// Data type of a custom field
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FieldDataType {
+    Text,
+    IsoDate,
+    Boolean,
+    Number,
+    SingleSelectList,
+    MultiSelectList,
+    PhoneNumber,
+}
+
+/// Kind of custom field validator
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FieldValidatorType {
+    Nop,
+    Min,
+    Max,
+    Regexp,
+    MinAge,
+    StrLength,
+    DateAfter,
+    DateBefore,
+    OneOf,
+    MultipleOf,
+    Email,
+    Whitelist,
+    Blacklist,
+    MinChosen,
+    MaxChosen,
+    PhoneNumber,
+    Step,
+}
+
+/// Validator of a custom field
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldValidator {
+    /// The kind of validator
+    #[serde(rename = "type")]
+    pub validator_type: FieldValidatorType,
+    /// The parameters of the validator (e.g. `{"min": 3}`)
+    #[serde(default)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+    /// The message shown when the validation fails
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl FieldValidator {
+    /// Create a validator without parameters
+    pub fn new(validator_type: FieldValidatorType) -> Self {
+        Self {
+            validator_type,
+            params: serde_json::Map::new(),
+            error_message: None,
+        }
+    }
+
+    /// Add a parameter
+    pub fn with_param(mut self, name: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// Set the message shown when the validation fails
+    pub fn with_error_message(mut self, error_message: &str) -> Self {
+        self.error_message = Some(error_message.to_string());
+        self
+    }
+}
+
+/// Definition of a custom field
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FcustomField&spec=piano-id)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldDefinition {
+    /// The field ID
+    pub field_name: String,
+    /// The field title
+    pub title: String,
+    /// The data type of the field
+    pub data_type: FieldDataType,
+    /// The field description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Whether users can edit the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editable: Option<bool>,
+    /// Whether the field is required by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_by_default: Option<bool>,
+    /// Whether the field is archived
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// Whether the field is hidden
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    /// The options of a select list field
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+    /// The options shown first in a select list field
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub favourite_options: Vec<String>,
+    /// The validators of the field
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validators: Vec<FieldValidator>,
+    /// The default sort order of the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<i64>,
+}
+
+impl CustomFieldDefinition {
+    /// Create a new custom field definition
+    pub fn new(field_name: &str, title: &str, data_type: FieldDataType) -> Self {
+        Self {
+            field_name: field_name.to_string(),
+            title: title.to_string(),
+            data_type,
+            comment: None,
+            editable: None,
+            required_by_default: None,
+            archived: None,
+            hidden: None,
+            options: vec![],
+            favourite_options: vec![],
+            validators: vec![],
+            default_sort_order: None,
+        }
+    }
+
+    /// Set the field description
+    pub fn with_comment(mut self, comment: &str) -> Self {
+        self.comment = Some(comment.to_string());
+        self
+    }
+
+    /// Set whether users can edit the field
+    pub fn with_editable(mut self, editable: bool) -> Self {
+        self.editable = Some(editable);
+        self
+    }
+
+    /// Set whether the field is required by default
+    pub fn with_required_by_default(mut self, required_by_default: bool) -> Self {
+        self.required_by_default = Some(required_by_default);
+        self
+    }
+
+    /// Set whether the field is archived
+    pub fn with_archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    /// Set whether the field is hidden
+    pub fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+
+    /// Set the options of a select list field
+    pub fn with_options(mut self, options: &[&str]) -> Self {
+        self.options = options.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Add a validator
+    pub fn with_validator(mut self, validator: FieldValidator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Set the default sort order
+    pub fn with_default_sort_order(mut self, default_sort_order: i64) -> Self {
+        self.default_sort_order = Some(default_sort_order);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_custom_field_definition() {
+        let definition =
+            CustomFieldDefinition::new("interests", "Interests", FieldDataType::MultiSelectList)
+                .with_options(&["sports", "news"])
+                .with_validator(
+                    FieldValidator::new(FieldValidatorType::MaxChosen).with_param("max", 2),
+                );
+        let value = serde_json::to_value(&definition).expect("OK");
+        assert_eq!(
+            value,
+            json!({
+                "fieldName": "interests",
+                "title": "Interests",
+                "dataType": "MULTI_SELECT_LIST",
+                "options": ["sports", "news"],
+                "validators": [{ "type": "MAX_CHOSEN", "params": { "max": 2 } }],
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<CustomFieldDefinition>(value).expect("OK"),
+            definition
+        );
+    }
+
+    #[test]
+    fn test_update_custom_field_values_request() {
+        let values = [("newsletter", "true"), ("interests", r#"["sports"]"#)];
+        let request = UpdateCustomFieldValuesRequest::new("UID", &values);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "uid": "UID",
+                "custom_fields": r#"{"interests":"[\"sports\"]","newsletter":"true"}"#,
+            })
+        );
+    }
+}
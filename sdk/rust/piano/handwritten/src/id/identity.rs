@@ -0,0 +1,161 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{
+    id::{expect_empty, token::Token, token::TokenResult, PianoIdAPI},
+    PianoRequest, PianoResponse,
+};
+
+impl PianoIdAPI {
+    /// Log in a user by email and password.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Flogin&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    pub async fn login(&self, params: &LoginRequest<'_>) -> Result<Token, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/identity/login", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+
+    /// Register a user and log them in.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fregister&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    pub async fn register(&self, params: &RegisterRequest<'_>) -> Result<Token, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/identity/register", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+
+    /// Create or update the password of an existing user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fpassword&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    pub async fn update_password(
+        &self,
+        params: &UpdatePasswordRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let response = self
+            .client
+            .post(format!("{}/publisher/identity/password", self.endpoint))
+            .json(&req)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+
+    /// Set the password of a user with the token of a password reset email.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fset~2Fpassword&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn set_password(
+        &self,
+        reset_password_token: &str,
+        password: &str,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: SetPasswordRequest {
+                reset_password_token,
+                password,
+            },
+        };
+        let response = self
+            .client
+            .post(format!("{}/publisher/identity/set/password", self.endpoint))
+            .json(&req)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+
+    /// Send a password reset email to a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Freset~2Fpassword&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn reset_password(
+        &self,
+        params: &ResetPasswordRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let response = self
+            .client
+            .post(format!("{}/publisher/reset/password", self.endpoint))
+            .json(&req)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+
+    /// Set the email confirmation state of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fdoi~2Fstatus&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_email_confirmation_state(
+        &self,
+        uid: &str,
+        email_confirmation_state: EmailConfirmationState,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: EmailConfirmationStateRequest {
+                uid,
+                email_confirmation_state,
+            },
+        };
+        let response = self
+            .client
+            .post(format!("{}/publisher/identity/doi/status", self.endpoint))
+            .json(&req)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+
+    /// Log out a user, revoking their access token.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogout&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn logout(&self, token: &str) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: LogoutRequest { token },
+        };
+        let response = self
+            .client
+            .post(format!("{}/publisher/logout", self.endpoint))
+            .json(&req)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+}
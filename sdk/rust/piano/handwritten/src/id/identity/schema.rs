@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+/// Request to log in a user by password
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Flogin&spec=piano-id)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginRequest<'a> {
+    /// The user's email address
+    pub email: &'a str,
+    /// The user's password
+    pub password: &'a str,
+    /// Whether to issue a long-lived session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stay_logged_in: Option<bool>,
+    /// The alias to log in with instead of the email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias_name: Option<&'a str>,
+    /// The login type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_type: Option<&'a str>,
+}
+
+impl<'a> LoginRequest<'a> {
+    /// Create a new login request
+    pub fn new(email: &'a str, password: &'a str) -> Self {
+        Self {
+            email,
+            password,
+            stay_logged_in: None,
+            alias_name: None,
+            login_type: None,
+        }
+    }
+
+    /// Set whether to issue a long-lived session
+    pub fn with_stay_logged_in(mut self, stay_logged_in: bool) -> Self {
+        self.stay_logged_in = Some(stay_logged_in);
+        self
+    }
+
+    /// Set the alias to log in with
+    pub fn with_alias_name(mut self, alias_name: &'a str) -> Self {
+        self.alias_name = Some(alias_name);
+        self
+    }
+
+    /// Set the login type
+    pub fn with_login_type(mut self, login_type: &'a str) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+}
+
+/// Request to register a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fregister&spec=piano-id)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterRequest<'a> {
+    /// The user's email address
+    pub email: &'a str,
+    /// The user's password (omitted for passwordless registration)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<&'a str>,
+    /// The user's first name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    /// The user's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    /// The user's phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    /// The public ID of the registration form, whose validators are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_id: Option<&'a str>,
+    /// Custom field values by field name
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::id::serialize_custom_field_values"
+    )]
+    pub custom_fields: Option<&'a [(&'a str, &'a str)]>,
+    /// User aliases by alias name
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub aliases: HashMap<&'a str, &'a str>,
+    /// Whether the user registers without password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passwordless: Option<bool>,
+    /// Whether the email address is already confirmed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmed_email: Option<bool>,
+}
+
+impl<'a> RegisterRequest<'a> {
+    /// Create a new register request
+    pub fn new(email: &'a str) -> Self {
+        Self {
+            email,
+            password: None,
+            first_name: None,
+            last_name: None,
+            phone: None,
+            form_id: None,
+            custom_fields: None,
+            aliases: HashMap::new(),
+            passwordless: None,
+            confirmed_email: None,
+        }
+    }
+
+    /// Set the password
+    pub fn with_password(mut self, password: &'a str) -> Self {
+        self.password = Some(password);
+        self
+    }
+
+    /// Set the first name
+    pub fn with_first_name(mut self, first_name: &'a str) -> Self {
+        self.first_name = Some(first_name);
+        self
+    }
+
+    /// Set the last name
+    pub fn with_last_name(mut self, last_name: &'a str) -> Self {
+        self.last_name = Some(last_name);
+        self
+    }
+
+    /// Set the phone number
+    pub fn with_phone(mut self, phone: &'a str) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    /// Set the public ID of the registration form
+    pub fn with_form_id(mut self, form_id: &'a str) -> Self {
+        self.form_id = Some(form_id);
+        self
+    }
+
+    /// Set the custom field values
+    pub fn with_custom_fields(mut self, custom_fields: &'a [(&'a str, &'a str)]) -> Self {
+        self.custom_fields = Some(custom_fields);
+        self
+    }
+
+    /// Add an alias
+    pub fn with_alias(mut self, name: &'a str, value: &'a str) -> Self {
+        self.aliases.insert(name, value);
+        self
+    }
+
+    /// Set whether the user registers without password
+    pub fn with_passwordless(mut self, passwordless: bool) -> Self {
+        self.passwordless = Some(passwordless);
+        self
+    }
+
+    /// Set whether the email address is already confirmed
+    pub fn with_confirmed_email(mut self, confirmed_email: bool) -> Self {
+        self.confirmed_email = Some(confirmed_email);
+        self
+    }
+}
+
+/// Request to create or update the password of an existing user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fpassword&spec=piano-id)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePasswordRequest<'a> {
+    /// The user's ID
+    pub uid: &'a str,
+    /// The new password
+    pub password: &'a str,
+    /// The current password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_password: Option<&'a str>,
+    /// Whether to update the password without the current one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_update: Option<bool>,
+}
+
+impl<'a> UpdatePasswordRequest<'a> {
+    /// Create a new update password request
+    pub fn new(uid: &'a str, password: &'a str) -> Self {
+        Self {
+            uid,
+            password,
+            current_password: None,
+            force_update: None,
+        }
+    }
+
+    /// Set the current password
+    pub fn with_current_password(mut self, current_password: &'a str) -> Self {
+        self.current_password = Some(current_password);
+        self
+    }
+
+    /// Set whether to update the password without the current one
+    pub fn with_force_update(mut self, force_update: bool) -> Self {
+        self.force_update = Some(force_update);
+        self
+    }
+}
+
+/// Request to set a password with a reset password token
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fset~2Fpassword&spec=piano-id)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetPasswordRequest<'a> {
+    pub reset_password_token: &'a str,
+    pub password: &'a str,
+}
+
+/// Request to send a password reset email
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Freset~2Fpassword&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub struct ResetPasswordRequest<'a> {
+    /// The user's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    /// The user's phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    /// The URL of the reset password page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reset_password_url: Option<&'a str>,
+}
+
+impl<'a> ResetPasswordRequest<'a> {
+    /// Create a request for the user with the given email address
+    pub fn by_email(email: &'a str) -> Self {
+        Self {
+            email: Some(email),
+            phone: None,
+            reset_password_url: None,
+        }
+    }
+
+    /// Create a request for the user with the given phone number
+    pub fn by_phone(phone: &'a str) -> Self {
+        Self {
+            email: None,
+            phone: Some(phone),
+            reset_password_url: None,
+        }
+    }
+
+    /// Set the URL of the reset password page
+    pub fn with_reset_password_url(mut self, reset_password_url: &'a str) -> Self {
+        self.reset_password_url = Some(reset_password_url);
+        self
+    }
+}
+
+/// Email confirmation state which can be set by the publisher
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EmailConfirmationState {
+    Confirmed,
+    PreConfirmed,
+}
+
+/// Request to set the email confirmation state of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fidentity~2Fdoi~2Fstatus&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub(super) struct EmailConfirmationStateRequest<'a> {
+    pub uid: &'a str,
+    pub email_confirmation_state: EmailConfirmationState,
+}
+
+/// Request to log out a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogout&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub(super) struct LogoutRequest<'a> {
+    pub token: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_login_request() {
+        let request = LoginRequest::new("user@example.com", "password").with_stay_logged_in(true);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "email": "user@example.com",
+                "password": "password",
+                "stayLoggedIn": true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_register_request() {
+        let custom_fields = [("newsletter", "true")];
+        let request = RegisterRequest::new("user@example.com")
+            .with_first_name("Jane")
+            .with_custom_fields(&custom_fields)
+            .with_alias("crm_id", "42")
+            .with_passwordless(true);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "email": "user@example.com",
+                "firstName": "Jane",
+                "customFields": r#"{"newsletter":"true"}"#,
+                "aliases": { "crm_id": "42" },
+                "passwordless": true,
+            })
+        );
+    }
+}
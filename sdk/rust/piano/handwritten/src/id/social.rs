@@ -0,0 +1,93 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{
+    id::{
+        token::{Token, TokenResult},
+        PianoIdAPI,
+    },
+    PianoRequest, PianoResponse,
+};
+
+impl PianoIdAPI {
+    /// Initiate social login and return the URL of the social network to redirect the user to.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogin~2Fsocial&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn initiate_social_login(
+        &self,
+        params: &SocialLoginRequest<'_>,
+    ) -> Result<String, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: SocialRequest {
+                api_token: &self.token,
+                inner: params,
+            },
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/login/social", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<SocialLinkResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_uri())
+    }
+
+    /// Exchange the `response_id` of the social login redirect for an access token.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogin~2Fsocial~2Fcode&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn exchange_social_code(&self, response_id: &str) -> Result<Token, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: SocialRequest {
+                api_token: &self.token,
+                inner: SocialCodeRequest { response_id },
+            },
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/login/social/code", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+
+    /// Merge a social account into an existing Piano ID account and log the user in.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogin~2Fsocial~2FcodeConfirm&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    pub async fn confirm_social_linking(
+        &self,
+        params: &ConfirmSocialLinkingRequest<'_>,
+    ) -> Result<Token, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: SocialRequest {
+                api_token: &self.token,
+                inner: params,
+            },
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/login/social/codeConfirm",
+                self.endpoint
+            ))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+}
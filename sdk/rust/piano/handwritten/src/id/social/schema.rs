@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to initiate social login
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogin~2Fsocial&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub struct SocialLoginRequest<'a> {
+    /// The social network (e.g. `FACEBOOK`, `GOOGLE`, `TWITTER`, `LINKEDIN` or `APPLE`)
+    pub social_type: &'a str,
+    /// The landing URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<&'a str>,
+    /// The public ID of the form, whose validators are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_id: Option<&'a str>,
+}
+
+impl<'a> SocialLoginRequest<'a> {
+    /// Create a new social login request
+    pub fn new(social_type: &'a str) -> Self {
+        Self {
+            social_type,
+            redirect_uri: None,
+            form_id: None,
+        }
+    }
+
+    /// Set the landing URL
+    pub fn with_redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    /// Set the public ID of the form
+    pub fn with_form_id(mut self, form_id: &'a str) -> Self {
+        self.form_id = Some(form_id);
+        self
+    }
+}
+
+/// Request to merge a social account into an existing Piano ID account
+///
+/// The user proves the ownership of the Piano ID account either with its password or
+/// with an access token of a previous authorization.
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flogin~2Fsocial~2FcodeConfirm&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub struct ConfirmSocialLinkingRequest<'a> {
+    /// The `linking_state` returned by the social code exchange
+    pub linking_state: &'a str,
+    /// The user's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    /// The user's password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<&'a str>,
+    /// The access token of a previous authorization
+    #[serde(rename = "confimed_token", skip_serializing_if = "Option::is_none")]
+    pub confirmed_token: Option<&'a str>,
+}
+
+impl<'a> ConfirmSocialLinkingRequest<'a> {
+    /// Create a request confirming the account with its password
+    pub fn with_password(linking_state: &'a str, email: &'a str, password: &'a str) -> Self {
+        Self {
+            linking_state,
+            email: Some(email),
+            password: Some(password),
+            confirmed_token: None,
+        }
+    }
+
+    /// Create a request confirming the account with an access token
+    pub fn with_token(linking_state: &'a str, confirmed_token: &'a str) -> Self {
+        Self {
+            linking_state,
+            email: None,
+            password: None,
+            confirmed_token: Some(confirmed_token),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct SocialRequest<'a, T: Serialize> {
+    pub api_token: &'a str,
+    #[serde(flatten)]
+    pub inner: T,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct SocialCodeRequest<'a> {
+    pub response_id: &'a str,
+}
+
+/// Social login link wrapped in `SocialLinkResponse` as documented, or returned as is
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(super) enum SocialLinkResult {
+    Wrapped {
+        #[serde(rename = "SocialLinkResponse")]
+        link: SocialLink,
+    },
+    Flat(SocialLink),
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct SocialLink {
+    pub uri: String,
+}
+
+impl SocialLinkResult {
+    pub(super) fn into_uri(self) -> String {
+        match self {
+            Self::Wrapped { link } | Self::Flat(link) => link.uri,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_social_request() {
+        let request = SocialRequest {
+            api_token: "TOKEN",
+            inner: ConfirmSocialLinkingRequest::with_token("STATE", "ACCESS"),
+        };
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "api_token": "TOKEN",
+                "linking_state": "STATE",
+                "confimed_token": "ACCESS",
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_social_link() {
+        let result: SocialLinkResult = serde_json::from_value(json!({
+            "SocialLinkResponse": { "uri": "https://accounts.example.com/auth" }
+        }))
+        .expect("OK");
+        assert_eq!(result.into_uri(), "https://accounts.example.com/auth");
+    }
+}
@@ -0,0 +1,68 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{id::PianoIdAPI, PianoRequest, PianoResponse};
+
+impl PianoIdAPI {
+    /// Verify an access token issued by Piano ID.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Ftoken~2Fverify&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, token)))]
+    pub async fn verify_token(&self, token: &str) -> Result<Token, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: VerifyTokenRequest { token },
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/token/verify", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+
+    /// Get a new access token with a refresh token.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Ftoken~2Frefresh&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, refresh_token)))]
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<Token, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: RefreshTokenRequest { refresh_token },
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/token/refresh", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+
+    /// Get an access token with an OAuth authorization code or a refresh token.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fidentity~2Foauth~2Ftoken&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    pub async fn get_oauth_token(
+        &self,
+        params: &OAuthTokenRequest<'_>,
+    ) -> Result<Token, crate::Error> {
+        let result = self
+            .client
+            .post(format!("{}/identity/oauth/token", self.endpoint))
+            .json(params)
+            .send()
+            .await?
+            .json::<PianoResponse<TokenResult>>()
+            .await?
+            .value()?;
+        Ok(result.into_token())
+    }
+}
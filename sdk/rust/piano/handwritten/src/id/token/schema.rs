@@ -0,0 +1,253 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to verify an access token
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Ftoken~2Fverify&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub(super) struct VerifyTokenRequest<'a> {
+    pub token: &'a str,
+}
+
+/// Request to refresh an access token
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Ftoken~2Frefresh&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub(super) struct RefreshTokenRequest<'a> {
+    pub refresh_token: &'a str,
+}
+
+/// Request to get an access token with an OAuth authorization code or a refresh token
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fidentity~2Foauth~2Ftoken&spec=piano-id)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthTokenRequest<'a> {
+    /// The application ID
+    pub client_id: &'a str,
+    /// The grant type (`authorization_code` or `refresh_token`)
+    pub grant_type: &'a str,
+    /// The authorization code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+    /// The refresh token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<&'a str>,
+    /// The client secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<&'a str>,
+    /// The redirect URI used to get the authorization code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<&'a str>,
+    /// The PKCE code verifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_verifier: Option<&'a str>,
+}
+
+impl<'a> OAuthTokenRequest<'a> {
+    /// Create a request exchanging an authorization code
+    pub fn authorization_code(client_id: &'a str, code: &'a str) -> Self {
+        Self {
+            client_id,
+            grant_type: "authorization_code",
+            code: Some(code),
+            refresh_token: None,
+            client_secret: None,
+            redirect_uri: None,
+            code_verifier: None,
+        }
+    }
+
+    /// Create a request exchanging a refresh token
+    pub fn refresh_token(client_id: &'a str, refresh_token: &'a str) -> Self {
+        Self {
+            client_id,
+            grant_type: "refresh_token",
+            code: None,
+            refresh_token: Some(refresh_token),
+            client_secret: None,
+            redirect_uri: None,
+            code_verifier: None,
+        }
+    }
+
+    /// Set the client secret
+    pub fn with_client_secret(mut self, client_secret: &'a str) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Set the redirect URI
+    pub fn with_redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    /// Set the PKCE code verifier
+    pub fn with_code_verifier(mut self, code_verifier: &'a str) -> Self {
+        self.code_verifier = Some(code_verifier);
+        self
+    }
+}
+
+/// Access token issued by Piano ID
+///
+/// Piano ID documents the fields in camel case while some endpoints answer in snake case,
+/// so both are accepted.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Token {
+    #[serde(alias = "access_token")]
+    access_token: String,
+    #[serde(default, alias = "token_type")]
+    token_type: Option<String>,
+    #[serde(default, alias = "refresh_token")]
+    refresh_token: Option<String>,
+    #[serde(default, alias = "expires_in")]
+    expires_in: Option<i64>,
+    #[serde(default, alias = "pub_id")]
+    pub_id: Option<String>,
+    #[serde(default)]
+    registration: bool,
+    #[serde(default, alias = "email_confirmation_required")]
+    email_confirmation_required: bool,
+    #[serde(default, alias = "pre_confirmed_user")]
+    pre_confirmed_user: bool,
+    #[serde(default, alias = "two_factor_auth_required")]
+    two_factor_auth_required: bool,
+    #[serde(default, alias = "phone_confirmation_required")]
+    phone_confirmation_required: bool,
+    #[serde(default, alias = "site_cookie_domain")]
+    site_cookie_domain: Option<String>,
+    #[serde(default, alias = "login_token_id")]
+    login_token_id: Option<String>,
+}
+
+impl Token {
+    /// Get the access token (JWT)
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    /// Get the token type (e.g. `Bearer`)
+    pub fn token_type(&self) -> Option<&str> {
+        self.token_type.as_deref()
+    }
+
+    /// Get the refresh token
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.refresh_token.as_deref()
+    }
+
+    /// Get the lifetime of the access token in seconds
+    pub fn expires_in(&self) -> Option<i64> {
+        self.expires_in
+    }
+
+    /// Get the public ID of the token
+    pub fn pub_id(&self) -> Option<&str> {
+        self.pub_id.as_deref()
+    }
+
+    /// Check if the token was issued for a new registration
+    pub fn is_registration(&self) -> bool {
+        self.registration
+    }
+
+    /// Check if the user still has to confirm their email
+    pub fn is_email_confirmation_required(&self) -> bool {
+        self.email_confirmation_required
+    }
+
+    /// Check if the user was pre-confirmed
+    pub fn is_pre_confirmed_user(&self) -> bool {
+        self.pre_confirmed_user
+    }
+
+    /// Check if the user still has to pass two-factor authentication
+    pub fn is_two_factor_auth_required(&self) -> bool {
+        self.two_factor_auth_required
+    }
+
+    /// Check if the user still has to confirm their phone number
+    pub fn is_phone_confirmation_required(&self) -> bool {
+        self.phone_confirmation_required
+    }
+
+    /// Get the domain of the site cookie
+    pub fn site_cookie_domain(&self) -> Option<&str> {
+        self.site_cookie_domain.as_deref()
+    }
+
+    /// Get the ID of the login token
+    pub fn login_token_id(&self) -> Option<&str> {
+        self.login_token_id.as_deref()
+    }
+}
+
+/// Token wrapped in `TokenResponse` as documented, or returned as is
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum TokenResult {
+    Wrapped {
+        #[serde(rename = "TokenResponse")]
+        token: Token,
+    },
+    Flat(Token),
+}
+
+impl TokenResult {
+    pub(crate) fn into_token(self) -> Token {
+        match self {
+            Self::Wrapped { token } | Self::Flat(token) => token,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_oauth_token_request() {
+        let request =
+            OAuthTokenRequest::authorization_code("AID", "CODE").with_code_verifier("VERIFIER");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "clientId": "AID",
+                "grantType": "authorization_code",
+                "code": "CODE",
+                "codeVerifier": "VERIFIER",
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_token() {
+        let wrapped: TokenResult = serde_json::from_value(json!({
+            "TokenResponse": {
+                "accessToken": "ACCESS",
+                "refreshToken": "REFRESH",
+                "expiresIn": 3600,
+                "emailConfirmationRequired": true
+            }
+        }))
+        .expect("OK");
+        let token = wrapped.into_token();
+        assert_eq!(token.access_token(), "ACCESS");
+        assert_eq!(token.refresh_token(), Some("REFRESH"));
+        assert!(token.is_email_confirmation_required());
+
+        let flat: TokenResult = serde_json::from_value(json!({
+            "access_token": "ACCESS",
+            "token_type": "Bearer",
+            "expires_in": 3600
+        }))
+        .expect("OK");
+        let token = flat.into_token();
+        assert_eq!(token.token_type(), Some("Bearer"));
+        assert_eq!(token.expires_in(), Some(3600));
+    }
+}
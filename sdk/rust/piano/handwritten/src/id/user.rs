@@ -0,0 +1,103 @@
+mod schema;
+pub use self::schema::*;
+
+use std::collections::HashMap;
+
+use crate::{
+    id::{expect_empty, expect_json, PianoIdAPI},
+    PianoRequest, PianoResponse,
+};
+
+impl PianoIdAPI {
+    /// Get the Piano ID profile of a user.
+    ///
+    /// Returns `None` if the user is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fusers~2Fget&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_user(
+        &self,
+        params: &GetIdUserRequest<'_>,
+    ) -> Result<Option<IdUser>, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/users/get", self.endpoint))
+            .json(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<IdUserResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(IdUserResult::into_user))
+    }
+
+    /// Get the aliases of a user by alias name.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuserinfo~2Faliases&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_user_aliases(
+        &self,
+        uid: &str,
+    ) -> Result<HashMap<String, String>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/userinfo/aliases", self.endpoint))
+            .query(&UserIdQuery {
+                aid: &self.app_id,
+                uid,
+            })
+            .send()
+            .await?
+            .json::<PianoResponse<HashMap<String, String>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// Set the aliases of a user by alias name.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuserinfo~2Faliases&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_user_aliases(
+        &self,
+        uid: &str,
+        aliases: &HashMap<&str, &str>,
+    ) -> Result<(), crate::Error> {
+        let response = self
+            .client
+            .post(format!("{}/publisher/userinfo/aliases", self.endpoint))
+            .query(&UserIdQuery {
+                aid: &self.app_id,
+                uid,
+            })
+            .json(aliases)
+            .send()
+            .await?;
+        expect_empty(response).await
+    }
+
+    /// Get the audit log of a user.
+    ///
+    /// The format of the audit records is not documented by Piano, so they are returned
+    /// as raw JSON.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Faudit~2Fuser&spec=piano-id)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_user_audit(
+        &self,
+        params: &UserAuditRequest<'_>,
+    ) -> Result<serde_json::Value, crate::Error> {
+        let response = self
+            .client
+            .get(format!("{}/publisher/audit/user", self.endpoint))
+            .query(&[("aid", &self.app_id), ("api_token", &self.token)])
+            .query(params)
+            .send()
+            .await?;
+        expect_json(response).await
+    }
+}
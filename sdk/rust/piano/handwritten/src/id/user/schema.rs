@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Request to get the Piano ID profile of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fusers~2Fget&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub struct GetIdUserRequest<'a> {
+    /// The user's ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+    /// The user's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    /// The user's phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+}
+
+impl<'a> GetIdUserRequest<'a> {
+    /// Create a request for the user with the given ID
+    pub fn by_uid(uid: &'a str) -> Self {
+        Self {
+            uid: Some(uid),
+            email: None,
+            phone: None,
+        }
+    }
+
+    /// Create a request for the user with the given email address
+    pub fn by_email(email: &'a str) -> Self {
+        Self {
+            uid: None,
+            email: Some(email),
+            phone: None,
+        }
+    }
+
+    /// Create a request for the user with the given phone number
+    pub fn by_phone(phone: &'a str) -> Self {
+        Self {
+            uid: None,
+            email: None,
+            phone: Some(phone),
+        }
+    }
+}
+
+/// Request to get the audit log of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Faudit~2Fuser&spec=piano-id)
+#[derive(Debug, Serialize)]
+pub struct UserAuditRequest<'a> {
+    /// The user's ID
+    pub uid: &'a str,
+    /// Filter by action type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<&'a str>,
+    /// Start of the period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<&'a str>,
+    /// End of the period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> UserAuditRequest<'a> {
+    /// Create a new user audit request
+    pub fn new(uid: &'a str) -> Self {
+        Self {
+            uid,
+            action_type: None,
+            from: None,
+            until: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the action type filter
+    pub fn with_action_type(mut self, action_type: &'a str) -> Self {
+        self.action_type = Some(action_type);
+        self
+    }
+
+    /// Set the period
+    pub fn with_period(mut self, from: &'a str, until: &'a str) -> Self {
+        self.from = Some(from);
+        self.until = Some(until);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Piano ID profile of a user
+#[derive(Debug, Deserialize, Clone)]
+pub struct IdUser {
+    uid: String,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    first_name: Option<String>,
+    #[serde(default)]
+    last_name: Option<String>,
+    #[serde(default)]
+    phone: Option<String>,
+    #[serde(default)]
+    create_date: Option<i64>,
+    #[serde(default)]
+    reset_password_email_sent: bool,
+    #[serde(default)]
+    custom_fields: Vec<CustomFieldValue>,
+    #[serde(default)]
+    aliases: HashMap<String, String>,
+}
+
+impl IdUser {
+    /// Get the user's ID
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    /// Get the user's email address
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// Get the user's first name
+    pub fn first_name(&self) -> Option<&str> {
+        self.first_name.as_deref()
+    }
+
+    /// Get the user's last name
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
+    }
+
+    /// Get the user's phone number
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+
+    /// Get the creation date (UNIX timestamp)
+    pub fn create_date(&self) -> Option<i64> {
+        self.create_date
+    }
+
+    /// Check if a reset password email was sent
+    pub fn is_reset_password_email_sent(&self) -> bool {
+        self.reset_password_email_sent
+    }
+
+    /// Get the custom field values
+    pub fn custom_fields(&self) -> &[CustomFieldValue] {
+        &self.custom_fields
+    }
+
+    /// Get the value of a custom field
+    pub fn custom_field(&self, name: &str) -> Option<&str> {
+        self.custom_fields
+            .iter()
+            .find(|field| field.name == name)
+            .and_then(|field| field.value.as_deref())
+    }
+
+    /// Get the user aliases by alias name
+    pub fn aliases(&self) -> &HashMap<String, String> {
+        &self.aliases
+    }
+}
+
+/// Custom field value of a user
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct CustomFieldValue {
+    name: String,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+impl CustomFieldValue {
+    /// Get the custom field name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the value (multi-select values are a JSON array)
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+/// Profile wrapped in `PublisherUserProfileResponse` as documented, or returned as is
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(super) enum IdUserResult {
+    Wrapped {
+        #[serde(rename = "PublisherUserProfileResponse")]
+        user: IdUser,
+    },
+    Flat(IdUser),
+}
+
+impl IdUserResult {
+    pub(super) fn into_user(self) -> IdUser {
+        match self {
+            Self::Wrapped { user } | Self::Flat(user) => user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct UserIdQuery<'a> {
+    pub aid: &'a str,
+    pub uid: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_id_user() {
+        let result: IdUserResult = serde_json::from_value(json!({
+            "PublisherUserProfileResponse": {
+                "uid": "UID",
+                "email": "user@example.com",
+                "first_name": "Jane",
+                "last_name": null,
+                "create_date": 1700000000,
+                "reset_password_email_sent": false,
+                "custom_fields": [
+                    { "name": "newsletter", "value": "true" },
+                    { "name": "interests", "value": "[\"sports\",\"news\"]" }
+                ],
+                "aliases": { "crm_id": "42" }
+            }
+        }))
+        .expect("OK");
+        let user = result.into_user();
+        assert_eq!(user.uid(), "UID");
+        assert_eq!(user.last_name(), None);
+        assert_eq!(user.custom_field("newsletter"), Some("true"));
+        assert_eq!(user.aliases().get("crm_id").map(String::as_str), Some("42"));
+    }
+}
@@ -1,19 +1,87 @@
+mod download;
+#[cfg(feature = "id")]
+pub mod id;
 pub mod publisher;
+#[cfg(feature = "user")]
+pub mod user;
+#[cfg(feature = "webhook")]
+pub mod webhook;
+
+pub use self::download::ByteStream;
 
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{
     de::{self, Unexpected},
     Deserialize, Deserializer, Serialize,
 };
-use std::{io::ErrorKind, marker::PhantomData, str::FromStr};
+use std::{future::Future, io::ErrorKind, marker::PhantomData, str::FromStr};
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
 
+/// Number of requests sent at the same time by the methods which fan out over many calls
+pub(crate) const CONCURRENCY: usize = 8;
+
+/// Options of a synchronization of Piano with a desired state, such as a roster of
+/// contract users or a resource catalog
 #[derive(Debug, Clone)]
+pub struct SyncOptions {
+    pub(crate) remove_missing: bool,
+    pub(crate) concurrency: usize,
+    pub(crate) dry_run: bool,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        Self {
+            remove_missing: true,
+            concurrency: CONCURRENCY,
+            dry_run: false,
+        }
+    }
+}
+
+impl SyncOptions {
+    /// Create options removing what is missing from the desired state, applying eight
+    /// calls at the same time
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether what is missing from the desired state is removed: contract users are
+    /// removed and revoked, resource tags and bundle members are detached
+    pub fn with_remove_missing(mut self, remove_missing: bool) -> Self {
+        self.remove_missing = remove_missing;
+        self
+    }
+
+    /// Set the maximum number of calls made at the same time
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set whether the plan is only computed, without calling the API
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+/// Create an [`ErrorKind::InvalidInput`] error, for input rejected without calling Piano
+pub(crate) fn invalid_input(message: impl Into<String>) -> Error {
+    Box::new(std::io::Error::new(ErrorKind::InvalidInput, message.into()))
+}
+
+#[derive(Clone)]
 pub struct PianoAPI {
     app_id: String,
     endpoint: String,
+    /// API token, for the endpoints which take it as a parameter instead of a header
+    #[cfg_attr(not(feature = "id"), allow(dead_code))]
+    token: String,
     pub(crate) client: reqwest::Client,
+    /// Client without the API token, used to fetch pre-signed download URLs
+    pub(crate) download_client: reqwest::Client,
 }
 
 impl PianoAPI {
@@ -31,7 +99,9 @@ impl PianoAPI {
         Self {
             endpoint: endpoint.to_string(),
             client,
+            download_client: reqwest::Client::new(),
             app_id: app_id.to_string(),
+            token: token.to_string(),
         }
     }
     fn mk_header(token: &str) -> HeaderMap {
@@ -42,6 +112,15 @@ impl PianoAPI {
     }
 }
 
+impl std::fmt::Debug for PianoAPI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PianoAPI")
+            .field("app_id", &self.app_id)
+            .field("endpoint", &self.endpoint)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct PianoRequest<T: Serialize> {
     aid: String,
@@ -100,6 +179,39 @@ impl<T> PianoPaginated<T> {
     pub fn has_next(&self) -> bool {
         self.offset + self.count < self.total
     }
+
+    /// Offset of the next page, or `None` if this page is the last one.
+    ///
+    /// An empty page is always the last one, so that a `total` which is out of date
+    /// does not cause an endless loop.
+    pub fn next_offset(&self) -> Option<usize> {
+        (self.count > 0 && self.has_next()).then_some(self.offset + self.count)
+    }
+}
+
+/// Read every page of a list, starting from offset 0.
+///
+/// `fetch_page` requests the page at an offset, and `items` extracts the items of a page.
+pub(crate) async fn fetch_all_pages<T, I, F, Fut>(
+    mut fetch_page: F,
+    mut items: impl FnMut(T) -> I,
+) -> Result<Vec<I::Item>, crate::Error>
+where
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<PianoPaginated<T>, crate::Error>>,
+    I: IntoIterator,
+{
+    let mut all = vec![];
+    let mut offset = 0;
+    loop {
+        let page = fetch_page(offset).await?;
+        let next = page.next_offset();
+        all.extend(items(page.value));
+        match next {
+            Some(next) => offset = next,
+            None => return Ok(all),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -175,7 +287,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{Empty, PianoCode};
+    use futures_util::{future, FutureExt};
+
+    use crate::{fetch_all_pages, Empty, PianoCode, PianoPaginated};
 
     #[test]
     fn sanity_check_piano_empty_response() {
@@ -190,4 +304,47 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn test_fetch_all_pages() {
+        let items: Vec<u32> = (0..7).collect();
+        let mut offsets = vec![];
+        let all = fetch_all_pages(
+            |offset| {
+                offsets.push(offset);
+                let value: Vec<u32> = items.iter().skip(offset).take(3).copied().collect();
+                future::ready(Ok(PianoPaginated {
+                    limit: 3,
+                    offset,
+                    total: items.len(),
+                    count: value.len(),
+                    value,
+                }))
+            },
+            |value| value,
+        )
+        .now_or_never()
+        .expect("ready")
+        .expect("OK");
+        assert_eq!(all, items);
+        assert_eq!(offsets, vec![0, 3, 6]);
+
+        // An empty page ends the list even if the total says otherwise
+        let all = fetch_all_pages(
+            |offset| {
+                future::ready(Ok(PianoPaginated {
+                    limit: 3,
+                    offset,
+                    total: 10,
+                    count: 0,
+                    value: Vec::<u32>::new(),
+                }))
+            },
+            |value| value,
+        )
+        .now_or_never()
+        .expect("ready")
+        .expect("OK");
+        assert!(all.is_empty());
+    }
 }
@@ -95,15 +95,38 @@ pub mod app;
 /// - List consent box configurations
 /// - Get specific consent box details
 /// - Manage GDPR and privacy compliance
+/// - Read and update the consent answers of users
 pub mod consent;
 
+/// Term conversion tracking
+///
+/// - Look up and count conversions
+/// - Iterate over conversions as a stream
+/// - Record custom, external, registration and third-party conversions
+pub mod conversion;
+
+/// Downloadable reports
+///
+/// - Generate access, activity, subscription, transactions and user reports
+/// - Track and rerun report generation tasks
+/// - Download reports as CSV
+pub mod export;
+
 /// GDPR compliance and data privacy
 ///
 /// - Export user data
 /// - Delete user data
 /// - Handle data privacy requests
+/// - Erase users with verification and an audit trail
 pub mod gdpr;
 
+/// Customer service inquiries
+///
+/// - List inquiries and their comments
+/// - Comment inquiries and perform actions on them
+/// - Count unresolved inquiries
+pub mod inquiry;
+
 /// Content licensing and contract management
 ///
 /// - Manage licensing contracts and users
@@ -112,11 +135,27 @@ pub mod gdpr;
 /// - Manage licensees and schedules
 pub mod licensing;
 
+/// Terms linked with external subscriptions
+///
+/// - Create and update linked terms and their custom fields
+/// - Synchronize external subscription events
+/// - Look up subscriptions by external subscription ID
+pub mod linked_term;
+
+/// Payments, refunds and payment methods
+///
+/// - Get user payments
+/// - Issue full and partial refunds
+/// - Manage payment methods and their billing addresses
+pub mod payment;
+
 /// Promotional campaigns and discount codes
 ///
 /// - Create and manage promotions
 /// - Generate and track promotion codes
 /// - Configure promotion terms and conditions
+/// - Run promo code campaigns and export their codes as CSV
+/// - Define and validate promotions with typed discounts, limits and terms
 pub mod promotion;
 
 /// Content resource management
@@ -124,6 +163,7 @@ pub mod promotion;
 /// - Create and manage content resources
 /// - Handle resource bundles and tags
 /// - Attach resources to licensing contracts
+/// - Synchronize resources, tags and bundles with a declarative catalog
 pub mod resource;
 
 /// Scheduling and time-based operations
@@ -131,6 +171,7 @@ pub mod resource;
 /// - Manage scheduling periods
 /// - Configure contract schedules
 /// - Handle time-based licensing
+/// - Find gaps and overlaps between periods and roll schedules forward
 pub mod schedule;
 
 /// Team member and permission management
@@ -146,3 +187,16 @@ pub mod team;
 /// - Update user information
 /// - Handle user authentication
 pub mod user;
+
+/// Gift vouchers
+///
+/// - Get vouchers and list the vouchers of a gifter
+/// - Resend vouchers with a new redemption code
+pub mod voucher;
+
+/// Webhook delivery management
+///
+/// - List webhook events and their delivery attempts
+/// - Resend, skip and replay failed webhooks
+/// - Configure the webhook endpoint and events
+pub mod webhook;
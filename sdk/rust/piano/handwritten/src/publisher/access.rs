@@ -13,95 +13,236 @@
 ///
 /// // Grant access to a user
 /// let grant_req = GrantAccessRequest::new("user_uid", "resource_id");
-/// api.grant_access(&grant_req).await?;
+/// let accesses = api.grant_access(&grant_req).await?;
 ///
 /// // Check if user has access
 /// let has_access = api.check_access("user_uid", "resource_id").await?;
 ///
 /// // List user's access rights
 /// let access_list = api.list_user_access("user_uid", &ListAccessRequest::new()).await?;
+///
+/// // Grant access to many users at once, sending them an invitation
+/// let emails = ["jane@example.com", "john@example.com"];
+/// let req = GrantAccessToUsersRequest::by_emails(&["resource_id"], &emails)
+///     .with_send_email(true);
+/// let report = api.grant_access_to_users(&req).await;
+/// for failure in report.failures() {
+///     println!("{} was not granted {}: {:?}", failure.user(), failure.rid(), failure.outcome());
+/// }
+///
+/// // Count the users with access to a resource
+/// let count = api.count_active_access("resource_id").await?;
 /// # Ok(())
 /// # }
 /// ```
 mod schema;
 pub use self::schema::*;
 
-use crate::{Empty, PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+use std::collections::BTreeMap;
+
+use futures_util::{future::join_all, stream, StreamExt, TryStreamExt};
+
+use crate::{PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
-    /// Grant access to a user for a specific resource.
-    ///
-    /// This endpoint grants access permissions to a user for accessing content or resources.
+    /// Grant access to a resource to a user, identified by UID or email addresses.
     ///
-    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faccess~2Fgrant
+    /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Fgrant
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn grant_access(
         &self,
         params: &GrantAccessRequest<'_>,
-    ) -> Result<AccessGrant, crate::Error> {
-        let req = PianoRequest {
-            aid: self.app_id.clone(),
-            inner: params,
-        };
+    ) -> Result<Vec<Access>, crate::Error> {
         let result = self
             .client
-            .post(format!("{}/publisher/access/grant", self.endpoint))
-            .form(&req)
+            .get(format!("{}/publisher/user/access/grant", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<AccessListResult>>()
+            .await?
+            .value()?;
+        Ok(result.accesses)
+    }
+
+    /// Grant access to resources to many users.
+    ///
+    /// Users identified by email addresses are granted with `/publisher/user/access/grantToUsers`,
+    /// `batch_size` emails per call. Piano creates the users who don't exist yet and, if
+    /// `send_email` is set, invites them. Users identified by UID are granted one by one with
+    /// `/publisher/user/access/grant`, `batch_size` requests at a time.
+    ///
+    /// A failed call does not stop the others. The report has one result per user and
+    /// resource, and every user of a failed batch of emails is reported as failed.
+    ///
+    /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2FgrantToUsers
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn grant_access_to_users(
+        &self,
+        params: &GrantAccessToUsersRequest<'_>,
+    ) -> AccessGrantReport {
+        let batch_size = params.batch_size.max(1);
+        let mut report = AccessGrantReport::default();
+        match params.users {
+            AccessGrantees::Emails(emails) => {
+                for emails in emails.chunks(batch_size) {
+                    let req = GrantToUsersParams {
+                        rid: params.rids,
+                        emails,
+                        expire_date: params.expire_date,
+                        send_email: params.send_email,
+                        message: params.message,
+                    };
+                    let outcome = match self.grant_access_to_emails(&req).await {
+                        Ok(granted) => {
+                            report.add_granted(granted);
+                            AccessGrantOutcome::Granted
+                        }
+                        Err(error) => AccessGrantOutcome::Failed {
+                            error: error.to_string(),
+                        },
+                    };
+                    for email in emails {
+                        for rid in params.rids {
+                            report.push(AccessGrantResult::new(email, rid, outcome.clone()));
+                        }
+                    }
+                }
+            }
+            AccessGrantees::Uids(uids) => {
+                let requests: Vec<GrantAccessRequest> = params
+                    .rids
+                    .iter()
+                    .flat_map(|rid| {
+                        uids.iter().map(|uid| GrantAccessRequest {
+                            expire_date: params.expire_date,
+                            send_email: params.send_email,
+                            message: params.message,
+                            ..GrantAccessRequest::new(uid, rid)
+                        })
+                    })
+                    .collect();
+                for requests in requests.chunks(batch_size) {
+                    let results = join_all(requests.iter().map(|req| self.grant_access(req))).await;
+                    for (req, result) in requests.iter().zip(results) {
+                        let outcome = match result {
+                            Ok(accesses) => {
+                                report.add_granted(accesses.len());
+                                AccessGrantOutcome::Granted
+                            }
+                            Err(error) => AccessGrantOutcome::Failed {
+                                error: error.to_string(),
+                            },
+                        };
+                        report.push(AccessGrantResult::new(
+                            req.uid.unwrap_or_default(),
+                            req.rid,
+                            outcome,
+                        ));
+                    }
+                }
+            }
+        }
+        report
+    }
+
+    async fn grant_access_to_emails(
+        &self,
+        req: &GrantToUsersParams<'_>,
+    ) -> Result<usize, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/user/access/grantToUsers",
+                self.endpoint
+            ))
+            .query(&[("aid", &self.app_id)])
+            .query(req)
             .send()
             .await?
-            .json::<PianoResponse<AccessGrantResult>>()
+            .json::<PianoResponse<AccessCountResult>>()
             .await?
             .value()?;
-        Ok(result.access_grant)
+        Ok(result.data as usize)
     }
 
-    /// Revoke access from a user for a specific resource.
+    /// Update the expiration date of an access.
     ///
-    /// This endpoint removes access permissions from a user for specific content or resources.
+    /// `None` leaves the expiration date unchanged. It does not make the access unlimited.
     ///
-    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faccess~2Frevoke
+    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faccess~2Fupdate
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
-    pub async fn revoke_access(
+    pub async fn update_access(
         &self,
-        params: &RevokeAccessRequest<'_>,
-    ) -> Result<(), crate::Error> {
-        let req = PianoRequest {
-            aid: self.app_id.clone(),
-            inner: params,
+        access_id: &str,
+        expire_date: Option<i64>,
+    ) -> Result<Access, crate::Error> {
+        let req = UpdateAccessRequest {
+            access_id,
+            expire_date,
         };
-        self.client
-            .post(format!("{}/publisher/access/revoke", self.endpoint))
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/access/update", self.endpoint))
             .form(&req)
             .send()
             .await?
-            .json::<PianoResponse<Empty>>()
+            .json::<PianoResponse<AccessResult>>()
             .await?
             .value()?;
-        Ok(())
+        Ok(result.access)
     }
 
-    /// Check if a user has access to a specific resource.
+    /// Revoke an access.
     ///
-    /// This endpoint verifies whether a user has valid access permissions for content or resources.
-    /// Returns `true` if the user has access, `false` otherwise.
+    /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Frevoke
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn revoke_access(&self, access_id: &str) -> Result<Access, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/user/access/revoke", self.endpoint))
+            .query(&[("access_id", access_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<AccessResult>>()
+            .await?
+            .value()?;
+        Ok(result.access)
+    }
+
+    /// Get the access of a user to a resource.
     ///
     /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Fcheck
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
-    pub async fn check_access(&self, uid: &str, resource_id: &str) -> Result<bool, crate::Error> {
+    pub async fn get_user_access(
+        &self,
+        uid: &str,
+        rid: &str,
+        cross_app: bool,
+    ) -> Result<Access, crate::Error> {
         let result = self
             .client
-            .get(format!("{}/publisher/access/check", self.endpoint))
-            .query(&[
-                ("aid", &self.app_id),
-                ("uid", &uid.to_string()),
-                ("resource_id", &resource_id.to_string()),
-            ])
+            .get(format!("{}/publisher/user/access/check", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("uid", uid), ("rid", rid)])
+            .query(&[("cross_app", cross_app)])
             .send()
             .await?
-            .json::<PianoResponse<AccessCheckResult>>()
+            .json::<PianoResponse<AccessResult>>()
             .await?
             .value()?;
-        Ok(result.has_access)
+        Ok(result.access)
+    }
+
+    /// Check if a user has access to a specific resource.
+    ///
+    /// Returns `true` if the user has access, `false` otherwise.
+    ///
+    /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Fcheck
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn check_access(&self, uid: &str, rid: &str) -> Result<bool, crate::Error> {
+        let access = self.get_user_access(uid, rid, false).await?;
+        Ok(access.is_granted())
     }
 
     /// List access rights for a specific user.
@@ -113,11 +254,11 @@ impl PianoAPI {
     pub async fn list_user_access(
         &self,
         uid: &str,
-        params: &ListAccessRequest<'_>,
+        params: &ListAccessRequest,
     ) -> Result<PianoPaginated<ListAccessResult>, crate::Error> {
         let result = self
             .client
-            .get(format!("{}/publisher/access/list", self.endpoint))
+            .get(format!("{}/publisher/user/access/list", self.endpoint))
             .query(&[("aid", &self.app_id), ("uid", &uid.to_string())])
             .query(params)
             .send()
@@ -128,26 +269,59 @@ impl PianoAPI {
         Ok(result)
     }
 
-    /// List all access grants with optional filtering.
+    /// Count the users with access to a resource.
     ///
-    /// This endpoint retrieves a paginated list of all access grants in the system.
+    /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fresource~2Fuser~2Flist
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn count_active_access(&self, rid: &str) -> Result<usize, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/resource/user/list", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("rid", rid)])
+            .query(&[("offset", 0), ("limit", 1)])
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ResourceUserListResult>>>()
+            .await?
+            .value()?;
+        Ok(result.total)
+    }
+
+    /// Count the users with access to each resource, keyed by resource ID.
     ///
-    /// Reference: https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Flist_all
+    /// Up to eight resources are counted at the same time.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
-    pub async fn list_all_access(
+    pub async fn count_active_access_by_resource(
         &self,
-        params: &ListAllAccessRequest<'_>,
-    ) -> Result<PianoPaginated<ListAccessResult>, crate::Error> {
+        rids: &[&str],
+    ) -> Result<BTreeMap<String, usize>, crate::Error> {
+        stream::iter(rids)
+            .map(|rid| async move {
+                let count = self.count_active_access(rid).await?;
+                Ok::<_, crate::Error>((rid.to_string(), count))
+            })
+            .buffered(crate::CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Count the active accesses of the whole application, across all resources.
+    ///
+    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faccess~2Factive~2Fcount
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn count_app_active_access(&self) -> Result<i64, crate::Error> {
         let result = self
             .client
-            .get(format!("{}/publisher/access/list_all", self.endpoint))
-            .query(&[("aid", &self.app_id)])
-            .query(params)
+            .post(format!(
+                "{}/publisher/user/access/active/count",
+                self.endpoint
+            ))
+            .form(&[("aid", &self.app_id)])
             .send()
             .await?
-            .json::<PianoResponse<PianoPaginated<ListAccessResult>>>()
+            .json::<PianoResponse<AccessCountResult>>()
             .await?
             .value()?;
-        Ok(result)
+        Ok(result.data)
     }
 }
@@ -1,105 +1,212 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 /// Request parameters for granting access to a user.
 ///
-/// Contains the required parameters to grant access permissions.
-#[derive(Debug, Serialize)]
+/// The user is identified by UID, or by email addresses, in which case Piano creates the
+/// users who don't exist yet.
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Fgrant)
+#[derive(Debug, Serialize, Clone)]
 pub struct GrantAccessRequest<'a> {
-    /// User's unique identifier (required)
-    pub uid: &'a str,
     /// Resource identifier (required)
-    pub resource_id: &'a str,
-    /// Type of resource (optional)
+    pub rid: &'a str,
+    /// User's unique identifier
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<&'a str>,
-    /// Access expiration date (UNIX timestamp) (optional)
+    pub uid: Option<&'a str>,
+    /// User's email addresses
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub emails: Option<&'a [&'a str]>,
+    /// Access expiration date (UNIX timestamp), unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    /// Whether to send an email to the user
+    pub send_email: bool,
+    /// The URL of the page linked from the email
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<i64>,
-    /// Custom parameters for the access grant (optional)
+    pub url: Option<&'a str>,
+    /// The message of the email
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_params: Option<&'a str>,
+    pub message: Option<&'a str>,
 }
 
 impl<'a> GrantAccessRequest<'a> {
-    /// Create a new access grant request with required parameters.
-    pub fn new(uid: &'a str, resource_id: &'a str) -> Self {
+    /// Create a new access grant request for a user.
+    pub fn new(uid: &'a str, rid: &'a str) -> Self {
         Self {
-            uid,
-            resource_id,
-            resource_type: None,
-            expires: None,
-            custom_params: None,
+            rid,
+            uid: Some(uid),
+            emails: None,
+            expire_date: None,
+            send_email: false,
+            url: None,
+            message: None,
         }
     }
 
-    /// Set the resource type.
-    pub fn with_resource_type(mut self, resource_type: &'a str) -> Self {
-        self.resource_type = Some(resource_type);
-        self
+    /// Create a new access grant request for a user identified by email addresses.
+    pub fn by_emails(emails: &'a [&'a str], rid: &'a str) -> Self {
+        Self {
+            uid: None,
+            emails: Some(emails),
+            ..Self::new("", rid)
+        }
     }
 
     /// Set the expiration timestamp.
-    pub fn with_expires(mut self, expires: i64) -> Self {
-        self.expires = Some(expires);
+    pub fn with_expire_date(mut self, expire_date: i64) -> Self {
+        self.expire_date = Some(expire_date);
+        self
+    }
+
+    /// Set whether to send an email to the user.
+    pub fn with_send_email(mut self, send_email: bool) -> Self {
+        self.send_email = send_email;
         self
     }
 
-    /// Set custom parameters.
-    pub fn with_custom_params(mut self, custom_params: &'a str) -> Self {
-        self.custom_params = Some(custom_params);
+    /// Set the URL of the page linked from the email.
+    pub fn with_url(mut self, url: &'a str) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Set the message of the email.
+    pub fn with_message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
         self
     }
 }
 
-/// Request parameters for revoking access from a user.
+/// Users to grant access to
+#[derive(Debug, Clone, Copy)]
+pub enum AccessGrantees<'a> {
+    /// Users identified by email address
+    Emails(&'a [&'a str]),
+    /// Users identified by UID
+    Uids(&'a [&'a str]),
+}
+
+/// Request parameters for granting access to many users.
 ///
-/// Contains the required parameters to revoke access permissions.
-#[derive(Debug, Serialize)]
-pub struct RevokeAccessRequest<'a> {
-    /// User's unique identifier (required)
-    pub uid: &'a str,
-    /// Resource identifier (required)
-    pub resource_id: &'a str,
-    /// Type of resource (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<&'a str>,
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2FgrantToUsers)
+#[derive(Debug, Clone)]
+pub struct GrantAccessToUsersRequest<'a> {
+    /// Resource identifiers
+    pub rids: &'a [&'a str],
+    /// Users to grant access to
+    pub users: AccessGrantees<'a>,
+    /// Access expiration date (UNIX timestamp), unlimited if not set
+    pub expire_date: Option<i64>,
+    /// Whether to send an invitation email to the users
+    pub send_email: bool,
+    /// The message of the invitation email
+    pub message: Option<&'a str>,
+    /// Number of emails per request, or number of concurrent requests for UIDs.
+    ///
+    /// Emails are sent in the query string, so large batches risk exceeding URL length
+    /// limits.
+    pub batch_size: usize,
 }
 
-impl<'a> RevokeAccessRequest<'a> {
-    /// Create a new access revoke request with required parameters.
-    pub fn new(uid: &'a str, resource_id: &'a str) -> Self {
+impl<'a> GrantAccessToUsersRequest<'a> {
+    /// Create a request granting access to users identified by email address.
+    pub fn by_emails(rids: &'a [&'a str], emails: &'a [&'a str]) -> Self {
+        Self {
+            rids,
+            users: AccessGrantees::Emails(emails),
+            expire_date: None,
+            send_email: false,
+            message: None,
+            batch_size: 100,
+        }
+    }
+
+    /// Create a request granting access to users identified by UID.
+    pub fn by_uids(rids: &'a [&'a str], uids: &'a [&'a str]) -> Self {
         Self {
-            uid,
-            resource_id,
-            resource_type: None,
+            users: AccessGrantees::Uids(uids),
+            batch_size: 10,
+            ..Self::by_emails(rids, &[])
         }
     }
 
-    /// Set the resource type.
-    pub fn with_resource_type(mut self, resource_type: &'a str) -> Self {
-        self.resource_type = Some(resource_type);
+    /// Set the expiration timestamp.
+    pub fn with_expire_date(mut self, expire_date: i64) -> Self {
+        self.expire_date = Some(expire_date);
+        self
+    }
+
+    /// Set whether to send an invitation email to the users.
+    pub fn with_send_email(mut self, send_email: bool) -> Self {
+        self.send_email = send_email;
+        self
+    }
+
+    /// Set the message of the invitation email.
+    pub fn with_message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Set the number of emails per request, or the number of concurrent requests for UIDs.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
         self
     }
 }
 
-/// Request parameters for listing user access rights.
-#[derive(Debug, Serialize, Default)]
-pub struct ListAccessRequest<'a> {
-    /// Maximum number of results to return (optional)
+#[derive(Debug, Serialize)]
+pub(super) struct GrantToUsersParams<'a> {
+    #[serde(serialize_with = "serialize_joined")]
+    pub rid: &'a [&'a str],
+    #[serde(serialize_with = "serialize_joined")]
+    pub emails: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    pub send_email: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<usize>,
-    /// Offset for pagination (optional)
+    pub message: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct UpdateAccessRequest<'a> {
+    pub access_id: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset: Option<usize>,
-    /// Filter by resource type (optional)
+    pub expire_date: Option<i64>,
+}
+
+/// Request parameters for listing user access rights.
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListAccessRequest {
+    /// Maximum number of results to return
+    pub limit: usize,
+    /// Offset for pagination
+    pub offset: usize,
+    /// Whether to expand bundled accesses
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<&'a str>,
-    /// Filter by status (optional)
+    pub expand_bundled: Option<bool>,
+    /// Whether to include cross application accesses
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<&'a str>,
+    pub cross_app: Option<bool>,
 }
 
-impl<'a> ListAccessRequest<'a> {
+impl Default for ListAccessRequest {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            offset: 0,
+            expand_bundled: None,
+            cross_app: None,
+        }
+    }
+}
+
+impl ListAccessRequest {
     /// Create a new access list request.
     pub fn new() -> Self {
         Self::default()
@@ -107,341 +214,554 @@ impl<'a> ListAccessRequest<'a> {
 
     /// Set the limit for pagination.
     pub fn with_limit(mut self, limit: usize) -> Self {
-        self.limit = Some(limit);
+        self.limit = limit;
         self
     }
 
     /// Set the offset for pagination.
     pub fn with_offset(mut self, offset: usize) -> Self {
-        self.offset = Some(offset);
+        self.offset = offset;
         self
     }
 
-    /// Filter by resource type.
-    pub fn with_resource_type(mut self, resource_type: &'a str) -> Self {
-        self.resource_type = Some(resource_type);
+    /// Set whether to expand bundled accesses.
+    pub fn with_expand_bundled(mut self, expand_bundled: bool) -> Self {
+        self.expand_bundled = Some(expand_bundled);
         self
     }
 
-    /// Filter by status.
-    pub fn with_status(mut self, status: &'a str) -> Self {
-        self.status = Some(status);
+    /// Set whether to include cross application accesses.
+    pub fn with_cross_app(mut self, cross_app: bool) -> Self {
+        self.cross_app = Some(cross_app);
         self
     }
 }
 
-/// Request parameters for listing all access grants.
-#[derive(Debug, Serialize, Default)]
-pub struct ListAllAccessRequest<'a> {
-    /// Maximum number of results to return (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<usize>,
-    /// Offset for pagination (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset: Option<usize>,
-    /// Filter by user UID (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub uid: Option<&'a str>,
-    /// Filter by resource ID (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_id: Option<&'a str>,
-    /// Filter by resource type (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<&'a str>,
-    /// Filter by status (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<&'a str>,
+/// Outcome of granting access to a user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum AccessGrantOutcome {
+    /// The access was granted
+    Granted,
+    /// The call granting the access failed
+    Failed { error: String },
 }
 
-impl<'a> ListAllAccessRequest<'a> {
-    /// Create a new list all access request.
-    pub fn new() -> Self {
-        Self::default()
+/// Outcome of granting access to a resource to a user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AccessGrantResult {
+    user: String,
+    rid: String,
+    #[serde(flatten)]
+    outcome: AccessGrantOutcome,
+}
+
+impl AccessGrantResult {
+    pub(super) fn new(user: &str, rid: &str, outcome: AccessGrantOutcome) -> Self {
+        Self {
+            user: user.to_string(),
+            rid: rid.to_string(),
+            outcome,
+        }
     }
 
-    /// Set the limit for pagination.
-    pub fn with_limit(mut self, limit: usize) -> Self {
-        self.limit = Some(limit);
-        self
+    /// Get the email address or UID of the user
+    pub fn user(&self) -> &str {
+        &self.user
     }
 
-    /// Set the offset for pagination.
-    pub fn with_offset(mut self, offset: usize) -> Self {
-        self.offset = Some(offset);
-        self
+    /// Get the resource ID
+    pub fn rid(&self) -> &str {
+        &self.rid
     }
 
-    /// Filter by user UID.
-    pub fn with_uid(mut self, uid: &'a str) -> Self {
-        self.uid = Some(uid);
-        self
+    /// Get the outcome
+    pub fn outcome(&self) -> &AccessGrantOutcome {
+        &self.outcome
     }
 
-    /// Filter by resource ID.
-    pub fn with_resource_id(mut self, resource_id: &'a str) -> Self {
-        self.resource_id = Some(resource_id);
-        self
+    /// Check if the access was granted
+    pub fn is_granted(&self) -> bool {
+        self.outcome == AccessGrantOutcome::Granted
     }
+}
 
-    /// Filter by resource type.
-    pub fn with_resource_type(mut self, resource_type: &'a str) -> Self {
-        self.resource_type = Some(resource_type);
-        self
+/// Outcome of granting access to many users, with one result per user and resource
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AccessGrantReport {
+    granted: usize,
+    results: Vec<AccessGrantResult>,
+}
+
+impl AccessGrantReport {
+    pub(super) fn push(&mut self, result: AccessGrantResult) {
+        self.results.push(result);
     }
 
-    /// Filter by status.
-    pub fn with_status(mut self, status: &'a str) -> Self {
-        self.status = Some(status);
-        self
+    pub(super) fn add_granted(&mut self, granted: usize) {
+        self.granted += granted;
+    }
+
+    /// Get the number of accesses granted, as reported by Piano
+    pub fn granted(&self) -> usize {
+        self.granted
+    }
+
+    /// Get the result of each user and resource
+    pub fn results(&self) -> &[AccessGrantResult] {
+        &self.results
+    }
+
+    /// Iterate over the users and resources the access could not be granted to
+    pub fn failures(&self) -> impl Iterator<Item = &AccessGrantResult> {
+        self.results.iter().filter(|result| !result.is_granted())
+    }
+
+    /// Check if the access was granted to every user
+    pub fn is_complete(&self) -> bool {
+        self.failures().next().is_none()
     }
 }
 
+/// Response wrapper for single access operations.
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct AccessResult {
+    #[serde(alias = "Access", alias = "AccessDTO")]
+    pub access: Access,
+}
+
 /// Response wrapper for access grant operations.
 #[derive(Debug, Deserialize, Clone)]
-pub(super) struct AccessGrantResult {
-    /// The access grant data - Piano API can return with different field names
-    #[serde(alias = "AccessGrant", alias = "access_grant")]
-    pub access_grant: AccessGrant,
+pub(super) struct AccessListResult {
+    #[serde(default, alias = "Access", alias = "access")]
+    pub accesses: Vec<Access>,
 }
 
-/// Response for access check operations.
+/// Response of `/publisher/resource/user/list`, of which only the total is read.
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ResourceUserListResult {}
+
+/// Response for access count operations.
 #[derive(Debug, Deserialize, Clone)]
-pub(super) struct AccessCheckResult {
-    /// Whether the user has access to the resource
-    #[serde(alias = "hasAccess", alias = "has_access")]
-    pub has_access: bool,
+pub(super) struct AccessCountResult {
+    pub data: i64,
 }
 
 /// Response for access list operations.
 #[derive(Debug, Deserialize, Clone)]
 pub struct ListAccessResult {
-    /// Array of access grants
-    #[serde(alias = "AccessGrants", alias = "access_grants")]
-    pub access_grants: Vec<AccessGrant>,
+    /// Array of accesses
+    #[serde(default, alias = "AccessDTO", alias = "accesses")]
+    pub accesses: Vec<Access>,
 }
 
-/// Represents an access grant in the Piano platform.
+/// Access of a user to a resource, as returned by the `/user/access` endpoints.
 ///
-/// Contains information about user access permissions for specific resources.
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faccess~2Fcheck)
 #[derive(Debug, Deserialize, Clone)]
-pub struct AccessGrant {
-    /// Access grant's unique identifier
-    access_grant_id: String,
-    /// User's unique identifier
-    uid: String,
-    /// Resource identifier
-    resource_id: String,
-    /// Type of resource
+pub struct Access {
+    access_id: String,
     #[serde(default)]
-    resource_type: Option<String>,
-    /// Access grant status
+    parent_access_id: Option<String>,
     #[serde(default)]
-    status: Option<String>,
-    /// Timestamp when access was granted (UNIX timestamp)
+    granted: bool,
     #[serde(default)]
-    granted_date: Option<i64>,
-    /// Timestamp when access expires (UNIX timestamp)
+    revoked: Option<bool>,
+    user: AccessUser,
+    resource: AccessResource,
     #[serde(default)]
-    expires_date: Option<i64>,
-    /// Custom parameters as JSON string (optional)
+    expire_date: Option<i64>,
     #[serde(default)]
-    custom_params: Option<String>,
+    start_date: Option<i64>,
+    #[serde(default)]
+    can_revoke_access: bool,
+    #[serde(default)]
+    custom_data: Option<String>,
 }
 
-impl AccessGrant {
-    /// Get the access grant's unique identifier.
-    pub fn access_grant_id(&self) -> &str {
-        &self.access_grant_id
+impl Access {
+    /// Get the access ID
+    pub fn access_id(&self) -> &str {
+        &self.access_id
     }
 
-    /// Get the user's unique identifier.
-    pub fn uid(&self) -> &str {
-        &self.uid
+    /// Get the parent access ID (for accesses to bundled resources)
+    pub fn parent_access_id(&self) -> Option<&str> {
+        self.parent_access_id.as_deref()
+    }
+
+    /// Check if the access is granted
+    pub fn is_granted(&self) -> bool {
+        self.granted
+    }
+
+    /// Check if the access is revoked
+    pub fn is_revoked(&self) -> bool {
+        self.revoked.unwrap_or_default()
     }
 
-    /// Get the resource identifier.
-    pub fn resource_id(&self) -> &str {
-        &self.resource_id
+    /// Get the user holding the access
+    pub fn user(&self) -> &AccessUser {
+        &self.user
     }
 
-    /// Get the resource type.
-    pub fn resource_type(&self) -> Option<&str> {
-        self.resource_type.as_deref()
+    /// Get the resource the access is granted to
+    pub fn resource(&self) -> &AccessResource {
+        &self.resource
     }
 
-    /// Get the access grant status.
-    pub fn status(&self) -> Option<&str> {
-        self.status.as_deref()
+    /// Get the expiration date (UNIX timestamp), `None` means unlimited
+    pub fn expire_date(&self) -> Option<i64> {
+        self.expire_date
     }
 
-    /// Get the timestamp when access was granted.
-    pub fn granted_date(&self) -> Option<i64> {
-        self.granted_date
+    /// Get the start date (UNIX timestamp)
+    pub fn start_date(&self) -> Option<i64> {
+        self.start_date
     }
 
-    /// Get the timestamp when access expires.
-    pub fn expires_date(&self) -> Option<i64> {
-        self.expires_date
+    /// Check if the access can be revoked
+    pub fn can_revoke_access(&self) -> bool {
+        self.can_revoke_access
     }
 
-    /// Get the custom parameters.
-    pub fn custom_params(&self) -> Option<&str> {
-        self.custom_params.as_deref()
+    /// Get the custom data
+    pub fn custom_data(&self) -> Option<&str> {
+        self.custom_data.as_deref()
     }
 
-    /// Check if the access grant is currently active (not expired).
+    /// Check if the access is currently active (granted, not revoked and not expired).
     pub fn is_active(&self) -> bool {
-        match (self.status.as_deref(), self.expires_date) {
-            (Some("ACTIVE"), None) => true,
-            (Some("ACTIVE"), Some(expires)) => {
+        if !self.granted || self.is_revoked() {
+            return false;
+        }
+        match self.expire_date {
+            None => true,
+            Some(expires) => {
                 let now = std::time::SystemTime::now()
                     .duration_since(std::time::UNIX_EPOCH)
                     .unwrap()
                     .as_secs() as i64;
                 expires > now
             }
-            _ => false,
         }
     }
 }
 
+/// User summary embedded in an [`Access`]
+#[derive(Debug, Deserialize, Clone)]
+pub struct AccessUser {
+    uid: String,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    first_name: Option<String>,
+    #[serde(default)]
+    last_name: Option<String>,
+    #[serde(default)]
+    personal_name: Option<String>,
+    #[serde(default)]
+    image1: Option<String>,
+}
+
+impl AccessUser {
+    /// Get the user's ID
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    /// Get the user's email address
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// Get the user's first name
+    pub fn first_name(&self) -> Option<&str> {
+        self.first_name.as_deref()
+    }
+
+    /// Get the user's last name
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
+    }
+
+    /// Get the user's name, ordered as per locale
+    pub fn personal_name(&self) -> Option<&str> {
+        self.personal_name.as_deref()
+    }
+
+    /// Get the URL of the user's profile image
+    pub fn image(&self) -> Option<&str> {
+        self.image1.as_deref()
+    }
+}
+
+/// Resource summary embedded in an [`Access`]
+#[derive(Debug, Deserialize, Clone)]
+pub struct AccessResource {
+    rid: String,
+    #[serde(default)]
+    aid: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    publish_date: Option<i64>,
+    #[serde(default)]
+    image_url: Option<String>,
+    #[serde(default)]
+    purchase_url: Option<String>,
+}
+
+impl AccessResource {
+    /// Get the resource ID
+    pub fn rid(&self) -> &str {
+        &self.rid
+    }
+
+    /// Get the application ID
+    pub fn aid(&self) -> Option<&str> {
+        self.aid.as_deref()
+    }
+
+    /// Get the resource name
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the resource description
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Get the publish date (UNIX timestamp)
+    pub fn publish_date(&self) -> Option<i64> {
+        self.publish_date
+    }
+
+    /// Get the URL of the resource image
+    pub fn image_url(&self) -> Option<&str> {
+        self.image_url.as_deref()
+    }
+
+    /// Get the URL of the purchase page
+    pub fn purchase_url(&self) -> Option<&str> {
+        self.purchase_url.as_deref()
+    }
+}
+
+fn serialize_str_slice<S>(slice: &Option<&[&str]>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match slice {
+        Some(slice) => serialize_joined(slice, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_joined<S>(slice: &&[&str], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&slice.join(","))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn access(granted: bool, expire_date: Option<i64>) -> Access {
+        serde_json::from_value(serde_json::json!({
+            "access_id": "AC1",
+            "granted": granted,
+            "user": { "uid": "user1" },
+            "resource": { "rid": "resource1" },
+            "expire_date": expire_date,
+        }))
+        .expect("Failed to deserialize access")
+    }
+
     #[test]
     fn test_grant_access_request_builder() {
         let req = GrantAccessRequest::new("user123", "article456")
-            .with_resource_type("article")
-            .with_expires(1234567890)
-            .with_custom_params(r#"{"source": "subscription"}"#);
+            .with_expire_date(1234567890)
+            .with_send_email(true)
+            .with_message("Welcome");
 
-        assert_eq!(req.uid, "user123");
-        assert_eq!(req.resource_id, "article456");
-        assert_eq!(req.resource_type, Some("article"));
-        assert_eq!(req.expires, Some(1234567890));
-        assert_eq!(req.custom_params, Some(r#"{"source": "subscription"}"#));
+        assert_eq!(req.uid, Some("user123"));
+        assert_eq!(req.rid, "article456");
+        assert_eq!(req.expire_date, Some(1234567890));
+        assert!(req.send_email);
+        assert_eq!(req.message, Some("Welcome"));
     }
 
     #[test]
-    fn test_revoke_access_request_builder() {
-        let req = RevokeAccessRequest::new("user123", "article456").with_resource_type("article");
-
-        assert_eq!(req.uid, "user123");
-        assert_eq!(req.resource_id, "article456");
-        assert_eq!(req.resource_type, Some("article"));
+    fn test_grant_access_by_emails_request() {
+        let emails = ["jane@example.com", "john@example.com"];
+        let req = GrantAccessRequest::by_emails(&emails, "article456");
+        assert_eq!(
+            serde_json::to_value(&req).expect("OK"),
+            serde_json::json!({
+                "rid": "article456",
+                "emails": "jane@example.com,john@example.com",
+                "send_email": false,
+            })
+        );
     }
 
     #[test]
-    fn test_access_grant_deserialization() {
-        let json = serde_json::json!({
-            "access_grant_id": "grant123",
-            "uid": "user123",
-            "resource_id": "article456",
-            "resource_type": "article",
-            "status": "ACTIVE",
-            "granted_date": 1234567890,
-            "expires_date": 1999999999
-        });
+    fn test_grant_to_users_params() {
+        let params = GrantToUsersParams {
+            rid: &["R1", "R2"],
+            emails: &["jane@example.com", "john@example.com"],
+            expire_date: Some(1999999999),
+            send_email: true,
+            message: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&params).expect("OK"),
+            serde_json::json!({
+                "rid": "R1,R2",
+                "emails": "jane@example.com,john@example.com",
+                "expire_date": 1999999999,
+                "send_email": true,
+            })
+        );
+    }
 
-        let grant: AccessGrant =
-            serde_json::from_value(json).expect("Failed to deserialize access grant");
-        assert_eq!(grant.access_grant_id(), "grant123");
-        assert_eq!(grant.uid(), "user123");
-        assert_eq!(grant.resource_id(), "article456");
-        assert_eq!(grant.resource_type(), Some("article"));
-        assert_eq!(grant.status(), Some("ACTIVE"));
-        assert_eq!(grant.granted_date(), Some(1234567890));
-        assert_eq!(grant.expires_date(), Some(1999999999));
+    #[test]
+    fn test_grant_access_to_users_request_builder() {
+        let uids = ["user1", "user2"];
+        let req = GrantAccessToUsersRequest::by_uids(&["R1"], &uids).with_batch_size(4);
+        assert!(matches!(req.users, AccessGrantees::Uids(uids) if uids.len() == 2));
+        assert_eq!(req.batch_size, 4);
+        assert!(!req.send_email);
     }
 
     #[test]
-    fn test_access_grant_is_active() {
-        let active_grant = AccessGrant {
-            access_grant_id: "grant1".to_string(),
-            uid: "user1".to_string(),
-            resource_id: "resource1".to_string(),
-            resource_type: Some("article".to_string()),
-            status: Some("ACTIVE".to_string()),
-            granted_date: Some(1234567890),
-            expires_date: Some(2999999999), // Far future
-            custom_params: None,
-        };
+    fn test_access_grant_report() {
+        let mut report = AccessGrantReport::default();
+        report.add_granted(2);
+        report.push(AccessGrantResult::new(
+            "jane@example.com",
+            "R1",
+            AccessGrantOutcome::Granted,
+        ));
+        report.push(AccessGrantResult::new(
+            "john@example.com",
+            "R1",
+            AccessGrantOutcome::Granted,
+        ));
+        assert!(report.is_complete());
+
+        report.push(AccessGrantResult::new(
+            "user3",
+            "R1",
+            AccessGrantOutcome::Failed {
+                error: "2004: User not found".to_string(),
+            },
+        ));
+        assert!(!report.is_complete());
+        assert_eq!(report.granted(), 2);
+        let failures: Vec<_> = report.failures().map(|result| result.user()).collect();
+        assert_eq!(failures, vec!["user3"]);
+        assert_eq!(
+            serde_json::to_value(&report.results()[2]).expect("OK"),
+            serde_json::json!({
+                "user": "user3",
+                "rid": "R1",
+                "outcome": "failed",
+                "error": "2004: User not found"
+            })
+        );
+    }
 
-        let expired_grant = AccessGrant {
-            access_grant_id: "grant2".to_string(),
-            uid: "user2".to_string(),
-            resource_id: "resource2".to_string(),
-            resource_type: Some("article".to_string()),
-            status: Some("ACTIVE".to_string()),
-            granted_date: Some(1234567890),
-            expires_date: Some(1234567891), // Past date
-            custom_params: None,
-        };
+    #[test]
+    fn test_access_deserialization() {
+        let json = serde_json::json!({
+            "access_id": "AC1",
+            "parent_access_id": null,
+            "granted": true,
+            "user": {
+                "uid": "PNI1",
+                "email": "user@example.com",
+                "first_name": "Jane",
+                "last_name": "Doe",
+                "personal_name": "Jane Doe",
+                "image1": null
+            },
+            "resource": {
+                "rid": "RID1",
+                "aid": "AID",
+                "name": "Premium",
+                "publish_date": 1700000000
+            },
+            "expire_date": null,
+            "start_date": 1700000000,
+            "can_revoke_access": true,
+            "custom_data": "{}"
+        });
 
-        let inactive_grant = AccessGrant {
-            access_grant_id: "grant3".to_string(),
-            uid: "user3".to_string(),
-            resource_id: "resource3".to_string(),
-            resource_type: Some("article".to_string()),
-            status: Some("REVOKED".to_string()),
-            granted_date: Some(1234567890),
-            expires_date: Some(2999999999),
-            custom_params: None,
-        };
+        let access: Access = serde_json::from_value(json).expect("Failed to deserialize access");
+        assert_eq!(access.access_id(), "AC1");
+        assert!(access.is_granted());
+        assert!(!access.is_revoked());
+        assert_eq!(access.user().uid(), "PNI1");
+        assert_eq!(access.resource().rid(), "RID1");
+        assert_eq!(access.expire_date(), None);
+        assert_eq!(access.start_date(), Some(1700000000));
+    }
 
-        assert!(active_grant.is_active());
-        assert!(!expired_grant.is_active());
-        assert!(!inactive_grant.is_active());
+    #[test]
+    fn test_access_is_active() {
+        assert!(access(true, None).is_active());
+        assert!(access(true, Some(2999999999)).is_active());
+        assert!(!access(true, Some(1234567891)).is_active());
+        assert!(!access(false, Some(2999999999)).is_active());
     }
 
     #[test]
-    fn test_access_check_result_deserialization() {
+    fn test_access_result_deserialization() {
         let json = serde_json::json!({
-            "has_access": true
+            "AccessDTO": {
+                "access_id": "AC1",
+                "granted": true,
+                "user": { "uid": "user1" },
+                "resource": { "rid": "resource1" }
+            }
         });
 
-        let result: AccessCheckResult =
+        let result: AccessResult =
             serde_json::from_value(json).expect("Failed to deserialize access check result");
-        assert!(result.has_access);
+        assert!(result.access.is_granted());
     }
 
     #[test]
     fn test_list_access_result_deserialization() {
         let json = serde_json::json!({
-            "access_grants": [
+            "accesses": [
                 {
-                    "access_grant_id": "grant1",
-                    "uid": "user1",
-                    "resource_id": "resource1",
-                    "status": "ACTIVE"
+                    "access_id": "grant1",
+                    "granted": true,
+                    "user": { "uid": "user1" },
+                    "resource": { "rid": "resource1" }
                 },
                 {
-                    "access_grant_id": "grant2",
-                    "uid": "user2",
-                    "resource_id": "resource2",
-                    "status": "REVOKED"
+                    "access_id": "grant2",
+                    "granted": false,
+                    "user": { "uid": "user1" },
+                    "resource": { "rid": "resource2" }
                 }
             ]
         });
 
         let result: ListAccessResult =
             serde_json::from_value(json).expect("Failed to deserialize list access result");
-        assert_eq!(result.access_grants.len(), 2);
-        assert_eq!(result.access_grants[0].access_grant_id(), "grant1");
-        assert_eq!(result.access_grants[1].access_grant_id(), "grant2");
-    }
-
-    #[test]
-    fn test_create_access_request_builder() {
-        let request = GrantAccessRequest::new("user123", "resource456")
-            .with_expires(1999999999)
-            .with_custom_params(r#"{"source": "subscription"}"#);
-
-        assert_eq!(request.uid, "user123");
-        assert_eq!(request.resource_id, "resource456");
-        assert_eq!(request.expires, Some(1999999999));
-        assert_eq!(request.custom_params, Some(r#"{"source": "subscription"}"#));
+        assert_eq!(result.accesses.len(), 2);
+        assert_eq!(result.accesses[0].access_id(), "grant1");
+        assert_eq!(result.accesses[1].access_id(), "grant2");
     }
 
     #[test]
@@ -449,12 +769,11 @@ mod tests {
         let request = ListAccessRequest::new()
             .with_limit(50)
             .with_offset(10)
-            .with_resource_type("article")
-            .with_status("active");
+            .with_expand_bundled(true);
 
-        assert_eq!(request.limit, Some(50));
-        assert_eq!(request.offset, Some(10));
-        assert_eq!(request.resource_type, Some("article"));
-        assert_eq!(request.status, Some("active"));
+        assert_eq!(request.limit, 50);
+        assert_eq!(request.offset, 10);
+        assert_eq!(request.expand_bundled, Some(true));
+        assert_eq!(request.cross_app, None);
     }
 }
@@ -1,6 +1,9 @@
 mod schema;
 pub use self::schema::*;
 
+/// Answers of users to consent boxes
+pub mod user_consent;
+
 use crate::{PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
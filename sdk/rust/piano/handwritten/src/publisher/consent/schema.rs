@@ -17,7 +17,7 @@ pub struct ListConsentsRequest<'a> {
     _lifetime: std::marker::PhantomData<&'a ()>,
 }
 
-impl<'a> ListConsentsRequest<'a> {
+impl ListConsentsRequest<'_> {
     /// Create a new list consents request
     pub fn new(offset: usize, limit: usize) -> Self {
         Self {
@@ -94,7 +94,7 @@ pub struct Consent {
 }
 
 /// Consent box type enum
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ConsentType {
     Registration,
@@ -0,0 +1,635 @@
+//! Consent state of users.
+//!
+//! Piano records an entry each time a user checks or unchecks a consent box at registration
+//! or checkout. A consent box whose field ID is enabled also stores the answer in the custom
+//! field named after its field ID, which is the source of truth kept by this module:
+//! [`UserConsentState`] reads the custom fields first and the latest entries second, and
+//! [`UserConsentState::diff`] turns consent updates, such as an offline opt-in, into the
+//! custom field values to set with `UpdateCustomFieldValuesRequest` of the Piano ID API.
+//!
+//! Piano only stores the answer in the custom field: the date and origin of an answer
+//! recorded this way are not kept, and its record reads as [`ConsentSource::CustomField`].
+//! Keep the applied [`ConsentDiff`] to audit offline opt-ins.
+//!
+//! ## Example
+//!
+//! Setting consents requires the `id` feature.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "id")]
+//! # async fn example(uid: &str) -> Result<(), piano_handwritten_api::Error> {
+//! use piano_handwritten_api::{
+//!     id::PianoIdAPI,
+//!     publisher::consent::user_consent::ConsentUpdate,
+//!     PianoAPI,
+//! };
+//!
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//! let id = PianoIdAPI::new(&api, "https://sandbox.piano.io/id/api/v1");
+//!
+//! if let Some(state) = api.user_consent_state(uid).await? {
+//!     for record in state.records() {
+//!         println!("{}: {} ({:?})", record.field_name(), record.accepted(), record.source());
+//!     }
+//! }
+//!
+//! // The user opted in to the newsletter on a paper form
+//! let diff = api
+//!     .update_user_consents(&id, uid, &[ConsentUpdate::new("Newsletter", true)])
+//!     .await?;
+//! println!("{} consents changed", diff.changes().len());
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::{Consent, ConsentType, ListConsentsRequest};
+#[cfg(feature = "id")]
+use crate::id::{custom_field::UpdateCustomFieldValuesRequest, PianoIdAPI};
+use crate::{
+    fetch_all_pages, invalid_input, publisher::user::CustomField, PianoAPI, PianoResponse,
+};
+
+/// Request to list the consent box entries of a user
+#[derive(Debug, Serialize)]
+pub struct ListConsentEntriesRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum number of results to return
+    pub limit: usize,
+}
+
+impl<'a> ListConsentEntriesRequest<'a> {
+    /// Create a new list consent entries request
+    pub fn new(uid: &'a str, offset: usize, limit: usize) -> Self {
+        Self { uid, offset, limit }
+    }
+}
+
+/// Response wrapper for consent entry list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConsentEntryListResult {
+    #[serde(alias = "ConsentBoxEntry")]
+    pub entries: Vec<ConsentEntry>,
+}
+
+/// A consent box checked or unchecked by a user
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ConsentEntry {
+    /// The name of the consent box field
+    pub field_name: String,
+    /// The displayed text of the consent box
+    pub display_text: String,
+    /// Whether the user checked the consent box
+    pub entry: bool,
+    /// The date when the entry was created
+    pub create_date: i64,
+    /// Where the consent box was displayed
+    pub r#type: ConsentType,
+}
+
+/// Origin of a consent record
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentSource {
+    /// A consent box at registration
+    Registration,
+    /// A consent box at checkout
+    Checkout,
+    /// The custom field of the consent box, without an entry giving the same answer
+    CustomField,
+}
+
+impl From<ConsentType> for ConsentSource {
+    fn from(consent_type: ConsentType) -> Self {
+        match consent_type {
+            ConsentType::Checkout => Self::Checkout,
+            ConsentType::Registration | ConsentType::Other => Self::Registration,
+        }
+    }
+}
+
+/// Answer of a user to a consent box
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct UserConsentRecord {
+    consent_id: String,
+    field_name: String,
+    field_id: Option<String>,
+    accepted: bool,
+    date: Option<i64>,
+    source: ConsentSource,
+}
+
+impl UserConsentRecord {
+    pub fn consent_id(&self) -> &str {
+        &self.consent_id
+    }
+    /// Get the name of the consent box field
+    pub fn field_name(&self) -> &str {
+        &self.field_name
+    }
+    /// Get the custom field storing the answer, if the field ID of the consent box is enabled
+    pub fn field_id(&self) -> Option<&str> {
+        self.field_id.as_deref()
+    }
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+    /// Get the date of the answer, unknown when it only comes from the custom field
+    pub fn date(&self) -> Option<i64> {
+        self.date
+    }
+    pub fn source(&self) -> &ConsentSource {
+        &self.source
+    }
+}
+
+/// Answers of a user to the enabled consent boxes of the application
+///
+/// Consent boxes the user never answered have no record.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct UserConsentState {
+    uid: String,
+    records: Vec<UserConsentRecord>,
+}
+
+impl UserConsentState {
+    /// Combine the consent boxes of the application with the custom fields and the consent
+    /// box entries of a user
+    ///
+    /// The value of the custom field of a consent box wins over its entries. The record
+    /// keeps the source and date of the latest entry giving the same answer.
+    pub fn new(
+        uid: &str,
+        consents: &[Consent],
+        custom_fields: &[CustomField],
+        entries: &[ConsentEntry],
+    ) -> Self {
+        let records = consents
+            .iter()
+            .filter(|consent| consent.is_enabled())
+            .filter_map(|consent| {
+                let field_id = consent
+                    .is_field_id_enabled()
+                    .then(|| consent.field_id().to_string());
+                let custom_field_value = field_id.as_deref().and_then(|field_id| {
+                    custom_fields
+                        .iter()
+                        .find(|field| field.field_name() == field_id)
+                        .and_then(custom_field_bool)
+                });
+                let latest_entry = |accepted: Option<bool>| {
+                    entries
+                        .iter()
+                        .filter(|entry| entry.field_name == consent.field_name())
+                        .filter(|entry| accepted.is_none_or(|accepted| entry.entry == accepted))
+                        .max_by_key(|entry| entry.create_date)
+                };
+                let (accepted, date, source) = match custom_field_value {
+                    Some(accepted) => match latest_entry(Some(accepted)) {
+                        Some(entry) => (accepted, Some(entry.create_date), entry.r#type.into()),
+                        None => (accepted, None, ConsentSource::CustomField),
+                    },
+                    None => {
+                        let entry = latest_entry(None)?;
+                        (entry.entry, Some(entry.create_date), entry.r#type.into())
+                    }
+                };
+                Some(UserConsentRecord {
+                    consent_id: consent.consent_id().to_string(),
+                    field_name: consent.field_name().to_string(),
+                    field_id,
+                    accepted,
+                    date,
+                    source,
+                })
+            })
+            .collect();
+        Self {
+            uid: uid.to_string(),
+            records,
+        }
+    }
+
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    pub fn records(&self) -> &[UserConsentRecord] {
+        &self.records
+    }
+
+    /// Get the answer to the consent box with the given field name
+    pub fn record(&self, field_name: &str) -> Option<&UserConsentRecord> {
+        self.records
+            .iter()
+            .find(|record| record.field_name == field_name)
+    }
+
+    /// Check whether the user accepted the consent box with the given field name
+    pub fn is_accepted(&self, field_name: &str) -> bool {
+        self.record(field_name)
+            .is_some_and(|record| record.accepted)
+    }
+
+    /// Compute the custom field values to set so that the state matches the updates
+    ///
+    /// Updates are matched to consent boxes by field name. Updates which do not change the
+    /// answer are skipped.
+    ///
+    /// Returns an [`std::io::ErrorKind::InvalidInput`] error if an update targets an unknown or
+    /// disabled consent box, or a consent box without an enabled field ID.
+    pub fn diff(
+        &self,
+        consents: &[Consent],
+        updates: &[ConsentUpdate],
+    ) -> Result<ConsentDiff, crate::Error> {
+        let mut changes: Vec<ConsentChange> = vec![];
+        for update in updates {
+            let consent = consents
+                .iter()
+                .find(|consent| consent.field_name() == update.field_name)
+                .filter(|consent| consent.is_enabled())
+                .ok_or_else(|| {
+                    invalid_input(format!(
+                        "{} is not an enabled consent box",
+                        update.field_name
+                    ))
+                })?;
+            if !consent.is_field_id_enabled() {
+                return Err(invalid_input(format!(
+                    "consent box {} is not stored in a custom field",
+                    update.field_name
+                )));
+            }
+            changes.retain(|change| change.field_name != update.field_name);
+            let previous = self
+                .record(&update.field_name)
+                .map(|record| record.accepted);
+            if previous == Some(update.accepted) {
+                continue;
+            }
+            changes.push(ConsentChange {
+                field_name: update.field_name.clone(),
+                field_id: consent.field_id().to_string(),
+                previous,
+                update: update.clone(),
+            });
+        }
+        Ok(ConsentDiff { changes })
+    }
+}
+
+/// Answer of a user to set on a consent box
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ConsentUpdate {
+    /// The name of the consent box field
+    pub field_name: String,
+    pub accepted: bool,
+}
+
+impl ConsentUpdate {
+    pub fn new(field_name: &str, accepted: bool) -> Self {
+        Self {
+            field_name: field_name.to_string(),
+            accepted,
+        }
+    }
+}
+
+/// Change of the answer to a consent box
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct ConsentChange {
+    pub field_name: String,
+    /// The custom field storing the answer
+    pub field_id: String,
+    /// The previous answer, `None` if the user never answered
+    pub previous: Option<bool>,
+    pub update: ConsentUpdate,
+}
+
+/// Changes to write to the consent custom fields of a user
+#[derive(Debug, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct ConsentDiff {
+    changes: Vec<ConsentChange>,
+}
+
+impl ConsentDiff {
+    pub fn changes(&self) -> &[ConsentChange] {
+        &self.changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get the custom field values to set, as pairs of field name and value
+    ///
+    /// Pass them to `UpdateCustomFieldValuesRequest::new` of the Piano ID API.
+    pub fn custom_field_values(&self) -> Vec<(&str, &str)> {
+        self.changes
+            .iter()
+            .map(|change| {
+                let value = if change.update.accepted {
+                    "true"
+                } else {
+                    "false"
+                };
+                (change.field_id.as_str(), value)
+            })
+            .collect()
+    }
+}
+
+fn custom_field_bool(field: &CustomField) -> Option<bool> {
+    field
+        .as_bool()
+        .or_else(|| field.value()?.trim().parse::<bool>().ok())
+}
+
+impl PianoAPI {
+    /// Get user's consent box entries
+    ///
+    /// Lists the consent boxes checked or unchecked by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The user ID and the page to list
+    ///
+    /// # Returns
+    ///
+    /// Returns the consent box entries of the page.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconsent~2Fentry~2Flist) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_consent_entries(
+        &self,
+        params: &ListConsentEntriesRequest<'_>,
+    ) -> Result<Vec<ConsentEntry>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/consent/entry/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<ConsentEntryListResult>>()
+            .await?
+            .value()?;
+        Ok(result.entries)
+    }
+
+    /// Get the answers of a user to the consent boxes of the application
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if the user is not found.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn user_consent_state(
+        &self,
+        uid: &str,
+    ) -> Result<Option<UserConsentState>, crate::Error> {
+        let Some(user) = self.get_user(uid).await? else {
+            return Ok(None);
+        };
+        let consents = self.list_all_consents().await?;
+        let entries = self.list_all_consent_entries(uid).await?;
+        Ok(Some(UserConsentState::new(
+            uid,
+            &consents,
+            user.custom_fields(),
+            &entries,
+        )))
+    }
+
+    /// Record the answers of a user to consent boxes
+    ///
+    /// Writes the changed answers into the custom fields of the consent boxes with a single
+    /// [`PianoIdAPI::update_custom_field_values`] request. Nothing is sent when no answer
+    /// changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The Piano ID client writing the custom fields
+    /// * `uid` - The user ID
+    /// * `updates` - The answers to record
+    ///
+    /// # Returns
+    ///
+    /// Returns the applied changes, an [`std::io::ErrorKind::NotFound`] error if the user is not found
+    /// and an [`std::io::ErrorKind::InvalidInput`] error if an update cannot be stored.
+    #[cfg(feature = "id")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, id)))]
+    pub async fn update_user_consents(
+        &self,
+        id: &PianoIdAPI,
+        uid: &str,
+        updates: &[ConsentUpdate],
+    ) -> Result<ConsentDiff, crate::Error> {
+        let user = self.get_user(uid).await?.ok_or_else(|| {
+            Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("user {uid} not found"),
+            ))
+        })?;
+        let consents = self.list_all_consents().await?;
+        let entries = self.list_all_consent_entries(uid).await?;
+        let state = UserConsentState::new(uid, &consents, user.custom_fields(), &entries);
+        let diff = state.diff(&consents, updates)?;
+        if !diff.is_empty() {
+            let values = diff.custom_field_values();
+            id.update_custom_field_values(&UpdateCustomFieldValuesRequest::new(uid, &values))
+                .await?;
+        }
+        Ok(diff)
+    }
+
+    async fn list_all_consents(&self) -> Result<Vec<Consent>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListConsentsRequest::new(offset, 100);
+                async move { self.list_consents(&params).await }
+            },
+            |page| page.consents,
+        )
+        .await
+    }
+
+    async fn list_all_consent_entries(&self, uid: &str) -> Result<Vec<ConsentEntry>, crate::Error> {
+        const LIMIT: usize = 100;
+        let mut entries = vec![];
+        loop {
+            let page = self
+                .list_consent_entries(&ListConsentEntriesRequest::new(uid, entries.len(), LIMIT))
+                .await?;
+            let count = page.len();
+            entries.extend(page);
+            if count < LIMIT {
+                break;
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn consent(field_name: &str, field_id: Option<&str>) -> Consent {
+        serde_json::from_value(json!({
+            "consent_id": format!("CB-{field_name}"),
+            "field_name": field_name,
+            "field_id": field_id.unwrap_or_default(),
+            "display_text": "",
+            "error_message": "",
+            "type": "registration",
+            "pre_checked": false,
+            "required": false,
+            "enabled": true,
+            "field_id_enabled": field_id.is_some()
+        }))
+        .expect("OK")
+    }
+
+    fn custom_field(field_name: &str, value: &str) -> CustomField {
+        serde_json::from_value(json!({
+            "archived": false,
+            "attribute": {},
+            "dataType": "BOOLEAN",
+            "editable": true,
+            "fieldName": field_name,
+            "title": field_name,
+            "value": value
+        }))
+        .expect("OK")
+    }
+
+    fn entry(field_name: &str, accepted: bool, create_date: i64, r#type: &str) -> ConsentEntry {
+        serde_json::from_value(json!({
+            "field_name": field_name,
+            "display_text": "",
+            "entry": accepted,
+            "create_date": create_date,
+            "type": r#type
+        }))
+        .expect("OK")
+    }
+
+    fn consents() -> Vec<Consent> {
+        vec![
+            consent("Newsletter", Some("newsletter_opt_in")),
+            consent("Terms", None),
+            consent("Partners", Some("partners_opt_in")),
+        ]
+    }
+
+    #[test]
+    fn test_user_consent_state() {
+        let state = UserConsentState::new(
+            "U1",
+            &consents(),
+            &[custom_field("newsletter_opt_in", "false")],
+            &[
+                entry("Newsletter", true, 100, "registration"),
+                entry("Newsletter", false, 200, "checkout"),
+                entry("Newsletter", true, 300, "checkout"),
+                entry("Terms", true, 100, "registration"),
+            ],
+        );
+        let newsletter = state.record("Newsletter").expect("record");
+        assert!(!newsletter.accepted());
+        assert_eq!(newsletter.date(), Some(200));
+        assert_eq!(newsletter.source(), &ConsentSource::Checkout);
+        assert_eq!(newsletter.field_id(), Some("newsletter_opt_in"));
+
+        let terms = state.record("Terms").expect("record");
+        assert!(terms.accepted());
+        assert_eq!(terms.source(), &ConsentSource::Registration);
+        assert_eq!(terms.field_id(), None);
+
+        assert!(state.record("Partners").is_none());
+
+        let state = UserConsentState::new(
+            "U1",
+            &consents(),
+            &[custom_field("partners_opt_in", "true")],
+            &[],
+        );
+        assert!(state.is_accepted("Partners"));
+        assert_eq!(
+            state.record("Partners").expect("record").source(),
+            &ConsentSource::CustomField
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let consents = consents();
+        let state = UserConsentState::new(
+            "U1",
+            &consents,
+            &[custom_field("newsletter_opt_in", "true")],
+            &[],
+        );
+        let diff = state
+            .diff(
+                &consents,
+                &[
+                    ConsentUpdate::new("Newsletter", true),
+                    ConsentUpdate::new("Partners", false),
+                    ConsentUpdate::new("Partners", true),
+                ],
+            )
+            .expect("OK");
+        assert_eq!(diff.changes().len(), 1);
+        assert_eq!(diff.changes()[0].previous, None);
+        assert_eq!(
+            diff.custom_field_values(),
+            vec![("partners_opt_in", "true")]
+        );
+
+        assert!(state
+            .diff(&consents, &[ConsentUpdate::new("Newsletter", true)])
+            .expect("OK")
+            .is_empty());
+        assert_eq!(
+            state
+                .diff(&consents, &[ConsentUpdate::new("Terms", true)])
+                .expect_err("invalid")
+                .to_string(),
+            "consent box Terms is not stored in a custom field"
+        );
+        assert!(state
+            .diff(&consents, &[ConsentUpdate::new("Unknown", true)])
+            .is_err());
+    }
+
+    #[test]
+    fn test_consent_update_codec() {
+        let update = ConsentUpdate::new("Newsletter", true);
+        let value = serde_json::to_value(&update).expect("OK");
+        assert_eq!(
+            value,
+            json!({
+                "field_name": "Newsletter",
+                "accepted": true
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<ConsentUpdate>(value).expect("OK"),
+            update
+        );
+    }
+}
@@ -0,0 +1,267 @@
+/// Conversion endpoints for Piano Publisher API.
+///
+/// This module provides functionality to look up term conversions and to record
+/// conversions made outside of Piano checkout.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use futures_util::TryStreamExt;
+/// use piano_handwritten_api::{PianoAPI, publisher::conversion::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// // Record a conversion made in our own checkout
+/// let request = CreateCustomConversionRequest::new("term_id", "user_uid")
+///     .with_access_period(30)
+///     .with_tracking_id("order-1");
+/// let conversion = api.create_custom_conversion(&request).await?;
+///
+/// // Iterate over all conversions of a user
+/// let params = ListConversionRequest::new().with_uid("user_uid");
+/// let conversions: Vec<Conversion> = api.list_conversions_stream(&params).try_collect().await?;
+/// # Ok(())
+/// # }
+/// ```
+mod schema;
+pub use self::schema::*;
+
+use futures_util::{stream, Stream, TryStreamExt};
+
+use crate::{Empty, PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Get a conversion.
+    ///
+    /// Returns `None` if the conversion is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_conversion(
+        &self,
+        params: &GetConversionRequest<'_>,
+    ) -> Result<Option<Conversion>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/conversion/get", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.conversion))
+    }
+
+    /// Get the last conversion of a user, resource or subscription.
+    ///
+    /// Returns `None` if there is no such conversion.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2FlastAccess)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_last_access_conversion(
+        &self,
+        params: &LastAccessConversionRequest<'_>,
+    ) -> Result<Option<Conversion>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/conversion/lastAccess", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.conversion))
+    }
+
+    /// Get the analytics data of a conversion.
+    ///
+    /// Returns `None` if the conversion is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2Fdata~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_conversion_data(
+        &self,
+        term_conversion_id: &str,
+    ) -> Result<Option<ConversionData>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/conversion/data/get", self.endpoint))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("term_conversion_id", term_conversion_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionDataResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.conversion_data))
+    }
+
+    /// List conversions in the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_conversions(
+        &self,
+        params: &ListConversionRequest<'_>,
+    ) -> Result<PianoPaginated<ListConversionResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/conversion/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListConversionResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// List all conversions matching the request as a stream.
+    ///
+    /// Pages are requested lazily, starting from the offset of `params`, until all
+    /// conversions have been returned.
+    pub fn list_conversions_stream<'a>(
+        &'a self,
+        params: &ListConversionRequest<'a>,
+    ) -> impl Stream<Item = Result<Conversion, crate::Error>> + 'a {
+        stream::try_unfold(Some(params.clone()), move |params| async move {
+            let Some(params) = params else {
+                return Ok::<_, crate::Error>(None);
+            };
+            let page = self.list_conversions(&params).await?;
+            let next = page.next_offset().map(|offset| params.with_offset(offset));
+            let conversions = stream::iter(page.value.conversions.into_iter().map(Ok));
+            Ok(Some((conversions, next)))
+        })
+        .try_flatten()
+    }
+
+    /// Count conversions in the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2Fcount)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn count_conversions(&self, term_id: Option<&str>) -> Result<usize, crate::Error> {
+        let mut query = vec![("aid", self.app_id.as_str())];
+        if let Some(term_id) = term_id {
+            query.push(("term_id", term_id));
+        }
+        let result = self
+            .client
+            .get(format!("{}/publisher/conversion/count", self.endpoint))
+            .query(&query)
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionCountResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Create a custom conversion, granting access to the term's resource.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Fcustom~2Fcreate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_custom_conversion(
+        &self,
+        params: &CreateCustomConversionRequest<'_>,
+    ) -> Result<Conversion, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/conversion/custom/create",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionResult>>()
+            .await?
+            .value()?;
+        Ok(result.conversion)
+    }
+
+    /// Create an external conversion, checked against the external API of the term.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Fexternal~2Fcreate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_external_conversion(
+        &self,
+        params: &CreateExternalConversionRequest<'_>,
+    ) -> Result<Conversion, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/conversion/external/create",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionResult>>()
+            .await?
+            .value()?;
+        Ok(result.conversion)
+    }
+
+    /// Create a registration conversion.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Fregistration~2Fcreate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_registration_conversion(
+        &self,
+        params: &CreateRegistrationConversionRequest<'_>,
+    ) -> Result<Conversion, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/conversion/registration/create",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<ConversionResult>>()
+            .await?
+            .value()?;
+        Ok(result.conversion)
+    }
+
+    /// Log a conversion made in a third-party app.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Flog)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn log_conversion(
+        &self,
+        params: &LogConversionRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        self.client
+            .post(format!("{}/publisher/conversion/log", self.endpoint))
+            .form(params)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+}
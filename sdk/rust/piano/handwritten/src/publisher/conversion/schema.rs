@@ -0,0 +1,926 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to get a conversion
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2Fget)
+#[derive(Debug, Serialize, Default)]
+pub struct GetConversionRequest<'a> {
+    /// The term conversion ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term_conversion_id: Option<&'a str>,
+    /// The access ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_id: Option<&'a str>,
+    /// Whether this term conversion is the latest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_last_term_conversion: Option<bool>,
+}
+
+impl<'a> GetConversionRequest<'a> {
+    /// Create a request to get a conversion by ID
+    pub fn by_id(term_conversion_id: &'a str) -> Self {
+        Self {
+            term_conversion_id: Some(term_conversion_id),
+            ..Default::default()
+        }
+    }
+
+    /// Create a request to get the conversion which granted an access
+    pub fn by_access_id(access_id: &'a str) -> Self {
+        Self {
+            access_id: Some(access_id),
+            ..Default::default()
+        }
+    }
+
+    /// Only return the conversion if it is the latest one
+    pub fn with_last_term_conversion(mut self, is_last_term_conversion: bool) -> Self {
+        self.is_last_term_conversion = Some(is_last_term_conversion);
+        self
+    }
+}
+
+/// Request to get the last conversion
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2FlastAccess)
+#[derive(Debug, Serialize, Default)]
+pub struct LastAccessConversionRequest<'a> {
+    /// The resource ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rid: Option<&'a str>,
+    /// The user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+    /// The user subscription ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_id: Option<&'a str>,
+}
+
+impl<'a> LastAccessConversionRequest<'a> {
+    /// Create a new last conversion request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by resource ID
+    pub fn with_rid(mut self, rid: &'a str) -> Self {
+        self.rid = Some(rid);
+        self
+    }
+
+    /// Filter by user ID
+    pub fn with_uid(mut self, uid: &'a str) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Filter by user subscription ID
+    pub fn with_subscription_id(mut self, subscription_id: &'a str) -> Self {
+        self.subscription_id = Some(subscription_id);
+        self
+    }
+}
+
+/// Request to list conversions in the application
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fconversion~2Flist)
+#[derive(Debug, Serialize, Clone)]
+pub struct ListConversionRequest<'a> {
+    /// The user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+    /// Date from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<i64>,
+    /// Date to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<i64>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListConversionRequest<'a> {
+    /// Create a new list conversions request
+    pub fn new() -> Self {
+        Self {
+            uid: None,
+            date_from: None,
+            date_to: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Filter by user ID
+    pub fn with_uid(mut self, uid: &'a str) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Filter by conversion date
+    pub fn with_date_range(mut self, date_from: i64, date_to: i64) -> Self {
+        self.date_from = Some(date_from);
+        self.date_to = Some(date_to);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListConversionRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State of the user at the time of the conversion
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserState {
+    Na,
+    Anon,
+    Registered,
+    HasActiveAccess,
+}
+
+/// Page and browser context of a conversion, used for analytics
+#[derive(Debug, Serialize, Default)]
+pub struct ConversionContext<'a> {
+    /// The custom parameters (any key-value pairs) to save, as a JSON object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_params: Option<&'a str>,
+    /// The unique browser ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser_id: Option<&'a str>,
+    /// Page title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_title: Option<&'a str>,
+    /// The URL of the page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+    /// The page referer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referer: Option<&'a str>,
+    /// The author of the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_author: Option<&'a str>,
+    /// When the content was published
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_created: Option<&'a str>,
+    /// The section for the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_section: Option<&'a str>,
+    /// The type of the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<&'a str>,
+    /// Comma-separated list of the tags of the page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<&'a str>,
+    /// Previous C1X user segments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_user_segments: Option<&'a str>,
+    /// User state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_state: Option<UserState>,
+    /// The conversion consents in JSON format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cookie_consents: Option<&'a str>,
+}
+
+impl<'a> ConversionContext<'a> {
+    /// Create an empty conversion context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the custom parameters, as a JSON object
+    pub fn with_custom_params(mut self, custom_params: &'a str) -> Self {
+        self.custom_params = Some(custom_params);
+        self
+    }
+
+    /// Set the unique browser ID
+    pub fn with_browser_id(mut self, browser_id: &'a str) -> Self {
+        self.browser_id = Some(browser_id);
+        self
+    }
+
+    /// Set the page title and URL
+    pub fn with_page(mut self, page_title: &'a str, url: &'a str) -> Self {
+        self.page_title = Some(page_title);
+        self.url = Some(url);
+        self
+    }
+
+    /// Set the page referer
+    pub fn with_referer(mut self, referer: &'a str) -> Self {
+        self.referer = Some(referer);
+        self
+    }
+
+    /// Set the comma-separated list of the tags of the page
+    pub fn with_tags(mut self, tags: &'a str) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Set the user state
+    pub fn with_user_state(mut self, user_state: UserState) -> Self {
+        self.user_state = Some(user_state);
+        self
+    }
+
+    /// Set the conversion consents in JSON format
+    pub fn with_cookie_consents(mut self, cookie_consents: &'a str) -> Self {
+        self.cookie_consents = Some(cookie_consents);
+        self
+    }
+}
+
+/// Request to create a custom conversion
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Fcustom~2Fcreate)
+#[derive(Debug, Serialize)]
+pub struct CreateCustomConversionRequest<'a> {
+    /// The term ID
+    pub term_id: &'a str,
+    /// The user ID
+    pub uid: &'a str,
+    /// The duration of the access being provided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_period: Option<i64>,
+    /// Whether to provide unlimited access
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlimited_access: Option<bool>,
+    /// Whether to extend the existing access
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extend_existing: Option<bool>,
+    /// The conversion ID to track in external systems
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_id: Option<&'a str>,
+    #[serde(flatten)]
+    pub context: ConversionContext<'a>,
+}
+
+impl<'a> CreateCustomConversionRequest<'a> {
+    /// Create a new custom conversion request
+    pub fn new(term_id: &'a str, uid: &'a str) -> Self {
+        Self {
+            term_id,
+            uid,
+            access_period: None,
+            unlimited_access: None,
+            extend_existing: None,
+            tracking_id: None,
+            context: ConversionContext::default(),
+        }
+    }
+
+    /// Set the duration of the access being provided
+    pub fn with_access_period(mut self, access_period: i64) -> Self {
+        self.access_period = Some(access_period);
+        self
+    }
+
+    /// Provide unlimited access
+    pub fn with_unlimited_access(mut self, unlimited_access: bool) -> Self {
+        self.unlimited_access = Some(unlimited_access);
+        self
+    }
+
+    /// Extend the existing access
+    pub fn with_extend_existing(mut self, extend_existing: bool) -> Self {
+        self.extend_existing = Some(extend_existing);
+        self
+    }
+
+    /// Set the conversion ID to track in external systems
+    pub fn with_tracking_id(mut self, tracking_id: &'a str) -> Self {
+        self.tracking_id = Some(tracking_id);
+        self
+    }
+
+    /// Set the page and browser context of the conversion
+    pub fn with_context(mut self, context: ConversionContext<'a>) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Request to create an external conversion
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Fexternal~2Fcreate)
+#[derive(Debug, Serialize)]
+pub struct CreateExternalConversionRequest<'a> {
+    /// The term ID
+    pub term_id: &'a str,
+    /// The user ID
+    pub uid: &'a str,
+    /// A JSON object defining what fields have to be checked with the external API
+    pub fields: &'a str,
+    /// Whether to check the validity of the passed values or use them forcibly
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_validity: Option<bool>,
+    /// Access expiration date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_to: Option<i64>,
+    /// The conversion ID to track in external systems
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_id: Option<&'a str>,
+    #[serde(flatten)]
+    pub context: ConversionContext<'a>,
+}
+
+impl<'a> CreateExternalConversionRequest<'a> {
+    /// Create a new external conversion request
+    pub fn new(term_id: &'a str, uid: &'a str, fields: &'a str) -> Self {
+        Self {
+            term_id,
+            uid,
+            fields,
+            check_validity: None,
+            access_to: None,
+            tracking_id: None,
+            context: ConversionContext::default(),
+        }
+    }
+
+    /// Check the validity of the passed values with the external API
+    pub fn with_check_validity(mut self, check_validity: bool) -> Self {
+        self.check_validity = Some(check_validity);
+        self
+    }
+
+    /// Set the access expiration date
+    pub fn with_access_to(mut self, access_to: i64) -> Self {
+        self.access_to = Some(access_to);
+        self
+    }
+
+    /// Set the conversion ID to track in external systems
+    pub fn with_tracking_id(mut self, tracking_id: &'a str) -> Self {
+        self.tracking_id = Some(tracking_id);
+        self
+    }
+
+    /// Set the page and browser context of the conversion
+    pub fn with_context(mut self, context: ConversionContext<'a>) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Request to create a registration conversion
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Fregistration~2Fcreate)
+#[derive(Debug, Serialize)]
+pub struct CreateRegistrationConversionRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The term ID
+    pub term_id: &'a str,
+    /// The user's email address
+    pub email: &'a str,
+    /// The user's first name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    /// The user's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    /// The creation date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_date: Option<i64>,
+    /// The access start date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_start_date: Option<i64>,
+    /// Piano's browser cookie containing the browser ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tbc: Option<&'a str>,
+    /// The pageview ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pageview_id: Option<&'a str>,
+}
+
+impl<'a> CreateRegistrationConversionRequest<'a> {
+    /// Create a new registration conversion request
+    pub fn new(term_id: &'a str, uid: &'a str, email: &'a str) -> Self {
+        Self {
+            uid,
+            term_id,
+            email,
+            first_name: None,
+            last_name: None,
+            create_date: None,
+            access_start_date: None,
+            tbc: None,
+            pageview_id: None,
+        }
+    }
+
+    /// Set the user's first name
+    pub fn with_first_name(mut self, first_name: &'a str) -> Self {
+        self.first_name = Some(first_name);
+        self
+    }
+
+    /// Set the user's last name
+    pub fn with_last_name(mut self, last_name: &'a str) -> Self {
+        self.last_name = Some(last_name);
+        self
+    }
+
+    /// Set the creation date
+    pub fn with_create_date(mut self, create_date: i64) -> Self {
+        self.create_date = Some(create_date);
+        self
+    }
+
+    /// Set the access start date
+    pub fn with_access_start_date(mut self, access_start_date: i64) -> Self {
+        self.access_start_date = Some(access_start_date);
+        self
+    }
+
+    /// Set the browser and pageview IDs, as retrieved through the JS SDK
+    pub fn with_tracking(mut self, tbc: &'a str, pageview_id: &'a str) -> Self {
+        self.tbc = Some(tbc);
+        self.pageview_id = Some(pageview_id);
+        self
+    }
+}
+
+/// Category of a third-party conversion
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversionCategory {
+    Uncategorized,
+    Payment,
+    Subscription,
+    GrantAccess,
+    GiftReceived,
+    GiftPurchased,
+    Registration,
+    Adview,
+    Custom,
+    External,
+    FreePromoRedemption,
+    AdblockDisabled,
+    MicroConversionLink,
+    MicroConversionEvent,
+    Newsletter,
+}
+
+/// Request to log a third-party conversion
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fconversion~2Flog)
+#[derive(Debug, Serialize)]
+pub struct LogConversionRequest<'a> {
+    /// The conversion ID to track in external systems
+    pub tracking_id: &'a str,
+    /// The term ID
+    pub term_id: &'a str,
+    /// The term name
+    pub term_name: &'a str,
+    /// The step of the external checkout as defined by the client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step_number: Option<u32>,
+    /// The conversion category
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion_category: Option<ConversionCategory>,
+    /// The conversion amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    /// The conversion currency under the ISO 4217 standard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<&'a str>,
+    #[serde(flatten)]
+    pub context: ConversionContext<'a>,
+}
+
+impl<'a> LogConversionRequest<'a> {
+    /// Create a new third-party conversion log request
+    pub fn new(tracking_id: &'a str, term_id: &'a str, term_name: &'a str) -> Self {
+        Self {
+            tracking_id,
+            term_id,
+            term_name,
+            step_number: None,
+            conversion_category: None,
+            amount: None,
+            currency: None,
+            context: ConversionContext::default(),
+        }
+    }
+
+    /// Set the step of the external checkout
+    pub fn with_step_number(mut self, step_number: u32) -> Self {
+        self.step_number = Some(step_number);
+        self
+    }
+
+    /// Set the conversion category
+    pub fn with_conversion_category(mut self, conversion_category: ConversionCategory) -> Self {
+        self.conversion_category = Some(conversion_category);
+        self
+    }
+
+    /// Set the conversion amount and its ISO 4217 currency
+    pub fn with_amount(mut self, amount: f64, currency: &'a str) -> Self {
+        self.amount = Some(amount);
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Set the page and browser context of the conversion
+    pub fn with_context(mut self, context: ConversionContext<'a>) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// The term conversion type
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub enum ConversionType {
+    #[serde(rename = "Unknown")]
+    Unknown,
+    #[serde(rename = "Payment")]
+    Payment,
+    #[serde(rename = "Subscription")]
+    Subscription,
+    #[serde(rename = "Renewal")]
+    Renewal,
+    #[serde(rename = "Grant access")]
+    GrantAccess,
+    #[serde(rename = "Gift received")]
+    GiftReceived,
+    #[serde(rename = "Gift purchased")]
+    GiftPurchased,
+    #[serde(rename = "Registration")]
+    Registration,
+    #[serde(rename = "Adview")]
+    Adview,
+    #[serde(rename = "Custom")]
+    Custom,
+    #[serde(rename = "External")]
+    External,
+    #[serde(rename = "Free promo redemption")]
+    FreePromoRedemption,
+    #[serde(rename = "External renewal")]
+    ExternalRenewal,
+    #[serde(rename = "Provisional")]
+    Provisional,
+    #[serde(rename = "Free renewal")]
+    FreeRenewal,
+    #[serde(rename = "Bill")]
+    Bill,
+    #[serde(rename = "Upgrade term")]
+    UpgradeTerm,
+    #[serde(rename = "Licensee contract redemption")]
+    LicenseeContractRedemption,
+    #[serde(rename = "Licensee contract renewal")]
+    LicenseeContractRenewal,
+    #[serde(rename = "Dynamic term purchase")]
+    DynamicTermPurchase,
+    #[serde(rename = "Link")]
+    Link,
+    /// A conversion type without a dedicated variant
+    #[serde(other)]
+    Other,
+}
+
+/// Term conversion
+#[derive(Debug, Deserialize, Clone)]
+pub struct Conversion {
+    term_conversion_id: String,
+    #[serde(rename = "type")]
+    conversion_type: ConversionType,
+    #[serde(default)]
+    aid: Option<String>,
+    #[serde(default)]
+    create_date: Option<i64>,
+    #[serde(default)]
+    browser_id: Option<String>,
+    #[serde(default)]
+    billing_plan: Option<String>,
+    #[serde(default)]
+    price_after_discount: Option<String>,
+    #[serde(default)]
+    term: Option<serde_json::Value>,
+    #[serde(default)]
+    user_access: Option<serde_json::Value>,
+    #[serde(default)]
+    user_payment: Option<serde_json::Value>,
+    #[serde(default)]
+    subscription: Option<serde_json::Value>,
+    #[serde(default)]
+    promo_code: Option<serde_json::Value>,
+}
+
+impl Conversion {
+    /// Get the term conversion ID
+    pub fn term_conversion_id(&self) -> &str {
+        &self.term_conversion_id
+    }
+    /// Get the term conversion type
+    pub fn conversion_type(&self) -> &ConversionType {
+        &self.conversion_type
+    }
+    /// Get the application ID
+    pub fn aid(&self) -> Option<&str> {
+        self.aid.as_deref()
+    }
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<i64> {
+        self.create_date
+    }
+    /// Get the unique browser ID
+    pub fn browser_id(&self) -> Option<&str> {
+        self.browser_id.as_deref()
+    }
+    /// Get the billing plan of the subscription
+    pub fn billing_plan(&self) -> Option<&str> {
+        self.billing_plan.as_deref()
+    }
+    /// Get the price of the subscription after discount
+    pub fn price_after_discount(&self) -> Option<&str> {
+        self.price_after_discount.as_deref()
+    }
+    /// Get the converted term
+    pub fn term(&self) -> Option<&serde_json::Value> {
+        self.term.as_ref()
+    }
+    /// Get the ID of the converted term
+    pub fn term_id(&self) -> Option<&str> {
+        self.term.as_ref()?.get("term_id")?.as_str()
+    }
+    /// Get the access granted by the conversion
+    pub fn user_access(&self) -> Option<&serde_json::Value> {
+        self.user_access.as_ref()
+    }
+    /// Get the payment of the conversion
+    pub fn user_payment(&self) -> Option<&serde_json::Value> {
+        self.user_payment.as_ref()
+    }
+    /// Get the subscription created by the conversion
+    pub fn subscription(&self) -> Option<&serde_json::Value> {
+        self.subscription.as_ref()
+    }
+    /// Get the promo code redeemed by the conversion
+    pub fn promo_code(&self) -> Option<&serde_json::Value> {
+        self.promo_code.as_ref()
+    }
+}
+
+/// Analytics data of a term conversion
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConversionData {
+    #[serde(default)]
+    aid: Option<String>,
+    #[serde(default)]
+    offer_id: Option<String>,
+    #[serde(default)]
+    term_id: Option<String>,
+    #[serde(default)]
+    offer_template_id: Option<String>,
+    #[serde(default)]
+    template_id: Option<String>,
+    #[serde(default)]
+    uid: Option<String>,
+    #[serde(default)]
+    user_country: Option<String>,
+    #[serde(default)]
+    user_region: Option<String>,
+    #[serde(default)]
+    user_city: Option<String>,
+    #[serde(default)]
+    zip: Option<String>,
+    #[serde(default)]
+    user_agent: Option<String>,
+    #[serde(default)]
+    locale: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    browser: Option<String>,
+    #[serde(default)]
+    platform: Option<String>,
+    #[serde(default)]
+    operating_system: Option<String>,
+    #[serde(default)]
+    tags: Option<String>,
+    #[serde(default)]
+    content_created: Option<String>,
+    #[serde(default)]
+    content_author: Option<String>,
+    #[serde(default)]
+    content_section: Option<String>,
+    #[serde(default)]
+    campaigns: Vec<String>,
+}
+
+impl ConversionData {
+    /// Get the application ID
+    pub fn aid(&self) -> Option<&str> {
+        self.aid.as_deref()
+    }
+    /// Get the offer ID
+    pub fn offer_id(&self) -> Option<&str> {
+        self.offer_id.as_deref()
+    }
+    /// Get the term ID
+    pub fn term_id(&self) -> Option<&str> {
+        self.term_id.as_deref()
+    }
+    /// Get the offer template ID
+    pub fn offer_template_id(&self) -> Option<&str> {
+        self.offer_template_id.as_deref()
+    }
+    /// Get the template ID
+    pub fn template_id(&self) -> Option<&str> {
+        self.template_id.as_deref()
+    }
+    /// Get the user ID
+    pub fn uid(&self) -> Option<&str> {
+        self.uid.as_deref()
+    }
+    /// Get the country of the user who converted the term
+    pub fn user_country(&self) -> Option<&str> {
+        self.user_country.as_deref()
+    }
+    /// Get the region of the user who converted the term
+    pub fn user_region(&self) -> Option<&str> {
+        self.user_region.as_deref()
+    }
+    /// Get the city of the user who converted the term
+    pub fn user_city(&self) -> Option<&str> {
+        self.user_city.as_deref()
+    }
+    /// Get the zip code of the user who converted the term
+    pub fn zip(&self) -> Option<&str> {
+        self.zip.as_deref()
+    }
+    /// Get the user agent of the user who converted the term
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+    /// Get the user's locale
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+    /// Get the URL of the page
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+    /// Get the browser with which the conversion was made
+    pub fn browser(&self) -> Option<&str> {
+        self.browser.as_deref()
+    }
+    /// Get the platform with which the conversion was made
+    pub fn platform(&self) -> Option<&str> {
+        self.platform.as_deref()
+    }
+    /// Get the operating system with which the conversion was made
+    pub fn operating_system(&self) -> Option<&str> {
+        self.operating_system.as_deref()
+    }
+    /// Get the tags of the page
+    pub fn tags(&self) -> Option<&str> {
+        self.tags.as_deref()
+    }
+    /// Get when the content was published
+    pub fn content_created(&self) -> Option<&str> {
+        self.content_created.as_deref()
+    }
+    /// Get the author of the content
+    pub fn content_author(&self) -> Option<&str> {
+        self.content_author.as_deref()
+    }
+    /// Get the section for the content
+    pub fn content_section(&self) -> Option<&str> {
+        self.content_section.as_deref()
+    }
+    /// Get the campaigns
+    pub fn campaigns(&self) -> &[String] {
+        &self.campaigns
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ConversionResult {
+    pub conversion: Conversion,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ConversionDataResult {
+    pub conversion_data: ConversionData,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListConversionResult {
+    #[serde(alias = "TermConversionDTO")]
+    pub conversions: Vec<Conversion>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ConversionCountResult {
+    pub data: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PianoPaginated, PianoResponse};
+
+    #[test]
+    fn test_create_custom_conversion_request() {
+        let request = CreateCustomConversionRequest::new("TM1", "uid1")
+            .with_access_period(30)
+            .with_tracking_id("order-1")
+            .with_context(
+                ConversionContext::new()
+                    .with_browser_id("browser1")
+                    .with_user_state(UserState::Registered),
+            );
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "term_id": "TM1",
+                "uid": "uid1",
+                "access_period": 30,
+                "tracking_id": "order-1",
+                "browser_id": "browser1",
+                "user_state": "registered"
+            })
+        );
+    }
+
+    #[test]
+    fn test_log_conversion_request() {
+        let request = LogConversionRequest::new("order-1", "TM1", "Monthly")
+            .with_conversion_category(ConversionCategory::GrantAccess)
+            .with_amount(9.99, "USD");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "tracking_id": "order-1",
+                "term_id": "TM1",
+                "term_name": "Monthly",
+                "conversion_category": "grant_access",
+                "amount": 9.99,
+                "currency": "USD"
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_conversion_result() {
+        let response: PianoResponse<PianoPaginated<ListConversionResult>> =
+            serde_json::from_value(serde_json::json!({
+                "code": 0,
+                "ts": 1700000000,
+                "limit": 100,
+                "offset": 0,
+                "total": 2,
+                "count": 2,
+                "TermConversionDTO": [
+                    {
+                        "term_conversion_id": "TCV1",
+                        "type": "Grant access",
+                        "aid": "AID",
+                        "create_date": 1700000000,
+                        "browser_id": null,
+                        "term": { "term_id": "TM1", "name": "Monthly" }
+                    },
+                    {
+                        "term_conversion_id": "TCV2",
+                        "type": "Shared subscription child renew",
+                        "aid": "AID",
+                        "create_date": 1700000100
+                    }
+                ]
+            }))
+            .expect("OK");
+        let result = response.value().expect("OK");
+        assert!(!result.has_next());
+        let conversions = &result.value.conversions;
+        assert_eq!(
+            conversions[0].conversion_type(),
+            &ConversionType::GrantAccess
+        );
+        assert_eq!(conversions[0].term_id(), Some("TM1"));
+        assert_eq!(conversions[1].conversion_type(), &ConversionType::Other);
+        assert_eq!(conversions[1].term_id(), None);
+    }
+}
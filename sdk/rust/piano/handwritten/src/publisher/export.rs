@@ -0,0 +1,356 @@
+/// Downloadable report (export) endpoints for Piano Publisher API.
+///
+/// Reports are generated asynchronously: creating a report returns an [`Export`] task which
+/// is polled until it is completed, after which the CSV file can be downloaded.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use piano_handwritten_api::{PianoAPI, publisher::export::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// // Create a transactions report and read its rows once it is generated
+/// let request = TransactionsReportExportRequest::new("refunds")
+///     .with_transactions_type(TransactionsType::Refunds)
+///     .with_date_range(1700000000, 1710000000)
+///     .v2();
+/// let rows: Vec<TransactionsReportRow> = api
+///     .run_export_and_download(&request, Duration::from_secs(5), Duration::from_secs(600))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+mod row;
+mod schema;
+pub use self::row::*;
+pub use self::schema::*;
+
+use std::{
+    io::ErrorKind,
+    time::{Duration, Instant},
+};
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::{ByteStream, PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Create a report generation task.
+    ///
+    /// The typed request of each report kind determines the endpoint, see [`ExportReport`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_export<R: ExportReport>(&self, params: &R) -> Result<Export, crate::Error> {
+        let url = format!("{}{}", self.endpoint, params.path());
+        let response = if params.method() == Method::GET {
+            self.client
+                .get(url)
+                .query(&[("aid", &self.app_id)])
+                .query(params)
+                .send()
+                .await?
+        } else {
+            let req = PianoRequest {
+                aid: self.app_id.clone(),
+                inner: params,
+            };
+            self.client
+                .request(params.method(), url)
+                .form(&req)
+                .send()
+                .await?
+        };
+        let result = response
+            .json::<PianoResponse<ExportResult>>()
+            .await?
+            .value()?;
+        Ok(result.export)
+    }
+
+    /// Create a task to generate an AAM daily proof of access or AAM monthly report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2Faam~2Fdaily)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_aam_export(
+        &self,
+        params: &AamExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate an AAM monthly report with dates in the YYYY-MM-DD format.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2Faam~2Fmonthly~2Fv2)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_aam_monthly_export_v2(
+        &self,
+        params: &AamMonthlyExportV2Request<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate an access report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FaccessReportExport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_access_report_export(
+        &self,
+        params: &AccessReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a daily activity report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FdailyActivityReportExport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_daily_activity_report_export(
+        &self,
+        params: &DailyActivityReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a monthly activity report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FmonthlyActivityReportExport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_monthly_activity_report_export(
+        &self,
+        params: &MonthlyActivityReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a subscription details report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FsubscriptionDetailsReport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_subscription_details_report_export(
+        &self,
+        params: &SubscriptionDetailsReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a subscription summary report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FsubscriptionSummaryReport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_subscription_summary_report_export(
+        &self,
+        params: &SubscriptionSummaryReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a term change (upgrade) report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FtermChangeReportExport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_term_change_report_export(
+        &self,
+        params: &TermChangeReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a transactions report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FtransactionsReport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_transactions_report_export(
+        &self,
+        params: &TransactionsReportExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Create a task to generate a user search report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FuserExport)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_user_export(
+        &self,
+        params: &UserExportRequest<'_>,
+    ) -> Result<Export, crate::Error> {
+        self.create_export(params).await
+    }
+
+    /// Get the state of a report generation task.
+    ///
+    /// Returns `None` if the report is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_export(&self, export_id: &str) -> Result<Option<Export>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/export/get", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("export_id", export_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<ExportResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.export))
+    }
+
+    /// Search report generation tasks by name.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_exports(
+        &self,
+        params: &ListExportRequest<'_>,
+    ) -> Result<PianoPaginated<ListExportResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/export/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListExportResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// Rerun the generation of a report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Frun)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn run_export(&self, export_id: &str) -> Result<bool, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/export/run", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("export_id", export_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<ExportBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Delete a report or a report generation task.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fdelete)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_export(&self, export_id: &str) -> Result<bool, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/export/delete", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("export_id", export_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<ExportBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Get the URL to download a report.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fdownload)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_export_download_url(&self, export_id: &str) -> Result<String, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/export/download", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("export_id", export_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<ExportDownloadResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Download the CSV file of a completed report as a byte stream.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn download_export(&self, export_id: &str) -> Result<ByteStream, crate::Error> {
+        let url = self.get_export_download_url(export_id).await?;
+        self.download_stream(&url).await
+    }
+
+    /// Download the CSV file of a completed report and parse it into rows.
+    ///
+    /// Columns are matched to fields of `T` by header name. Use a row type such as
+    /// [`TransactionsReportRow`] for reports with known columns, or
+    /// `HashMap<String, String>` to read the rows without a dedicated type.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn download_export_rows<T: DeserializeOwned>(
+        &self,
+        export_id: &str,
+    ) -> Result<Vec<T>, crate::Error> {
+        let url = self.get_export_download_url(export_id).await?;
+        self.download_csv(&url).await
+    }
+
+    /// Wait until a report has been generated.
+    ///
+    /// Polls the report generation task every `poll_interval` and returns the completed task.
+    /// Returns an error if the generation fails, and an [`ErrorKind::TimedOut`] error if the
+    /// report has not been generated within `timeout`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn wait_for_export_completion(
+        &self,
+        export_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Export, crate::Error> {
+        let started = Instant::now();
+        loop {
+            let export = self.get_export(export_id).await?.ok_or_else(|| {
+                Box::new(std::io::Error::new(
+                    ErrorKind::NotFound,
+                    format!("export {export_id} not found"),
+                ))
+            })?;
+            if export.is_completed() {
+                return Ok(export);
+            }
+            if export.is_failed() {
+                return Err(Box::new(std::io::Error::new(
+                    ErrorKind::Other,
+                    format!("export {export_id} failed"),
+                )));
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                percentage = export.export_percentage(),
+                "export in progress"
+            );
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(Box::new(std::io::Error::new(
+                    ErrorKind::TimedOut,
+                    format!("export {export_id} did not complete within {timeout:?}"),
+                )));
+            }
+            tokio::time::sleep(poll_interval.min(timeout - elapsed)).await;
+        }
+    }
+
+    /// Generate a report, wait until it is completed and parse its CSV file into rows.
+    ///
+    /// See [`PianoAPI::wait_for_export_completion`] and [`PianoAPI::download_export_rows`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn run_export_and_download<R: ExportReport, T: DeserializeOwned>(
+        &self,
+        params: &R,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<T>, crate::Error> {
+        let export = self.create_export(params).await?;
+        self.wait_for_export_completion(export.export_id(), poll_interval, timeout)
+            .await?;
+        self.download_export_rows(export.export_id()).await
+    }
+}
@@ -0,0 +1,129 @@
+use serde::Deserialize;
+
+/// Row of a transactions report, see [`super::TransactionsReportExportRequest`].
+///
+/// Columns are matched by header name. Columns that are not listed here are ignored, and
+/// missing or empty columns are read as `None`. Amounts are kept as they are formatted in
+/// the report. Use `HashMap<String, String>` rows to read every column.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TransactionsReportRow {
+    #[serde(rename = "Transaction ID", default)]
+    pub transaction_id: Option<String>,
+    #[serde(rename = "Date", alias = "Transaction date", default)]
+    pub date: Option<String>,
+    #[serde(rename = "Transaction type", alias = "Type", default)]
+    pub transaction_type: Option<String>,
+    #[serde(rename = "User ID", alias = "UID", default)]
+    pub uid: Option<String>,
+    #[serde(rename = "Email", alias = "User email", default)]
+    pub email: Option<String>,
+    #[serde(rename = "Term ID", default)]
+    pub term_id: Option<String>,
+    #[serde(rename = "Term name", default)]
+    pub term_name: Option<String>,
+    #[serde(rename = "Resource ID", default)]
+    pub rid: Option<String>,
+    #[serde(rename = "Subscription ID", default)]
+    pub subscription_id: Option<String>,
+    #[serde(rename = "Amount", alias = "Price", default)]
+    pub amount: Option<String>,
+    #[serde(rename = "Tax", default)]
+    pub tax: Option<String>,
+    #[serde(rename = "Currency", default)]
+    pub currency: Option<String>,
+    #[serde(rename = "Payment method", default)]
+    pub payment_method: Option<String>,
+    #[serde(rename = "External transaction ID", default)]
+    pub external_transaction_id: Option<String>,
+    #[serde(rename = "Promo code", alias = "Promotion code", default)]
+    pub promo_code: Option<String>,
+    #[serde(rename = "Country", default)]
+    pub country: Option<String>,
+}
+
+/// Row of a subscription details report, see [`super::SubscriptionDetailsReportExportRequest`].
+///
+/// Columns are matched by header name. Columns that are not listed here are ignored, and
+/// missing or empty columns are read as `None`. Use `HashMap<String, String>` rows to read
+/// every column.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SubscriptionDetailsReportRow {
+    #[serde(rename = "Subscription ID", default)]
+    pub subscription_id: Option<String>,
+    #[serde(rename = "User ID", alias = "UID", default)]
+    pub uid: Option<String>,
+    #[serde(rename = "Email", alias = "User email", default)]
+    pub email: Option<String>,
+    #[serde(rename = "Term ID", default)]
+    pub term_id: Option<String>,
+    #[serde(rename = "Term name", default)]
+    pub term_name: Option<String>,
+    #[serde(rename = "Resource ID", default)]
+    pub rid: Option<String>,
+    #[serde(rename = "Status", alias = "Subscription status", default)]
+    pub status: Option<String>,
+    #[serde(rename = "Auto renew", alias = "Auto-renew", default)]
+    pub auto_renew: Option<String>,
+    #[serde(rename = "Start date", alias = "Created", default)]
+    pub start_date: Option<String>,
+    #[serde(rename = "Next billing date", alias = "Next bill date", default)]
+    pub next_billing_date: Option<String>,
+    #[serde(rename = "End date", alias = "Expiration date", default)]
+    pub end_date: Option<String>,
+    #[serde(rename = "Billing plan", default)]
+    pub billing_plan: Option<String>,
+    #[serde(rename = "Price", default)]
+    pub price: Option<String>,
+    #[serde(rename = "Currency", default)]
+    pub currency: Option<String>,
+    #[serde(rename = "Payment method", default)]
+    pub payment_method: Option<String>,
+}
+
+impl SubscriptionDetailsReportRow {
+    /// Check if the subscription renews automatically
+    pub fn is_auto_renewing(&self) -> Option<bool> {
+        self.auto_renew
+            .as_deref()
+            .map(|value| matches!(value.to_ascii_lowercase().as_str(), "true" | "yes" | "1"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::download::parse_csv;
+
+    #[test]
+    fn test_transactions_report_row() {
+        let data = "\u{feff}Transaction ID,Date,Transaction type,User ID,Email,Term ID,Term name,Amount,Tax,Currency,Promo code,Unlisted column\n\
+            TX1,2024-11-06 02:54:24,Payment,UID1,a@example.com,TM1,Monthly,19.99,1.50,USD,,x\n\
+            TX2,2024-11-07 10:00:00,Refund,UID2,b@example.com,TM1,Monthly,-19.99,,USD,SPRING,y\n";
+        let rows = parse_csv::<TransactionsReportRow>(data.as_bytes()).expect("OK");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].transaction_id.as_deref(), Some("TX1"));
+        assert_eq!(rows[0].uid.as_deref(), Some("UID1"));
+        assert_eq!(rows[0].amount.as_deref(), Some("19.99"));
+        assert_eq!(rows[0].promo_code, None);
+        assert_eq!(rows[0].subscription_id, None);
+        assert_eq!(rows[1].transaction_type.as_deref(), Some("Refund"));
+        assert_eq!(rows[1].tax, None);
+        assert_eq!(rows[1].promo_code.as_deref(), Some("SPRING"));
+    }
+
+    #[test]
+    fn test_subscription_details_report_row() {
+        let data = "Subscription ID,UID,User email,Term ID,Status,Auto renew,Next billing date,Price,Currency\n\
+            RCX1,UID1,a@example.com,TM1,Active,true,2025-01-01,9.99,EUR\n\
+            RCX2,UID2,b@example.com,TM2,Expired,false,,9.99,EUR\n";
+        let rows = parse_csv::<SubscriptionDetailsReportRow>(data.as_bytes()).expect("OK");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].subscription_id.as_deref(), Some("RCX1"));
+        assert_eq!(rows[0].uid.as_deref(), Some("UID1"));
+        assert_eq!(rows[0].email.as_deref(), Some("a@example.com"));
+        assert_eq!(rows[0].is_auto_renewing(), Some(true));
+        assert_eq!(rows[1].is_auto_renewing(), Some(false));
+        assert_eq!(rows[1].next_billing_date, None);
+        assert_eq!(rows[1].end_date, None);
+    }
+}
@@ -0,0 +1,1426 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::publisher::{
+    payment::PaymentStatus,
+    user::{CustomFieldQuery, Source},
+};
+
+/// A report that can be generated as a downloadable export.
+///
+/// Implemented by the request type of each report kind, so that a report can be
+/// created with [`crate::PianoAPI::create_export`] or run to completion with
+/// [`crate::PianoAPI::run_export_and_download`].
+pub trait ExportReport: Serialize + std::fmt::Debug {
+    /// Path of the endpoint creating the report, relative to the API endpoint
+    fn path(&self) -> &'static str;
+    /// HTTP method of the endpoint creating the report.
+    ///
+    /// Parameters are sent as a query string for `GET` and as a form body otherwise.
+    fn method(&self) -> Method;
+}
+
+/// Downloadable report completion status
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportStatus {
+    Created,
+    InProgress,
+    Completed,
+    Failed,
+    ReProcess,
+}
+
+/// The report type of a downloadable report
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportReportType {
+    Users,
+    UsersEs,
+    SubscriptionDetails,
+    AamDailyProof,
+    AamMonthly,
+    AiDownload,
+    AccessReport,
+    AccessReportTz,
+    DailyActivityReport,
+    MonthlyActivityReport,
+    Transactions,
+    TransactionsReport,
+    TransactionsReportTz,
+    TransactionsReportUpdated,
+    SubscriptionSummary,
+    SubscriptionDetailsReport,
+    SubscriptionDetailsReportTz,
+    SubscriptionSummaryReport,
+    TermChangeReport,
+    PromoCodesReport,
+    /// A report type not known to this version of the SDK
+    #[serde(other)]
+    Unknown,
+}
+
+/// Downloadable report generation task
+#[derive(Debug, Deserialize, Clone)]
+pub struct Export {
+    export_id: String,
+    export_name: String,
+    export_created: i64,
+    #[serde(default)]
+    export_completed: Option<i64>,
+    #[serde(default)]
+    export_percentage: u8,
+    #[serde(default)]
+    export_records: Option<u64>,
+    export_status: ExportStatus,
+    #[serde(default)]
+    report_type: Option<ExportReportType>,
+    #[serde(default)]
+    export_updated: Option<i64>,
+    #[serde(default)]
+    export_repeatable: bool,
+    #[serde(default)]
+    filter_data: Option<String>,
+}
+
+impl Export {
+    /// Get the ID of the downloadable report
+    pub fn export_id(&self) -> &str {
+        &self.export_id
+    }
+    /// Get the downloadable report name
+    pub fn export_name(&self) -> &str {
+        &self.export_name
+    }
+    /// Get the time stamp of the report creation
+    pub fn export_created(&self) -> i64 {
+        self.export_created
+    }
+    /// Get the time stamp of the report completion
+    pub fn export_completed(&self) -> Option<i64> {
+        self.export_completed
+    }
+    /// Get the percentage of the report completion
+    pub fn export_percentage(&self) -> u8 {
+        self.export_percentage
+    }
+    /// Get the number of records in the report
+    pub fn export_records(&self) -> Option<u64> {
+        self.export_records
+    }
+    /// Get the report completion status
+    pub fn export_status(&self) -> &ExportStatus {
+        &self.export_status
+    }
+    /// Get the report type
+    pub fn report_type(&self) -> Option<&ExportReportType> {
+        self.report_type.as_ref()
+    }
+    /// Get the time stamp of the last report update
+    pub fn export_updated(&self) -> Option<i64> {
+        self.export_updated
+    }
+    /// Whether the report can be generated again
+    pub fn is_export_repeatable(&self) -> bool {
+        self.export_repeatable
+    }
+    /// Get the filters the report was generated with
+    pub fn filter_data(&self) -> Option<&str> {
+        self.filter_data.as_deref()
+    }
+    /// Whether the report has been generated and can be downloaded
+    pub fn is_completed(&self) -> bool {
+        self.export_status == ExportStatus::Completed
+    }
+    /// Whether the report generation has failed
+    pub fn is_failed(&self) -> bool {
+        self.export_status == ExportStatus::Failed
+    }
+}
+
+/// Term type filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermType {
+    Payment,
+    Adview,
+    Registration,
+    Newsletter,
+    External,
+    Custom,
+    GrantAccess,
+    Gift,
+    SpecificEmailAddressesContract,
+    EmailDomainContract,
+    IpRangeContract,
+    Dynamic,
+    Linked,
+}
+
+impl TermType {
+    /// Get the name of the term type used in report filters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TermType::Payment => "payment",
+            TermType::Adview => "adview",
+            TermType::Registration => "registration",
+            TermType::Newsletter => "newsletter",
+            TermType::External => "external",
+            TermType::Custom => "custom",
+            TermType::GrantAccess => "grant_access",
+            TermType::Gift => "gift",
+            TermType::SpecificEmailAddressesContract => "specific_email_addresses_contract",
+            TermType::EmailDomainContract => "email_domain_contract",
+            TermType::IpRangeContract => "ip_range_contract",
+            TermType::Dynamic => "dynamic",
+            TermType::Linked => "linked",
+        }
+    }
+
+    /// Get the numeric code of the term type used in user search filters
+    pub fn code(&self) -> u8 {
+        match self {
+            TermType::Payment => 1,
+            TermType::Adview => 2,
+            TermType::Registration => 3,
+            TermType::Newsletter => 4,
+            TermType::External => 5,
+            TermType::Custom => 6,
+            TermType::GrantAccess => 7,
+            TermType::Gift => 8,
+            TermType::SpecificEmailAddressesContract => 9,
+            TermType::EmailDomainContract => 10,
+            TermType::IpRangeContract => 11,
+            TermType::Dynamic => 12,
+            TermType::Linked => 13,
+        }
+    }
+}
+
+impl Serialize for TermType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Access status filter of the access report
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum AccessStatus {
+    /// Active accesses
+    #[serde(rename = "yes")]
+    Active,
+    /// Inactive accesses
+    #[serde(rename = "no")]
+    Inactive,
+    /// Accesses in any status
+    #[serde(rename = "all")]
+    All,
+}
+
+/// Transactions type filter of the transactions report
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionsType {
+    All,
+    Purchases,
+    Refunds,
+}
+
+/// Status of an active subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActiveSubscriptionStatus {
+    Active,
+    FailedAndRetry,
+}
+
+impl ActiveSubscriptionStatus {
+    /// Get the name of the status used in report filters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActiveSubscriptionStatus::Active => "active",
+            ActiveSubscriptionStatus::FailedAndRetry => "failedAndRetry",
+        }
+    }
+}
+
+/// Status of an inactive subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InactiveSubscriptionStatus {
+    Cancelled,
+    PaymentFailure,
+    Expired,
+    Completed,
+    Upgraded,
+}
+
+impl InactiveSubscriptionStatus {
+    /// Get the name of the status used in report filters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InactiveSubscriptionStatus::Cancelled => "cancelled",
+            InactiveSubscriptionStatus::PaymentFailure => "paymentFailure",
+            InactiveSubscriptionStatus::Expired => "expired",
+            InactiveSubscriptionStatus::Completed => "completed",
+            InactiveSubscriptionStatus::Upgraded => "upgraded",
+        }
+    }
+}
+
+/// Email confirmation state filter of the user export
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailConfirmationState {
+    Unconfirmed,
+    Confirmed,
+    PreConfirmed,
+}
+
+/// Request to create an AAM daily proof of access or AAM monthly report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2Faam~2Fdaily)
+#[derive(Debug, Serialize)]
+pub struct AamExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// The first date of the report range (Unix time)
+    pub date_from: i64,
+    /// The last date of the report range (Unix time)
+    pub date_to: i64,
+    /// Enable snowflake
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snowflake: Option<bool>,
+    #[serde(skip)]
+    monthly: bool,
+}
+
+impl<'a> AamExportRequest<'a> {
+    /// Create a request for the AAM daily proof of access
+    pub fn daily(export_name: &'a str, date_from: i64, date_to: i64) -> Self {
+        Self {
+            export_name,
+            date_from,
+            date_to,
+            snowflake: None,
+            monthly: false,
+        }
+    }
+
+    /// Create a request for the AAM monthly report
+    pub fn monthly(export_name: &'a str, date_from: i64, date_to: i64) -> Self {
+        Self {
+            monthly: true,
+            ..Self::daily(export_name, date_from, date_to)
+        }
+    }
+
+    /// Enable or disable snowflake
+    pub fn with_snowflake(mut self, snowflake: bool) -> Self {
+        self.snowflake = Some(snowflake);
+        self
+    }
+}
+
+impl ExportReport for AamExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        if self.monthly {
+            "/publisher/export/create/aam/monthly"
+        } else {
+            "/publisher/export/create/aam/daily"
+        }
+    }
+    fn method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Request to create an AAM monthly report with dates in the YYYY-MM-DD format
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2Faam~2Fmonthly~2Fv2)
+#[derive(Debug, Serialize)]
+pub struct AamMonthlyExportV2Request<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// The first date of the report range (YYYY-MM-DD)
+    pub date_from: &'a str,
+    /// The last date of the report range (YYYY-MM-DD)
+    pub date_to: &'a str,
+    /// Enable snowflake
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snowflake: Option<bool>,
+}
+
+impl<'a> AamMonthlyExportV2Request<'a> {
+    /// Create a new AAM monthly report request
+    pub fn new(export_name: &'a str, date_from: &'a str, date_to: &'a str) -> Self {
+        Self {
+            export_name,
+            date_from,
+            date_to,
+            snowflake: None,
+        }
+    }
+
+    /// Enable or disable snowflake
+    pub fn with_snowflake(mut self, snowflake: bool) -> Self {
+        self.snowflake = Some(snowflake);
+        self
+    }
+}
+
+impl ExportReport for AamMonthlyExportV2Request<'_> {
+    fn path(&self) -> &'static str {
+        "/publisher/export/create/aam/monthly/v2"
+    }
+    fn method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Request to create an access report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FaccessReportExport)
+#[derive(Debug, Serialize)]
+pub struct AccessReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// Access start date: the first date of the date range
+    pub date_from: i64,
+    /// Access start date: the last date of the date range
+    pub date_to: i64,
+    /// Access status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_status: Option<AccessStatus>,
+    /// Term types
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_term_types"
+    )]
+    pub term_type: Option<Vec<TermType>>,
+    /// Term IDs
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub term_id: Option<&'a [&'a str]>,
+    /// Next billing date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_billing_date: Option<i64>,
+    /// Last payment status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_payment_status: Option<PaymentStatus>,
+    /// Access expiration or revocation date: the first date of the date range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_from: Option<i64>,
+    /// Access expiration or revocation date: the last date of the date range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_to: Option<i64>,
+    #[serde(skip)]
+    v2: bool,
+}
+
+impl<'a> AccessReportExportRequest<'a> {
+    /// Create a new access report request for accesses started within the date range
+    pub fn new(export_name: &'a str, date_from: i64, date_to: i64) -> Self {
+        Self {
+            export_name,
+            date_from,
+            date_to,
+            access_status: None,
+            term_type: None,
+            term_id: None,
+            next_billing_date: None,
+            last_payment_status: None,
+            end_date_from: None,
+            end_date_to: None,
+            v2: false,
+        }
+    }
+
+    /// Use the v2 endpoint, in which dates indicate the app's time
+    pub fn v2(mut self) -> Self {
+        self.v2 = true;
+        self
+    }
+
+    /// Filter by access status
+    pub fn with_access_status(mut self, access_status: AccessStatus) -> Self {
+        self.access_status = Some(access_status);
+        self
+    }
+
+    /// Filter by term types
+    pub fn with_term_types(mut self, term_types: &[TermType]) -> Self {
+        self.term_type = Some(term_types.to_vec());
+        self
+    }
+
+    /// Filter by term IDs
+    pub fn with_term_ids(mut self, term_ids: &'a [&'a str]) -> Self {
+        self.term_id = Some(term_ids);
+        self
+    }
+
+    /// Filter by next billing date
+    pub fn with_next_billing_date(mut self, next_billing_date: i64) -> Self {
+        self.next_billing_date = Some(next_billing_date);
+        self
+    }
+
+    /// Filter by last payment status
+    pub fn with_last_payment_status(mut self, last_payment_status: PaymentStatus) -> Self {
+        self.last_payment_status = Some(last_payment_status);
+        self
+    }
+
+    /// Filter by access expiration or revocation date
+    pub fn with_end_date(mut self, end_date_from: i64, end_date_to: i64) -> Self {
+        self.end_date_from = Some(end_date_from);
+        self.end_date_to = Some(end_date_to);
+        self
+    }
+}
+
+impl ExportReport for AccessReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        if self.v2 {
+            "/publisher/export/create/accessReportExport/v2"
+        } else {
+            "/publisher/export/create/accessReportExport"
+        }
+    }
+    fn method(&self) -> Method {
+        Method::GET
+    }
+}
+
+/// Request to create a daily activity report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FdailyActivityReportExport)
+#[derive(Debug, Serialize)]
+pub struct DailyActivityReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// Date
+    pub date: i64,
+    /// Term types
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_term_types"
+    )]
+    pub term_type: Option<Vec<TermType>>,
+    /// Currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<&'a str>,
+    /// Currency list
+    #[serde(
+        rename = "currencyList",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub currency_list: Option<&'a [&'a str]>,
+}
+
+impl<'a> DailyActivityReportExportRequest<'a> {
+    /// Create a new daily activity report request
+    pub fn new(export_name: &'a str, date: i64) -> Self {
+        Self {
+            export_name,
+            date,
+            term_type: None,
+            currency: None,
+            currency_list: None,
+        }
+    }
+
+    /// Filter by term types
+    pub fn with_term_types(mut self, term_types: &[TermType]) -> Self {
+        self.term_type = Some(term_types.to_vec());
+        self
+    }
+
+    /// Filter by currency
+    pub fn with_currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Filter by a list of currencies
+    pub fn with_currency_list(mut self, currency_list: &'a [&'a str]) -> Self {
+        self.currency_list = Some(currency_list);
+        self
+    }
+}
+
+impl ExportReport for DailyActivityReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        "/publisher/export/create/dailyActivityReportExport"
+    }
+    fn method(&self) -> Method {
+        Method::GET
+    }
+}
+
+/// Request to create a monthly activity report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FmonthlyActivityReportExport)
+#[derive(Debug, Serialize)]
+pub struct MonthlyActivityReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// Number of month
+    pub month: u32,
+    /// Year
+    pub year: i32,
+    /// Term types
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_term_types"
+    )]
+    pub term_type: Option<Vec<TermType>>,
+    /// Currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<&'a str>,
+    /// Currency list
+    #[serde(
+        rename = "currencyList",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub currency_list: Option<&'a [&'a str]>,
+}
+
+impl<'a> MonthlyActivityReportExportRequest<'a> {
+    /// Create a new monthly activity report request
+    pub fn new(export_name: &'a str, year: i32, month: u32) -> Self {
+        Self {
+            export_name,
+            month,
+            year,
+            term_type: None,
+            currency: None,
+            currency_list: None,
+        }
+    }
+
+    /// Filter by term types
+    pub fn with_term_types(mut self, term_types: &[TermType]) -> Self {
+        self.term_type = Some(term_types.to_vec());
+        self
+    }
+
+    /// Filter by currency
+    pub fn with_currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Filter by a list of currencies
+    pub fn with_currency_list(mut self, currency_list: &'a [&'a str]) -> Self {
+        self.currency_list = Some(currency_list);
+        self
+    }
+}
+
+impl ExportReport for MonthlyActivityReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        "/publisher/export/create/monthlyActivityReportExport"
+    }
+    fn method(&self) -> Method {
+        Method::GET
+    }
+}
+
+/// Request to create a subscription details report
+///
+/// The search flags of each filter are set along with the filter.
+///
+/// This report is deprecated by Piano.
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FsubscriptionDetailsReport)
+#[derive(Debug, Serialize, Default)]
+pub struct SubscriptionDetailsReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_new_subscriptions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_subscriptions_created_from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_subscriptions_created_to: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_active_now_subscriptions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active_now_subscriptions_statuses: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_inactive_subscriptions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inactive_subscriptions_statuses: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_inactive_from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_inactive_to: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_updated_subscriptions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_subscriptions_statuses: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_updated_from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_updated_to: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_auto_renewing_subscriptions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_auto_renewing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_subscriptions_by_next_billing_date: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_next_billing_date_from: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriptions_next_billing_date_to: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_subscriptions_by_terms: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    subscriptions_terms: Option<&'a [&'a str]>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_term_types"
+    )]
+    subscriptions_term_types: Option<Vec<TermType>>,
+    #[serde(skip)]
+    v2: bool,
+}
+
+impl<'a> SubscriptionDetailsReportExportRequest<'a> {
+    /// Create a new subscription details report request
+    pub fn new(export_name: &'a str) -> Self {
+        Self {
+            export_name,
+            ..Default::default()
+        }
+    }
+
+    /// Use the v2 endpoint, in which dates indicate UTC time instead of the app's local time
+    pub fn v2(mut self) -> Self {
+        self.v2 = true;
+        self
+    }
+
+    /// Set the search value
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Search for subscriptions created within the date range
+    pub fn with_new_subscriptions(mut self, created_from: i64, created_to: i64) -> Self {
+        self.search_new_subscriptions = Some(true);
+        self.new_subscriptions_created_from = Some(created_from);
+        self.new_subscriptions_created_to = Some(created_to);
+        self
+    }
+
+    /// Search for subscriptions which are active now in any of the statuses
+    pub fn with_active_now_subscriptions(mut self, statuses: &[ActiveSubscriptionStatus]) -> Self {
+        self.search_active_now_subscriptions = Some(true);
+        self.active_now_subscriptions_statuses = Some(join(statuses.iter().map(|s| s.as_str())));
+        self
+    }
+
+    /// Search for subscriptions which became inactive in any of the statuses within the date range
+    pub fn with_inactive_subscriptions(
+        mut self,
+        statuses: &[InactiveSubscriptionStatus],
+        inactive_from: i64,
+        inactive_to: i64,
+    ) -> Self {
+        self.search_inactive_subscriptions = Some(true);
+        self.inactive_subscriptions_statuses = Some(join(statuses.iter().map(|s| s.as_str())));
+        self.subscriptions_inactive_from = Some(inactive_from);
+        self.subscriptions_inactive_to = Some(inactive_to);
+        self
+    }
+
+    /// Search for subscriptions renewed within the date range
+    pub fn with_renewed_subscriptions(mut self, updated_from: i64, updated_to: i64) -> Self {
+        self.search_updated_subscriptions = Some(true);
+        self.updated_subscriptions_statuses = Some("renewed");
+        self.subscriptions_updated_from = Some(updated_from);
+        self.subscriptions_updated_to = Some(updated_to);
+        self
+    }
+
+    /// Search for subscriptions which are auto-renewing or not
+    pub fn with_auto_renewing(mut self, auto_renewing: bool) -> Self {
+        self.search_auto_renewing_subscriptions = Some(true);
+        self.subscriptions_auto_renewing = Some(auto_renewing);
+        self
+    }
+
+    /// Search for subscriptions whose next billing date is within the date range
+    pub fn with_next_billing_date(mut self, next_billing_from: i64, next_billing_to: i64) -> Self {
+        self.search_subscriptions_by_next_billing_date = Some(true);
+        self.subscriptions_next_billing_date_from = Some(next_billing_from);
+        self.subscriptions_next_billing_date_to = Some(next_billing_to);
+        self
+    }
+
+    /// Search for subscriptions to any of the terms
+    pub fn with_terms(mut self, term_ids: &'a [&'a str]) -> Self {
+        self.search_subscriptions_by_terms = Some(true);
+        self.subscriptions_terms = Some(term_ids);
+        self
+    }
+
+    /// Search for subscriptions to terms of any of the types
+    pub fn with_term_types(mut self, term_types: &[TermType]) -> Self {
+        self.search_subscriptions_by_terms = Some(true);
+        self.subscriptions_term_types = Some(term_types.to_vec());
+        self
+    }
+}
+
+impl ExportReport for SubscriptionDetailsReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        if self.v2 {
+            "/publisher/export/create/subscriptionDetailsReport/v2"
+        } else {
+            "/publisher/export/create/subscriptionDetailsReport"
+        }
+    }
+    fn method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Request to create a subscription summary report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FsubscriptionSummaryReport)
+#[derive(Debug, Serialize)]
+pub struct SubscriptionSummaryReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// The first date of the report range
+    pub date_from: i64,
+    /// The last date of the report range
+    pub date_to: i64,
+}
+
+impl<'a> SubscriptionSummaryReportExportRequest<'a> {
+    /// Create a new subscription summary report request
+    pub fn new(export_name: &'a str, date_from: i64, date_to: i64) -> Self {
+        Self {
+            export_name,
+            date_from,
+            date_to,
+        }
+    }
+}
+
+impl ExportReport for SubscriptionSummaryReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        "/publisher/export/create/subscriptionSummaryReport"
+    }
+    fn method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Request to create a term change (upgrade) report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Fcreate~2FtermChangeReportExport)
+#[derive(Debug, Serialize)]
+pub struct TermChangeReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// Date from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<i64>,
+    /// Date to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<i64>,
+}
+
+impl<'a> TermChangeReportExportRequest<'a> {
+    /// Create a new term change report request
+    pub fn new(export_name: &'a str) -> Self {
+        Self {
+            export_name,
+            date_from: None,
+            date_to: None,
+        }
+    }
+
+    /// Limit the report to the date range
+    pub fn with_date_range(mut self, date_from: i64, date_to: i64) -> Self {
+        self.date_from = Some(date_from);
+        self.date_to = Some(date_to);
+        self
+    }
+}
+
+impl ExportReport for TermChangeReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        "/publisher/export/create/termChangeReportExport"
+    }
+    fn method(&self) -> Method {
+        Method::GET
+    }
+}
+
+/// Request to create a transactions report
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FtransactionsReport)
+#[derive(Debug, Serialize)]
+pub struct TransactionsReportExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// The transactions type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions_type: Option<TransactionsType>,
+    /// Field to order by (`payment_date`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    /// Date from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<i64>,
+    /// Date to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<i64>,
+    #[serde(skip)]
+    v2: bool,
+}
+
+impl<'a> TransactionsReportExportRequest<'a> {
+    /// Create a new transactions report request
+    pub fn new(export_name: &'a str) -> Self {
+        Self {
+            export_name,
+            transactions_type: None,
+            order_by: None,
+            order_direction: None,
+            q: None,
+            date_from: None,
+            date_to: None,
+            v2: false,
+        }
+    }
+
+    /// Use the v2 endpoint, in which dates indicate the app's time instead of UTC
+    pub fn v2(mut self) -> Self {
+        self.v2 = true;
+        self
+    }
+
+    /// Filter by transactions type
+    pub fn with_transactions_type(mut self, transactions_type: TransactionsType) -> Self {
+        self.transactions_type = Some(transactions_type);
+        self
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Set the search value
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Limit the report to the date range
+    pub fn with_date_range(mut self, date_from: i64, date_to: i64) -> Self {
+        self.date_from = Some(date_from);
+        self.date_to = Some(date_to);
+        self
+    }
+}
+
+impl ExportReport for TransactionsReportExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        if self.v2 {
+            "/publisher/export/create/transactionsReport/v2"
+        } else {
+            "/publisher/export/create/transactionsReport"
+        }
+    }
+    fn method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Request to create a user search report
+///
+/// Covers the most common user search filters.
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fexport~2Fcreate~2FuserExport)
+#[derive(Debug, Serialize, Default)]
+pub struct UserExportRequest<'a> {
+    /// The downloadable report name
+    pub export_name: &'a str,
+    /// Names of the custom fields to include in the report
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub export_custom_fields: Option<&'a [&'a str]>,
+    /// Export all the custom fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_all_custom_fields: Option<bool>,
+    /// Whether to include notes about users in the report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_notes: Option<bool>,
+    /// Finds users whose names start with this keyword
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    /// Finds users whose emails contain this keyword
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    /// Finds users registered from this date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_from: Option<i64>,
+    /// Finds users registered until this date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_until: Option<i64>,
+    /// Finds users who have access to these resources
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub access_to_resources: Option<&'a [&'a str]>,
+    /// Finds users who have converted on these terms
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub converted_terms: Option<&'a [&'a str]>,
+    /// Finds users who converted on terms of these types
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_term_type_codes"
+    )]
+    pub converted_term_types: Option<Vec<TermType>>,
+    /// Finds users who have any active access from this date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_from: Option<i64>,
+    /// Finds users who have any access until this date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_until: Option<i64>,
+    /// Finds users who currently have any type of access
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_access: Option<bool>,
+    /// Finds users who have an active subscription
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_active_subscription: Option<bool>,
+    /// Finds users who have converted on any term
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_conversion_term: Option<bool>,
+    /// Finds users who redeemed these promotions
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub redeemed_promotions: Option<&'a [&'a str]>,
+    /// Finds users who have made any payment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_payment: Option<bool>,
+    /// Finds users whose last access time was on or after this date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_access_time_from: Option<i64>,
+    /// Finds users whose last access time was on or before this date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_access_time_until: Option<i64>,
+    /// Finds users with a specific email confirmation state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_confirmation_state: Option<EmailConfirmationState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_fields: Option<String>,
+    /// The data source for user searching
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    /// Field to order by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+}
+
+impl<'a> UserExportRequest<'a> {
+    /// Create a new user search report request
+    pub fn new(export_name: &'a str) -> Self {
+        Self {
+            export_name,
+            ..Default::default()
+        }
+    }
+
+    /// Include the custom fields in the report
+    pub fn with_export_custom_fields(mut self, custom_fields: &'a [&'a str]) -> Self {
+        self.export_custom_fields = Some(custom_fields);
+        self
+    }
+
+    /// Include all the custom fields in the report
+    pub fn with_all_custom_fields(mut self) -> Self {
+        self.export_all_custom_fields = Some(true);
+        self
+    }
+
+    /// Include notes about users in the report
+    pub fn with_notes(mut self) -> Self {
+        self.include_notes = Some(true);
+        self
+    }
+
+    /// Search by email
+    pub fn with_email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Search by users registered within the date range
+    pub fn with_registered(mut self, registered_from: i64, registered_until: i64) -> Self {
+        self.registered_from = Some(registered_from);
+        self.registered_until = Some(registered_until);
+        self
+    }
+
+    /// Search by users who converted on terms of any of the types
+    pub fn with_converted_term_types(mut self, term_types: &[TermType]) -> Self {
+        self.converted_term_types = Some(term_types.to_vec());
+        self
+    }
+
+    /// Search by custom field values
+    pub fn with_custom_field_queries(mut self, custom_field_queries: &[CustomFieldQuery]) -> Self {
+        let value = serde_json::to_string(custom_field_queries).expect("OK");
+        self.custom_fields = Some(value);
+        self
+    }
+
+    /// Set the data source for user searching
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Set the search value
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+}
+
+impl ExportReport for UserExportRequest<'_> {
+    fn path(&self) -> &'static str {
+        "/publisher/export/create/userExport"
+    }
+    fn method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Request to list report generation tasks
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fexport~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListExportRequest<'a> {
+    /// Field to order by (`create_date`, `update_date`, `completion_date` or `start_date`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListExportRequest<'a> {
+    /// Create a new list exports request
+    pub fn new() -> Self {
+        Self {
+            order_by: None,
+            order_direction: None,
+            q: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Search by report name
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListExportRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ExportResult {
+    #[serde(alias = "Export")]
+    pub export: Export,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListExportResult {
+    #[serde(alias = "Export")]
+    pub exports: Vec<Export>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ExportDownloadResult {
+    pub data: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ExportBooleanResult {
+    pub data: bool,
+}
+
+fn join<'s>(values: impl Iterator<Item = &'s str>) -> String {
+    values.collect::<Vec<_>>().join(",")
+}
+
+fn serialize_str_slice<S>(slice: &Option<&[&str]>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match slice {
+        Some(slice) => serializer.serialize_str(&slice.join(",")),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_term_types<S>(
+    term_types: &Option<Vec<TermType>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match term_types {
+        Some(term_types) => serializer.serialize_str(&join(term_types.iter().map(|t| t.as_str()))),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_term_type_codes<S>(
+    term_types: &Option<Vec<TermType>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match term_types {
+        Some(term_types) => serializer.serialize_str(
+            &term_types
+                .iter()
+                .map(|t| t.code().to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PianoPaginated, PianoResponse};
+
+    #[test]
+    fn test_access_report_export_request() {
+        let term_ids = ["TM1", "TM2"];
+        let request = AccessReportExportRequest::new("access", 1700000000, 1710000000)
+            .with_access_status(AccessStatus::Active)
+            .with_term_types(&[TermType::Payment, TermType::GrantAccess])
+            .with_term_ids(&term_ids)
+            .with_last_payment_status(PaymentStatus::RefundRequested);
+        assert_eq!(
+            request.path(),
+            "/publisher/export/create/accessReportExport"
+        );
+        assert_eq!(request.method(), Method::GET);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "export_name": "access",
+                "date_from": 1700000000,
+                "date_to": 1710000000,
+                "access_status": "yes",
+                "term_type": "payment,grant_access",
+                "term_id": "TM1,TM2",
+                "last_payment_status": "refund requested"
+            })
+        );
+        assert_eq!(
+            request.v2().path(),
+            "/publisher/export/create/accessReportExport/v2"
+        );
+    }
+
+    #[test]
+    fn test_subscription_details_report_export_request() {
+        let request = SubscriptionDetailsReportExportRequest::new("subscriptions")
+            .with_active_now_subscriptions(&[
+                ActiveSubscriptionStatus::Active,
+                ActiveSubscriptionStatus::FailedAndRetry,
+            ])
+            .with_renewed_subscriptions(1700000000, 1710000000)
+            .with_term_types(&[TermType::Payment])
+            .v2();
+        assert_eq!(
+            request.path(),
+            "/publisher/export/create/subscriptionDetailsReport/v2"
+        );
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "export_name": "subscriptions",
+                "search_active_now_subscriptions": true,
+                "active_now_subscriptions_statuses": "active,failedAndRetry",
+                "search_updated_subscriptions": true,
+                "updated_subscriptions_statuses": "renewed",
+                "subscriptions_updated_from": 1700000000,
+                "subscriptions_updated_to": 1710000000,
+                "search_subscriptions_by_terms": true,
+                "subscriptions_term_types": "payment"
+            })
+        );
+    }
+
+    #[test]
+    fn test_user_export_request() {
+        let request = UserExportRequest::new("users")
+            .with_all_custom_fields()
+            .with_converted_term_types(&[TermType::Payment, TermType::Linked])
+            .with_source(Source::VX);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "export_name": "users",
+                "export_all_custom_fields": true,
+                "converted_term_types": "1,13",
+                "source": "VX"
+            })
+        );
+    }
+
+    #[test]
+    fn test_aam_export_request() {
+        let daily = AamExportRequest::daily("aam", 1700000000, 1710000000);
+        let monthly = AamExportRequest::monthly("aam", 1700000000, 1710000000);
+        assert_eq!(daily.path(), "/publisher/export/create/aam/daily");
+        assert_eq!(monthly.path(), "/publisher/export/create/aam/monthly");
+        assert_eq!(
+            serde_json::to_value(&monthly).expect("OK"),
+            serde_json::json!({
+                "export_name": "aam",
+                "date_from": 1700000000,
+                "date_to": 1710000000
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_export_result() {
+        let response: PianoResponse<PianoPaginated<ListExportResult>> =
+            serde_json::from_value(serde_json::json!({
+                "code": 0,
+                "ts": 1700000000,
+                "limit": 100,
+                "offset": 0,
+                "total": 2,
+                "count": 2,
+                "Export": [
+                    {
+                        "export_id": "EX1",
+                        "export_name": "users",
+                        "export_created": 1700000000,
+                        "export_completed": 1700000100,
+                        "export_percentage": 100,
+                        "export_records": 42,
+                        "export_status": "COMPLETED",
+                        "report_type": "USERS",
+                        "export_updated": 1700000100,
+                        "export_repeatable": true,
+                        "filter_data": null
+                    },
+                    {
+                        "export_id": "EX2",
+                        "export_name": "new report",
+                        "export_created": 1700000200,
+                        "export_percentage": 30,
+                        "export_status": "IN_PROGRESS",
+                        "report_type": "SOME_NEW_REPORT",
+                        "export_repeatable": false
+                    }
+                ]
+            }))
+            .expect("OK");
+        let result = response.value().expect("OK");
+        let exports = &result.value.exports;
+        assert!(exports[0].is_completed());
+        assert_eq!(exports[0].export_records(), Some(42));
+        assert_eq!(exports[0].report_type(), Some(&ExportReportType::Users));
+        assert_eq!(exports[1].export_status(), &ExportStatus::InProgress);
+        assert_eq!(exports[1].report_type(), Some(&ExportReportType::Unknown));
+        assert_eq!(exports[1].export_completed(), None);
+    }
+}
@@ -1,7 +1,9 @@
 mod schema;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoResponse};
+pub mod workflow;
+
+use crate::{Empty, PianoAPI, PianoResponse};
 
 impl PianoAPI {
     /// Delete personal data
@@ -16,7 +18,10 @@ impl PianoAPI {
             .query(&[("aid", &self.app_id)])
             .form(req)
             .send()
-            .await?;
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
         Ok(())
     }
 
@@ -35,7 +40,10 @@ impl PianoAPI {
             .query(&[("aid", &self.app_id)])
             .form(req)
             .send()
-            .await?;
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
         Ok(())
     }
 
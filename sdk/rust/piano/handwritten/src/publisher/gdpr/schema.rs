@@ -1,23 +1,48 @@
 use serde::{Deserialize, Serialize};
 
+/// Scope of the personal data to export or delete
+///
+/// See the [Piano data security FAQ](https://docs.piano.io/data-security-faqs/#q1).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum GdprScope {
+    /// Personally identifiable information only
+    Pii,
+    /// All the data of the user
+    #[default]
+    All,
+}
+
 /// Request to delete personal data for GDPR compliance
 #[derive(Debug, Serialize)]
 pub struct GdprDeleteRequest<'a> {
     /// The user ID
     pub uid: &'a str,
+    /// The scope of the deleted data, all the data by default
+    pub scope: GdprScope,
 }
 
 impl<'a> GdprDeleteRequest<'a> {
     /// Create a new GDPR delete request
     pub fn new(uid: &'a str) -> Self {
-        Self { uid }
+        Self {
+            uid,
+            scope: GdprScope::All,
+        }
+    }
+
+    /// Set the scope of the deleted data
+    pub fn with_scope(mut self, scope: GdprScope) -> Self {
+        self.scope = scope;
+        self
     }
 }
 
 /// Request to delete anonymous user profile for GDPR compliance
 #[derive(Debug, Serialize)]
 pub struct GdprDeleteAnonymousRequest<'a> {
-    /// The anonymous user ID
+    /// The unique browser ID of the anonymous user
+    #[serde(rename = "browser_id")]
     pub anon_uid: &'a str,
 }
 
@@ -33,24 +58,50 @@ impl<'a> GdprDeleteAnonymousRequest<'a> {
 pub struct GdprExportRequest<'a> {
     /// The user ID
     pub uid: &'a str,
+    /// The scope of the exported data, all the data by default
+    pub scope: GdprScope,
 }
 
 impl<'a> GdprExportRequest<'a> {
     /// Create a new GDPR export request
     pub fn new(uid: &'a str) -> Self {
-        Self { uid }
+        Self {
+            uid,
+            scope: GdprScope::All,
+        }
+    }
+
+    /// Set the scope of the exported data
+    pub fn with_scope(mut self, scope: GdprScope) -> Self {
+        self.scope = scope;
+        self
     }
 }
 
 /// GDPR export response containing user data
-#[derive(Debug, Deserialize, Clone)]
+///
+/// The records are kept as JSON, as they are meant to be handed over to the user.
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GdprExportResponse {
     /// User information
     pub user: Option<serde_json::Value>,
-    /// User activities
-    pub activities: Option<Vec<serde_json::Value>>,
-    /// Additional data
-    pub additional_data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub addresses: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub subscriptions: Vec<serde_json::Value>,
+    /// Payment methods
+    #[serde(default)]
+    pub upis: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub payments: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub transactions: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub conversions: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub contract_users: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub shared_accounts: Vec<serde_json::Value>,
 }
 
 /// Response wrapper for GDPR export operations
@@ -68,17 +119,45 @@ mod tests {
     fn test_gdpr_delete_request() {
         let request = GdprDeleteRequest::new("user123");
         assert_eq!(request.uid, "user123");
+        assert_eq!(
+            serde_json::to_value(request.with_scope(GdprScope::Pii)).expect("OK"),
+            serde_json::json!({ "uid": "user123", "scope": "PII" })
+        );
     }
 
     #[test]
     fn test_gdpr_delete_anonymous_request() {
         let request = GdprDeleteAnonymousRequest::new("anon123");
         assert_eq!(request.anon_uid, "anon123");
+        assert_eq!(
+            serde_json::to_value(request).expect("OK"),
+            serde_json::json!({ "browser_id": "anon123" })
+        );
     }
 
     #[test]
     fn test_gdpr_export_request() {
         let request = GdprExportRequest::new("user123");
         assert_eq!(request.uid, "user123");
+        assert_eq!(request.scope, GdprScope::All);
+    }
+
+    #[test]
+    fn test_gdpr_export_response() {
+        let response = serde_json::from_value::<GdprExportResponseResult>(serde_json::json!({
+            "EraseUserResponse": {
+                "user": { "uid": "user123", "email": "user@example.com" },
+                "addresses": [],
+                "subscriptions": [{ "subscription_id": "S1" }],
+                "upis": [],
+                "payments": [],
+                "transactions": [],
+                "conversions": [],
+                "contract_users": [],
+                "shared_accounts": []
+            }
+        }))
+        .expect("OK");
+        assert_eq!(response.erase_user_response.subscriptions.len(), 1);
     }
 }
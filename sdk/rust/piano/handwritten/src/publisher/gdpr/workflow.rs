@@ -0,0 +1,514 @@
+//! Erasure of a user with verification and an audit trail.
+//!
+//! [`PianoAPI::gdpr_erase_user`] runs the three steps of an erasure request in order:
+//!
+//! 1. export the personal data of the user as JSON to a writer, so that it can be handed
+//!    over to the user,
+//! 2. delete the personal data,
+//! 3. check with [`PianoAPI::get_user`] that the user is gone.
+//!
+//! The check only runs for [`GdprScope::All`]: deleting the personally identifiable
+//! information keeps the user, so there is nothing to look up after a [`GdprScope::Pii`]
+//! erasure, which has no verify step.
+//!
+//! A step only runs if the previous one succeeded. Each step produces a [`GdprAuditRecord`]
+//! with its timestamps and a unique ID, which can be kept as JSON lines with
+//! [`GdprErasureReport::write_audit_log`].
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{publisher::gdpr::workflow::GdprErasureRequest, PianoAPI};
+//!
+//! # async fn example() -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! let export = std::fs::File::create("PNIXXXXXXXXXXXX.json")?;
+//! let request = GdprErasureRequest::new("PNIXXXXXXXXXXXX").with_case_id("DSR-2024-042");
+//! let report = api.gdpr_erase_user(&request, export).await;
+//!
+//! let audit_log = std::fs::OpenOptions::new()
+//!     .create(true)
+//!     .append(true)
+//!     .open("gdpr-audit.jsonl")?;
+//! report.write_audit_log(audit_log)?;
+//! assert!(report.is_erased(), "{:?}", report.failures().collect::<Vec<_>>());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    io::Write,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{GdprDeleteRequest, GdprExportRequest, GdprExportResponse, GdprScope};
+use crate::PianoAPI;
+
+/// Number of steps started by this process, keeping request IDs unique
+static STEP_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Erasure of the personal data of a user
+#[derive(Debug, Clone)]
+pub struct GdprErasureRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The scope of the exported and deleted data, all the data by default
+    pub scope: GdprScope,
+    /// The reference of the data subject request, generated if not set
+    pub case_id: Option<&'a str>,
+    /// The delay between two lookups of the user, 5 seconds by default
+    pub poll_interval: Duration,
+    /// The time to wait for the user to be gone, 2 minutes by default
+    pub timeout: Duration,
+}
+
+impl<'a> GdprErasureRequest<'a> {
+    /// Create an erasure of all the data of a user
+    pub fn new(uid: &'a str) -> Self {
+        Self {
+            uid,
+            scope: GdprScope::All,
+            case_id: None,
+            poll_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(120),
+        }
+    }
+
+    /// Set the scope of the exported and deleted data
+    pub fn with_scope(mut self, scope: GdprScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Set the reference of the data subject request
+    ///
+    /// The reference prefixes the request IDs of the steps.
+    pub fn with_case_id(mut self, case_id: &'a str) -> Self {
+        self.case_id = Some(case_id);
+        self
+    }
+
+    /// Set how often and how long to look the user up after the deletion
+    pub fn with_verification(mut self, poll_interval: Duration, timeout: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Step of an erasure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GdprStep {
+    Export,
+    Delete,
+    Verify,
+}
+
+impl GdprStep {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Export => "export",
+            Self::Delete => "delete",
+            Self::Verify => "verify",
+        }
+    }
+}
+
+impl fmt::Display for GdprStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Outcome of an erasure step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum GdprStepOutcome {
+    Succeeded,
+    Failed {
+        error: String,
+    },
+    /// The step did not run because a previous step failed
+    Skipped {
+        reason: String,
+    },
+}
+
+/// Audit record of an erasure step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GdprAuditRecord {
+    /// The reference of the data subject request
+    pub case_id: String,
+    pub uid: String,
+    pub scope: GdprScope,
+    pub step: GdprStep,
+    #[serde(flatten)]
+    pub outcome: GdprStepOutcome,
+    /// When the step started, as a UNIX timestamp in milliseconds
+    pub started_at: i64,
+    /// When the step finished, as a UNIX timestamp in milliseconds
+    pub finished_at: i64,
+    /// The unique ID of this attempt of the step
+    ///
+    /// Made of the case ID, the step, the start time and a sequence number, so that the
+    /// records of a rerun erasure do not share IDs.
+    pub request_id: String,
+    /// What the step did, such as the size of the export
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl GdprAuditRecord {
+    pub fn is_succeeded(&self) -> bool {
+        self.outcome == GdprStepOutcome::Succeeded
+    }
+}
+
+/// Result of an erasure, with the audit record of every step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GdprErasureReport {
+    pub case_id: String,
+    pub uid: String,
+    pub records: Vec<GdprAuditRecord>,
+}
+
+impl GdprErasureReport {
+    /// Check whether the user was exported, deleted and, for [`GdprScope::All`], verified
+    /// to be gone
+    pub fn is_erased(&self) -> bool {
+        !self.records.is_empty() && self.records.iter().all(GdprAuditRecord::is_succeeded)
+    }
+
+    /// Get the audit record of a step
+    pub fn record(&self, step: GdprStep) -> Option<&GdprAuditRecord> {
+        self.records.iter().find(|record| record.step == step)
+    }
+
+    /// Get the records of the failed steps
+    pub fn failures(&self) -> impl Iterator<Item = &GdprAuditRecord> {
+        self.records
+            .iter()
+            .filter(|record| matches!(record.outcome, GdprStepOutcome::Failed { .. }))
+    }
+
+    /// Write the audit records as JSON lines
+    pub fn write_audit_log<W: Write>(&self, mut writer: W) -> Result<(), crate::Error> {
+        for record in &self.records {
+            serde_json::to_writer(&mut writer, record)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Audit record being built for a step
+struct StepAudit<'a> {
+    case_id: &'a str,
+    request: &'a GdprErasureRequest<'a>,
+    step: GdprStep,
+    started_at: i64,
+    request_id: String,
+}
+
+impl<'a> StepAudit<'a> {
+    fn start(case_id: &'a str, request: &'a GdprErasureRequest<'a>, step: GdprStep) -> Self {
+        let started_at = now_millis();
+        let sequence = STEP_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+        Self {
+            case_id,
+            request,
+            step,
+            started_at,
+            request_id: format!("{case_id}-{step}-{started_at}-{sequence}"),
+        }
+    }
+
+    fn finish(self, outcome: GdprStepOutcome, detail: Option<String>) -> GdprAuditRecord {
+        GdprAuditRecord {
+            case_id: self.case_id.to_string(),
+            uid: self.request.uid.to_string(),
+            scope: self.request.scope,
+            step: self.step,
+            outcome,
+            started_at: self.started_at,
+            finished_at: now_millis(),
+            request_id: self.request_id,
+            detail,
+        }
+    }
+
+    fn skip(self, reason: String) -> GdprAuditRecord {
+        let record = self.finish(GdprStepOutcome::Skipped { reason }, None);
+        GdprAuditRecord {
+            finished_at: record.started_at,
+            ..record
+        }
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn failed(error: crate::Error) -> GdprStepOutcome {
+    GdprStepOutcome::Failed {
+        error: error.to_string(),
+    }
+}
+
+impl PianoAPI {
+    /// Export, delete and verify the erasure of the personal data of a user
+    ///
+    /// Writes the export as pretty-printed JSON to `writer`, deletes the user only if the
+    /// export succeeded, then, for [`GdprScope::All`], looks the user up until it is not
+    /// found.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The user, the scope and the verification settings
+    /// * `writer` - Where to write the exported data
+    ///
+    /// # Returns
+    ///
+    /// Returns the audit records of the steps. Failures are recorded in the report rather
+    /// than returned as errors, see [`GdprErasureReport::is_erased`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, writer)))]
+    pub async fn gdpr_erase_user<W: Write>(
+        &self,
+        req: &GdprErasureRequest<'_>,
+        writer: W,
+    ) -> GdprErasureReport {
+        let case_id = match req.case_id {
+            Some(case_id) => case_id.to_string(),
+            None => format!("gdpr-{}-{}", req.uid, now_millis()),
+        };
+        let mut records = vec![];
+
+        let audit = StepAudit::start(&case_id, req, GdprStep::Export);
+        let result = self
+            .gdpr_export(&GdprExportRequest::new(req.uid).with_scope(req.scope))
+            .await
+            .and_then(|export| write_export(&export, writer));
+        records.push(match result {
+            Ok(size) => audit.finish(
+                GdprStepOutcome::Succeeded,
+                Some(format!("exported {size} bytes")),
+            ),
+            Err(error) => audit.finish(failed(error), None),
+        });
+
+        let audit = StepAudit::start(&case_id, req, GdprStep::Delete);
+        records.push(if records.iter().all(GdprAuditRecord::is_succeeded) {
+            let delete = GdprDeleteRequest::new(req.uid).with_scope(req.scope);
+            match self.gdpr_delete(&delete).await {
+                Ok(()) => audit.finish(GdprStepOutcome::Succeeded, None),
+                Err(error) => audit.finish(failed(error), None),
+            }
+        } else {
+            audit.skip("the export failed".to_string())
+        });
+
+        if req.scope == GdprScope::All {
+            let audit = StepAudit::start(&case_id, req, GdprStep::Verify);
+            records.push(if records.iter().all(GdprAuditRecord::is_succeeded) {
+                let (result, lookups) = self.wait_for_user_deletion(req).await;
+                let detail = Some(format!("{lookups} lookups"));
+                match result {
+                    Ok(()) => audit.finish(GdprStepOutcome::Succeeded, detail),
+                    Err(error) => audit.finish(failed(error), detail),
+                }
+            } else {
+                audit.skip("the deletion did not run or failed".to_string())
+            });
+        }
+
+        #[cfg(feature = "tracing")]
+        for record in &records {
+            tracing::info!(
+                case_id = record.case_id,
+                step = record.step.as_str(),
+                outcome = ?record.outcome,
+                "gdpr erasure step"
+            );
+        }
+        GdprErasureReport {
+            case_id,
+            uid: req.uid.to_string(),
+            records,
+        }
+    }
+
+    /// Look a user up until it is not found, returning the number of lookups
+    async fn wait_for_user_deletion(
+        &self,
+        req: &GdprErasureRequest<'_>,
+    ) -> (Result<(), crate::Error>, usize) {
+        let started = Instant::now();
+        let mut lookups = 0;
+        loop {
+            lookups += 1;
+            match self.get_user(req.uid).await {
+                Ok(None) => return (Ok(()), lookups),
+                Ok(Some(_)) => {}
+                Err(error) => return (Err(error), lookups),
+            }
+            let elapsed = started.elapsed();
+            if elapsed >= req.timeout {
+                return (
+                    Err(Box::new(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        format!("user {} still exists after {:?}", req.uid, req.timeout),
+                    ))),
+                    lookups,
+                );
+            }
+            tokio::time::sleep(req.poll_interval.min(req.timeout - elapsed)).await;
+        }
+    }
+}
+
+fn write_export<W: Write>(
+    export: &GdprExportResponse,
+    mut writer: W,
+) -> Result<usize, crate::Error> {
+    let value = serde_json::to_vec_pretty(export)?;
+    writer.write_all(&value)?;
+    writer.flush()?;
+    Ok(value.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn record(step: GdprStep, outcome: GdprStepOutcome) -> GdprAuditRecord {
+        GdprAuditRecord {
+            case_id: "DSR-1".to_string(),
+            uid: "U1".to_string(),
+            scope: GdprScope::All,
+            step,
+            outcome,
+            started_at: 1700000000000,
+            finished_at: 1700000000250,
+            request_id: format!("DSR-1-{step}-1700000000000-0"),
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let mut report = GdprErasureReport {
+            case_id: "DSR-1".to_string(),
+            uid: "U1".to_string(),
+            records: vec![
+                record(GdprStep::Export, GdprStepOutcome::Succeeded),
+                record(GdprStep::Delete, GdprStepOutcome::Succeeded),
+                record(GdprStep::Verify, GdprStepOutcome::Succeeded),
+            ],
+        };
+        assert!(report.is_erased());
+        assert_eq!(report.failures().count(), 0);
+
+        report.records[1].outcome = GdprStepOutcome::Failed {
+            error: "Access denied".to_string(),
+        };
+        report.records[2].outcome = GdprStepOutcome::Skipped {
+            reason: "the deletion did not run or failed".to_string(),
+        };
+        assert!(!report.is_erased());
+        assert_eq!(
+            report
+                .failures()
+                .map(|record| record.step)
+                .collect::<Vec<_>>(),
+            vec![GdprStep::Delete]
+        );
+        assert_eq!(
+            report
+                .record(GdprStep::Verify)
+                .map(GdprAuditRecord::is_succeeded),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_write_audit_log() {
+        let report = GdprErasureReport {
+            case_id: "DSR-1".to_string(),
+            uid: "U1".to_string(),
+            records: vec![
+                record(
+                    GdprStep::Export,
+                    GdprStepOutcome::Failed {
+                        error: "timeout".to_string(),
+                    },
+                ),
+                record(
+                    GdprStep::Delete,
+                    GdprStepOutcome::Skipped {
+                        reason: "the export failed".to_string(),
+                    },
+                ),
+            ],
+        };
+        let mut log = vec![];
+        report.write_audit_log(&mut log).expect("OK");
+        let lines = String::from_utf8(log).expect("OK");
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(lines[0]).expect("OK"),
+            json!({
+                "case_id": "DSR-1",
+                "uid": "U1",
+                "scope": "ALL",
+                "step": "export",
+                "outcome": "failed",
+                "error": "timeout",
+                "started_at": 1700000000000i64,
+                "finished_at": 1700000000250i64,
+                "request_id": "DSR-1-export-1700000000000-0"
+            })
+        );
+        assert_eq!(
+            serde_json::from_str::<GdprAuditRecord>(lines[1]).expect("OK"),
+            report.records[1]
+        );
+    }
+
+    #[test]
+    fn test_request_ids_are_unique() {
+        let request = GdprErasureRequest::new("U1");
+        let first = StepAudit::start("DSR-1", &request, GdprStep::Export);
+        let second = StepAudit::start("DSR-1", &request, GdprStep::Export);
+        assert!(first.request_id.starts_with("DSR-1-export-"));
+        assert_ne!(first.request_id, second.request_id);
+    }
+
+    #[test]
+    fn test_write_export() {
+        let export = serde_json::from_value::<GdprExportResponse>(json!({
+            "user": { "uid": "U1" },
+            "payments": [{ "payment_id": "P1" }]
+        }))
+        .expect("OK");
+        let mut output = vec![];
+        let size = write_export(&export, &mut output).expect("OK");
+        assert_eq!(size, output.len());
+        let value = serde_json::from_slice::<serde_json::Value>(&output).expect("OK");
+        assert_eq!(value["user"]["uid"], "U1");
+        assert_eq!(value["payments"][0]["payment_id"], "P1");
+    }
+}
@@ -0,0 +1,204 @@
+/// Customer service inquiry endpoints for Piano Publisher API.
+///
+/// Inquiries are raised by users from My Account, e.g. to ask for a refund, and are answered
+/// by the publisher with comments and actions.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use piano_handwritten_api::{PianoAPI, publisher::inquiry::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// let unresolved = api.count_unresolved_inquiries(None).await?;
+/// println!("{unresolved} inquiries to answer");
+///
+/// // Resolve the inquiries of a user
+/// let inquiries = api.list_inquiries(&ListInquiryRequest::new("user_uid")).await?;
+/// for inquiry in &inquiries.value.inquiries {
+///     let actions = api.list_inquiry_actions(inquiry.payment_inquiry_id()).await?;
+///     if actions.contains(&InquiryAction::Resolve) {
+///         let request = InquiryActionRequest::new(
+///             inquiry.payment_inquiry_id(),
+///             &[InquiryAction::Resolve],
+///             "Thank you for reaching out",
+///         );
+///         api.perform_inquiry_action(&request).await?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+mod schema;
+pub use self::schema::*;
+
+use crate::{PianoAPI, PianoPaginated, PianoResponse};
+
+impl PianoAPI {
+    /// Comment an inquiry and perform actions on it (e.g. resolve it or refund the user).
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2Fadd)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn perform_inquiry_action(
+        &self,
+        params: &InquiryActionRequest<'_>,
+    ) -> Result<Inquiry, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/inquiry/add", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<InquiryResult>>()
+            .await?
+            .value()?;
+        Ok(result.inquiry)
+    }
+
+    /// List the inquiries of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_inquiries(
+        &self,
+        params: &ListInquiryRequest<'_>,
+    ) -> Result<PianoPaginated<ListInquiryResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/inquiry/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListInquiryResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// Get an inquiry.
+    ///
+    /// Returns `None` if the inquiry is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2Fdetails)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_inquiry(
+        &self,
+        payment_inquiry_id: &str,
+    ) -> Result<Option<Inquiry>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/inquiry/details", self.endpoint))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("payment_inquiry_id", payment_inquiry_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<InquiryResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.inquiry))
+    }
+
+    /// Get the inquiry of a user with the last comment.
+    ///
+    /// Returns `None` if the user has no inquiry.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2FlastComment)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_last_inquiry_comment(
+        &self,
+        uid: &str,
+    ) -> Result<Option<Inquiry>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/inquiry/lastComment", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("uid", uid)])
+            .send()
+            .await?
+            .json::<PianoResponse<InquiryResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.inquiry))
+    }
+
+    /// List the comments of an inquiry.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2Fcomments)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_inquiry_comments(
+        &self,
+        payment_inquiry_id: &str,
+    ) -> Result<Vec<InquiryComment>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/inquiry/comments", self.endpoint))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("payment_inquiry_id", payment_inquiry_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<InquiryCommentsResult>>()
+            .await?
+            .value()?;
+        Ok(result.comments)
+    }
+
+    /// List the actions which can be performed on an inquiry.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2FinquiryActions)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_inquiry_actions(
+        &self,
+        payment_inquiry_id: &str,
+    ) -> Result<Vec<InquiryAction>, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/inquiry/inquiryActions",
+                self.endpoint
+            ))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("payment_inquiry_id", payment_inquiry_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<InquiryActionsResult>>()
+            .await?
+            .value()?;
+        Ok(result
+            .actions
+            .into_iter()
+            .map(|action| action.caption)
+            .collect())
+    }
+
+    /// Count the unresolved inquiries of the application, or of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2FunresolvedCount)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn count_unresolved_inquiries(&self, uid: Option<&str>) -> Result<i64, crate::Error> {
+        let mut request = self
+            .client
+            .get(format!(
+                "{}/publisher/inquiry/unresolvedCount",
+                self.endpoint
+            ))
+            .query(&[("aid", &self.app_id)]);
+        if let Some(uid) = uid {
+            request = request.query(&[("uid", uid)]);
+        }
+        let result = request
+            .send()
+            .await?
+            .json::<PianoResponse<InquiryCountResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+}
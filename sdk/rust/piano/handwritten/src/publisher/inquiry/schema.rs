@@ -0,0 +1,441 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Action performed on an inquiry
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InquiryAction {
+    ReplyOnly,
+    Resolve,
+    RefundGrant,
+    RefundRevoke,
+    NoRefundGrant,
+    NoRefundRevoke,
+    RefundOnly,
+    Decline,
+    SubCancel,
+    SubRefundOnly,
+    SubRefundCancel,
+}
+
+impl InquiryAction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReplyOnly => "REPLY_ONLY",
+            Self::Resolve => "RESOLVE",
+            Self::RefundGrant => "REFUND_GRANT",
+            Self::RefundRevoke => "REFUND_REVOKE",
+            Self::NoRefundGrant => "NO_REFUND_GRANT",
+            Self::NoRefundRevoke => "NO_REFUND_REVOKE",
+            Self::RefundOnly => "REFUND_ONLY",
+            Self::Decline => "DECLINE",
+            Self::SubCancel => "SUB_CANCEL",
+            Self::SubRefundOnly => "SUB_REFUND_ONLY",
+            Self::SubRefundCancel => "SUB_REFUND_CANCEL",
+        }
+    }
+}
+
+/// Request to comment an inquiry and perform actions on it
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2Fadd)
+#[derive(Debug, Serialize)]
+pub struct InquiryActionRequest<'a> {
+    /// The public ID of the inquiry
+    pub payment_inquiry_id: &'a str,
+    /// The actions to perform
+    #[serde(rename = "action", serialize_with = "serialize_actions")]
+    pub actions: &'a [InquiryAction],
+    /// The comment
+    pub comment: &'a str,
+    /// Whether the comment is internal, i.e. hidden from the user
+    pub internal: bool,
+}
+
+impl<'a> InquiryActionRequest<'a> {
+    /// Create a new inquiry action request
+    pub fn new(
+        payment_inquiry_id: &'a str,
+        actions: &'a [InquiryAction],
+        comment: &'a str,
+    ) -> Self {
+        Self {
+            payment_inquiry_id,
+            actions,
+            comment,
+            internal: false,
+        }
+    }
+
+    /// Set whether the comment is internal
+    pub fn with_internal(mut self, internal: bool) -> Self {
+        self.internal = internal;
+        self
+    }
+}
+
+/// Request to list the inquiries of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Finquiry~2Flist)
+#[derive(Debug, Serialize, Clone)]
+pub struct ListInquiryRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum number of results to return
+    pub limit: usize,
+}
+
+impl<'a> ListInquiryRequest<'a> {
+    /// Create a new list inquiry request
+    pub fn new(uid: &'a str) -> Self {
+        Self {
+            uid,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the offset
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the limit
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// Customer service inquiry
+#[derive(Debug, Deserialize, Clone)]
+pub struct Inquiry {
+    payment_inquiry_id: String,
+    #[serde(default)]
+    state: Option<i32>,
+    #[serde(default)]
+    inquiry_reason: Option<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    create_date: Option<String>,
+    #[serde(default)]
+    inquiry_comments: Vec<InquiryComment>,
+    #[serde(default)]
+    resource: Option<serde_json::Value>,
+    #[serde(default)]
+    update_state_by: Option<serde_json::Value>,
+    #[serde(default)]
+    update_state_date: Option<String>,
+    #[serde(default)]
+    start_date: Option<String>,
+    #[serde(default)]
+    expire_date: Option<String>,
+    #[serde(default)]
+    transaction_date: Option<String>,
+    #[serde(default)]
+    transaction_id: Option<String>,
+    #[serde(default)]
+    spent_money: Option<f64>,
+    #[serde(default)]
+    spent_money_display: Option<String>,
+    #[serde(default)]
+    currency: Option<String>,
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    refunded_date: Option<String>,
+    #[serde(default)]
+    refund_amount: Option<String>,
+    #[serde(default)]
+    is_access_expired: bool,
+    #[serde(default)]
+    is_access_revoked: bool,
+    #[serde(default)]
+    is_access_unlimited: bool,
+}
+
+impl Inquiry {
+    /// Get the public ID of the inquiry
+    pub fn payment_inquiry_id(&self) -> &str {
+        &self.payment_inquiry_id
+    }
+    /// Get the state of the inquiry
+    pub fn state(&self) -> Option<i32> {
+        self.state
+    }
+    /// Get the reason of the inquiry
+    pub fn inquiry_reason(&self) -> Option<&str> {
+        self.inquiry_reason.as_deref()
+    }
+    /// Get the category of the inquiry
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<&str> {
+        self.create_date.as_deref()
+    }
+    /// Get the comments of the inquiry
+    pub fn inquiry_comments(&self) -> &[InquiryComment] {
+        &self.inquiry_comments
+    }
+    /// Get the resource the inquiry is about
+    pub fn resource(&self) -> Option<&serde_json::Value> {
+        self.resource.as_ref()
+    }
+    /// Get the ID of the resource the inquiry is about
+    pub fn rid(&self) -> Option<&str> {
+        self.resource.as_ref()?.get("rid")?.as_str()
+    }
+    /// Get the user who last updated the state
+    pub fn update_state_by(&self) -> Option<&serde_json::Value> {
+        self.update_state_by.as_ref()
+    }
+    /// Get the date when the state was last updated
+    pub fn update_state_date(&self) -> Option<&str> {
+        self.update_state_date.as_deref()
+    }
+    /// Get the start date of the access
+    pub fn start_date(&self) -> Option<&str> {
+        self.start_date.as_deref()
+    }
+    /// Get the expiration date of the access
+    pub fn expire_date(&self) -> Option<&str> {
+        self.expire_date.as_deref()
+    }
+    /// Get the transaction date
+    pub fn transaction_date(&self) -> Option<&str> {
+        self.transaction_date.as_deref()
+    }
+    /// Get the transaction ID
+    pub fn transaction_id(&self) -> Option<&str> {
+        self.transaction_id.as_deref()
+    }
+    /// Get the money spent
+    pub fn spent_money(&self) -> Option<f64> {
+        self.spent_money
+    }
+    /// Get the money spent as displayed
+    pub fn spent_money_display(&self) -> Option<&str> {
+        self.spent_money_display.as_deref()
+    }
+    /// Get the currency of the payment
+    pub fn currency(&self) -> Option<&str> {
+        self.currency.as_deref()
+    }
+    /// Get the source
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+    /// Get the refund date
+    pub fn refunded_date(&self) -> Option<&str> {
+        self.refunded_date.as_deref()
+    }
+    /// Get the refunded amount, before tax
+    pub fn refund_amount(&self) -> Option<&str> {
+        self.refund_amount.as_deref()
+    }
+    /// Check if the access is expired
+    pub fn is_access_expired(&self) -> bool {
+        self.is_access_expired
+    }
+    /// Check if the access is revoked
+    pub fn is_access_revoked(&self) -> bool {
+        self.is_access_revoked
+    }
+    /// Check if the access is unlimited
+    pub fn is_access_unlimited(&self) -> bool {
+        self.is_access_unlimited
+    }
+}
+
+/// Comment of an inquiry
+#[derive(Debug, Deserialize, Clone)]
+pub struct InquiryComment {
+    comment_id: String,
+    #[serde(default)]
+    submitter_type: Option<i32>,
+    #[serde(default)]
+    create_date: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    personal_name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_internal")]
+    internal: bool,
+}
+
+impl InquiryComment {
+    /// Get the comment ID
+    pub fn comment_id(&self) -> &str {
+        &self.comment_id
+    }
+    /// Get the submitter type
+    pub fn submitter_type(&self) -> Option<i32> {
+        self.submitter_type
+    }
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<&str> {
+        self.create_date.as_deref()
+    }
+    /// Get the message
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+    /// Get the submitter's email address
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+    /// Get the submitter's name
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    /// Get the submitter's name, ordered as per locale
+    pub fn personal_name(&self) -> Option<&str> {
+        self.personal_name.as_deref()
+    }
+    /// Check if the comment is internal, i.e. hidden from the user
+    pub fn is_internal(&self) -> bool {
+        self.internal
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct InquiryResult {
+    #[serde(rename = "PaymentInquiry")]
+    pub inquiry: Inquiry,
+}
+
+/// Result of a list inquiry request
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListInquiryResult {
+    #[serde(rename = "PaymentInquiry", default)]
+    pub inquiries: Vec<Inquiry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct InquiryCommentsResult {
+    #[serde(rename = "InquiryComment", default)]
+    pub comments: Vec<InquiryComment>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct CommentAction {
+    pub caption: InquiryAction,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct InquiryActionsResult {
+    #[serde(rename = "CommentAction", default)]
+    pub actions: Vec<CommentAction>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct InquiryCountResult {
+    pub data: i64,
+}
+
+fn serialize_actions<S>(actions: &&[InquiryAction], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let actions: Vec<&str> = actions.iter().map(InquiryAction::as_str).collect();
+    serializer.serialize_str(&actions.join(","))
+}
+
+/// The `internal` flag of comments is documented as a string
+fn deserialize_internal<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        String(String),
+    }
+    Ok(match Option::<Flag>::deserialize(deserializer)? {
+        Some(Flag::Bool(internal)) => internal,
+        Some(Flag::String(internal)) => internal == "true",
+        None => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_inquiry_action_request() {
+        let request = InquiryActionRequest::new(
+            "PI1",
+            &[InquiryAction::RefundGrant, InquiryAction::Resolve],
+            "Refunded",
+        )
+        .with_internal(true);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "payment_inquiry_id": "PI1",
+                "action": "REFUND_GRANT,RESOLVE",
+                "comment": "Refunded",
+                "internal": true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_inquiry() {
+        let result: InquiryResult = serde_json::from_value(json!({
+            "PaymentInquiry": {
+                "payment_inquiry_id": "PI1",
+                "resource": { "rid": "RID1", "name": "Premium" },
+                "state": 1,
+                "inquiry_reason": "Refund",
+                "create_date": "2024-01-01",
+                "inquiry_comments": [{
+                    "comment_id": "IC1",
+                    "submitter_type": 0,
+                    "create_date": "2024-01-01",
+                    "message": "Please refund",
+                    "email": "user@example.com",
+                    "name": "Jane",
+                    "personal_name": "Jane Doe",
+                    "internal": "false"
+                }],
+                "spent_money": 9.99,
+                "currency": "USD",
+                "is_access_expired": false,
+                "is_access_revoked": false,
+                "is_access_unlimited": true
+            }
+        }))
+        .expect("OK");
+        let inquiry = result.inquiry;
+        assert_eq!(inquiry.rid(), Some("RID1"));
+        assert_eq!(inquiry.spent_money(), Some(9.99));
+        assert_eq!(inquiry.inquiry_comments().len(), 1);
+        assert!(!inquiry.inquiry_comments()[0].is_internal());
+    }
+
+    #[test]
+    fn test_deserialize_inquiry_actions() {
+        let result: InquiryActionsResult = serde_json::from_value(json!({
+            "CommentAction": [
+                { "id": "1", "caption": "REPLY_ONLY" },
+                { "id": "2", "caption": "SUB_REFUND_CANCEL" }
+            ]
+        }))
+        .expect("OK");
+        assert_eq!(result.actions[1].caption, InquiryAction::SubRefundCancel);
+    }
+}
@@ -71,7 +71,9 @@ pub mod contract;
 /// Manages domain restrictions and validation for email domain contracts:
 /// - **Domain specification**: Add domains like "company.com" for user access
 /// - **Wildcard support**: Use asterisk (*) as wildcard character in domains  
-/// - **Domain validation**: Automatic validation of domain formats
+/// - **Domain validation**: Lowercase and punycode domains, and reject free email providers
+/// - **Duplicate detection**: Find domains set on more than one contract
+/// - **Resolution**: Find the contract granting access to an email address
 /// - **Access rules**: Any user with email from specified domains can redeem
 /// - **Domain management**: Add, remove, and list domains for contracts
 ///
@@ -93,7 +95,8 @@ pub mod contract_domain_user;
 ///
 /// Manages IP address restrictions for automatic, registration-free access:
 /// - **IP range definition**: Support IPv4/IPv6 addresses and CIDR notation (e.g., 192.168.0.1/24)
-/// - **DNS name support**: Accept DNS names in addition to IP addresses
+/// - **Validation**: Malformed ranges are rejected before they are sent to Piano
+/// - **Overlap checks**: Find overlapping ranges and the contracts covering an address
 /// - **Automatic access**: Users get immediate access based on IP, no registration needed
 /// - **Anonymous support**: Works with unregistered/anonymous users
 /// - **Composer integration**: Automatic paywall bypass for matching IP addresses
@@ -140,6 +143,24 @@ pub mod licensee;
 /// **Note**: Notifications appear in dashboard only; no emails are sent to managers or representatives.
 pub mod notification;
 
+/// Notification rules of licensees
+///
+/// Configures which licensing events show up as notifications:
+/// - **Seat triggers**: Percentage of used seats exceeds or drops below a threshold, or seats reach capacity
+/// - **Period triggers**: Days before the end of the access period or the date of sale
+/// - **Contract scope**: Rules apply to all contracts of the licensee or to selected ones
+/// - **Provisioning**: Create the standard rules a licensee is missing
+pub mod notification_rule;
+
+/// Reconciliation of contract users with a roster
+///
+/// Keeps specific email address contracts in sync with the rosters sent by licensees:
+/// - **Planning**: Compute the users to add, restore, rename and remove
+/// - **Seat limits**: Skip the users which do not fit in contracts with a hard seats limit
+/// - **Dry run**: Print or serialize the plan without calling the API
+/// - **Reporting**: Apply the plan with bounded concurrency and report the outcome per user
+pub mod reconcile;
+
 /// Licensing schedules and billing period management
 ///
 /// Manages temporal aspects and billing cycles of licensing contracts:
@@ -150,6 +171,7 @@ pub mod notification;
 /// - **Renewal management**: Automatic renewals when new periods are available
 /// - **Expiration handling**: "Won't renew" state when no next period exists
 /// - **Schedule reuse**: Same schedule can be used across multiple contracts
+/// - **Affected contracts**: List the contracts using a schedule before changing or retiring it
 ///
 /// **Important**: Users lose access when schedules expire unless new periods are added.
 pub mod schedule;
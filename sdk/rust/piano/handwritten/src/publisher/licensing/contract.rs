@@ -3,7 +3,7 @@ pub mod schedule;
 mod schema;
 pub use self::schema::*;
 
-use crate::{Empty, PianoAPI, PianoPaginated, PianoResponse};
+use crate::{fetch_all_pages, Empty, PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -27,6 +27,31 @@ impl PianoAPI {
         Ok(result)
     }
 
+    /// List all the contracts of a licensee
+    ///
+    /// Follows the pagination of [`PianoAPI::list_contracts`] until every contract has
+    /// been read.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_contracts(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Vec<Contract>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListContractRequest::new(licensee_id)
+                    .with_limit(100)
+                    .with_offset(offset);
+                async move { self.list_contracts(&params).await }
+            },
+            |page| page.contracts,
+        )
+        .await
+    }
+
     /// Get a contract by its ID
     ///
     /// Returns a contract by its public ID.
@@ -169,4 +194,40 @@ impl PianoAPI {
             .value()?;
         Ok(())
     }
+
+    /// Redeem a contract for a user
+    ///
+    /// Gives the user access to the resource of the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The redeem contract request
+    ///
+    /// # Returns
+    ///
+    /// Returns the data returned by Piano on successful redemption.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2Fcontract~2Fredeem) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn redeem_contract<'a>(
+        &self,
+        req: &RedeemContractRequest<'a>,
+    ) -> Result<String, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/licensing/contract/redeem",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<RedeemContractResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
 }
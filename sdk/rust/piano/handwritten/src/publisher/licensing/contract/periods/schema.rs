@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+pub use crate::publisher::licensing::contract::SchedulePeriodStatus;
+
 #[derive(Debug, Serialize)]
 pub struct ActivatePeriodRequest {
     contract_id: String,
@@ -39,15 +41,7 @@ impl SchedulePeriod {
     pub fn status(&self) -> &SchedulePeriodStatus {
         &self.status
     }
-}
-#[derive(Debug, Deserialize, Clone)]
-pub enum SchedulePeriodStatus {
-    #[serde(rename = "ACTIVATED")]
-    Activated,
-    #[serde(rename = "ACTIVE")]
-    Active,
-    #[serde(rename = "INACTIVE")]
-    Inactive,
-    #[serde(rename = "ENDED")]
-    Ended,
+    pub fn ready(&self) -> bool {
+        self.status.is_ready()
+    }
 }
@@ -113,6 +113,34 @@ impl<'a> DeactivateContractRequest<'a> {
     }
 }
 
+/// Request to redeem a contract for a user
+///
+/// Gives the user access to the resource of the contract.
+///
+/// # Reference
+///
+/// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2Fcontract~2Fredeem) for more details.
+#[derive(Debug, Serialize)]
+pub struct RedeemContractRequest<'a> {
+    /// The public ID of the contract
+    pub contract_id: &'a str,
+    /// The user ID
+    pub uid: &'a str,
+}
+
+impl<'a> RedeemContractRequest<'a> {
+    /// Create a new redeem contract request
+    pub fn new(contract_id: &'a str, uid: &'a str) -> Self {
+        Self { contract_id, uid }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct RedeemContractResult {
+    #[serde(default)]
+    pub data: String,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ListContractResult {
     pub contracts: Vec<Contract>,
@@ -169,16 +197,10 @@ pub struct ContractPeriod {
 
 impl ContractPeriod {
     pub fn ready(&self) -> bool {
-        matches!(
-            self.status(),
-            SchedulePeriodStatus::Active | SchedulePeriodStatus::Activated
-        )
+        self.status.is_ready()
     }
     pub fn not_ready(&self) -> bool {
-        matches!(
-            self.status(),
-            SchedulePeriodStatus::Inactive | SchedulePeriodStatus::Ended
-        )
+        !self.status.is_ready()
     }
 }
 
@@ -203,10 +225,15 @@ impl ContractPeriod {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// Status of a period of a licensing schedule
+///
+/// Shared by the periods of contracts and the periods returned when activating them.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SchedulePeriodStatus {
+    /// The period is activated but has not started yet
     #[serde(rename = "ACTIVATED")]
     Activated,
+    /// The period has started and grants access
     #[serde(rename = "ACTIVE")]
     Active,
     #[serde(rename = "INACTIVE")]
@@ -215,6 +242,13 @@ pub enum SchedulePeriodStatus {
     Ended,
 }
 
+impl SchedulePeriodStatus {
+    /// Check whether the period grants access, now or once it starts
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Active | Self::Activated)
+    }
+}
+
 impl Contract {
     pub fn contract_id(&self) -> &str {
         &self.contract_id
@@ -1,7 +1,12 @@
+mod domain;
 mod schema;
+pub use self::domain::*;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoResponse};
+use futures_util::{stream, StreamExt, TryStreamExt};
+
+use super::contract::{Contract, PianoContractType};
+use crate::{fetch_all_pages, Empty, PianoAPI, PianoPaginated, PianoResponse, CONCURRENCY};
 
 impl PianoAPI {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -25,12 +30,37 @@ impl PianoAPI {
         Ok(result)
     }
 
+    /// List all the email domains of a contract
+    ///
+    /// Follows the pagination of [`PianoAPI::list_contract_domain`] until every domain has
+    /// been read.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_contract_domains(
+        &self,
+        contract_id: &str,
+    ) -> Result<Vec<ContractDomain>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListContractDomainRequest::new(contract_id).with_offset(offset);
+                async move { self.list_contract_domain(&params).await }
+            },
+            |page| page.contract_domains,
+        )
+        .await
+    }
+
+    /// Add an email domain to a contract
+    ///
+    /// The domain is normalized, see [`normalize_domain`], and an
+    /// [`std::io::ErrorKind::InvalidInput`] error is returned without calling Piano if it is
+    /// malformed or rejected by the policy of the request.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn create_contract_domain<'a>(
         &self,
         req: &CreateContractDomainRequest<'a>,
     ) -> Result<ContractDomain, crate::Error> {
-        println!("{:?}", self.app_id);
+        let domain = req.normalized_domain()?;
+        let req = CreateContractDomainRequest::new(req.contract_id(), &domain);
         let result = self
             .client
             .post(format!(
@@ -38,7 +68,7 @@ impl PianoAPI {
                 self.endpoint,
             ))
             .query(&[("aid", &self.app_id)])
-            .form(req)
+            .form(&req)
             .send()
             .await?
             .json::<PianoResponse<ContractDomainResult>>()
@@ -46,19 +76,27 @@ impl PianoAPI {
             .value()?;
         Ok(result.contract_domain)
     }
+    /// Update an email domain of a contract
+    ///
+    /// The domain is normalized, see [`normalize_domain`], and an
+    /// [`std::io::ErrorKind::InvalidInput`] error is returned without calling Piano if it is
+    /// malformed or rejected by the policy of the request.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn update_contract_domain<'a>(
         &self,
         req: &UpdateContractDomainRequest<'a>,
     ) -> Result<ContractDomain, crate::Error> {
+        let domain = req.normalized_domain()?;
+        let req =
+            UpdateContractDomainRequest::new(req.contract_domain_id(), req.contract_id(), &domain);
         let result = self
             .client
             .post(format!(
-                "{}/publisher/licensing/contractDomain/create",
+                "{}/publisher/licensing/contractDomain/update",
                 self.endpoint,
             ))
             .query(&[("aid", &self.app_id)])
-            .form(req)
+            .form(&req)
             .send()
             .await?
             .json::<PianoResponse<ContractDomainResult>>()
@@ -66,24 +104,149 @@ impl PianoAPI {
             .value()?;
         Ok(result.contract_domain)
     }
+    /// Remove an email domain from a contract
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractDomain~2Fremove) for more details.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn remove_contract_domain<'a>(
         &self,
         req: &RemoveContractDomainRequest<'a>,
-    ) -> Result<ContractDomain, crate::Error> {
-        let result = self
-            .client
+    ) -> Result<(), crate::Error> {
+        self.client
             .post(format!(
-                "{}/publisher/licensing/contractDomain/create",
+                "{}/publisher/licensing/contractDomain/remove",
                 self.endpoint,
             ))
             .query(&[("aid", &self.app_id)])
             .form(req)
             .send()
             .await?
-            .json::<PianoResponse<ContractDomainResult>>()
+            .json::<PianoResponse<Empty>>()
             .await?
             .value()?;
-        Ok(result.contract_domain)
+        Ok(())
+    }
+
+    /// Remove an email domain from a contract and revoke the access of its users
+    ///
+    /// All contract users with the email domain are removed and their accesses revoked.
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractDomain~2FremoveAndRevoke) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn remove_and_revoke_contract_domain<'a>(
+        &self,
+        req: &RemoveAndRevokeContractDomainRequest<'a>,
+    ) -> Result<(), crate::Error> {
+        self.client
+            .post(format!(
+                "{}/publisher/licensing/contractDomain/removeAndRevoke",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// List the email domains of all the email domain contracts of a licensee
+    ///
+    /// The domains of up to eight contracts are requested at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_licensee_domains(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Vec<LicenseeDomain>, crate::Error> {
+        let contracts = self.list_all_contracts(licensee_id).await?;
+        self.list_contracts_domains(&contracts).await
+    }
+
+    /// List the email domains of the email domain contracts of every licensee
+    ///
+    /// The contracts of up to eight licensees, then the domains of up to eight contracts,
+    /// are requested at the same time.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_app_domains(&self) -> Result<Vec<LicenseeDomain>, crate::Error> {
+        let licensees = self.list_all_licensees().await?;
+        let contracts = stream::iter(&licensees)
+            .map(|licensee| self.list_all_contracts(licensee.licensee_id()))
+            .buffered(CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+        let contracts = contracts.into_iter().flatten().collect::<Vec<_>>();
+        self.list_contracts_domains(&contracts).await
+    }
+
+    async fn list_contracts_domains(
+        &self,
+        contracts: &[Contract],
+    ) -> Result<Vec<LicenseeDomain>, crate::Error> {
+        let domains = stream::iter(contracts.iter().filter(|contract| {
+            matches!(
+                contract.contract_type(),
+                PianoContractType::EMAIL_DOMAIN_CONTRACT
+            )
+        }))
+        .map(|contract| async move {
+            let domains = self
+                .list_all_contract_domains(contract.contract_id())
+                .await?;
+            Ok::<_, crate::Error>(
+                domains
+                    .iter()
+                    .map(|domain| {
+                        LicenseeDomain::new(
+                            contract.licensee_id(),
+                            contract.contract_id(),
+                            contract.contract_is_active(),
+                            domain,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .buffered(CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await?;
+        Ok(domains.into_iter().flatten().collect())
+    }
+
+    /// Find the domains set on more than one contract of a licensee
+    ///
+    /// Use [`find_duplicate_domains`] with [`PianoAPI::list_app_domains`] to find the
+    /// duplicates across licensees.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn find_licensee_duplicate_domains(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Vec<DuplicateDomain>, crate::Error> {
+        let domains = self.list_licensee_domains(licensee_id).await?;
+        Ok(find_duplicate_domains(&domains))
+    }
+
+    /// Find the email domain contract granting access to the owner of an email address
+    ///
+    /// Scans the email domain contracts of every licensee, see [`resolve_domain`] for the
+    /// matching rules. Callers resolving many addresses should list the domains once with
+    /// [`PianoAPI::list_app_domains`] and use [`resolve_domain`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if no active contract covers the domain of the email address.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn resolve_contract_for_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<LicenseeDomain>, crate::Error> {
+        email_domain(email)?;
+        let domains = self.list_app_domains().await?;
+        Ok(resolve_domain(&domains, email)?.cloned())
     }
 }
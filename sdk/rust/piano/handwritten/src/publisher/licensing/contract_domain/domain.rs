@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::invalid_input;
+
+/// Free email providers rejected by [`DomainPolicy::default`]
+pub const FREE_MAIL_DOMAINS: &[&str] = &[
+    "163.com",
+    "aol.com",
+    "gmail.com",
+    "gmx.com",
+    "gmx.de",
+    "gmx.net",
+    "googlemail.com",
+    "hotmail.com",
+    "icloud.com",
+    "live.com",
+    "mac.com",
+    "mail.com",
+    "mail.ru",
+    "me.com",
+    "msn.com",
+    "naver.com",
+    "outlook.com",
+    "proton.me",
+    "protonmail.com",
+    "qq.com",
+    "web.de",
+    "yahoo.co.jp",
+    "yahoo.com",
+    "yandex.ru",
+    "zoho.com",
+];
+
+/// Rules applied to the email domains of contracts
+///
+/// Domains are lowercased and converted to their ASCII (punycode) form, so that
+/// `Bücher.Example` and `xn--bcher-kva.example` are the same domain. A leading `*.`
+/// label is kept as a wildcard matching every subdomain.
+///
+/// The default policy rejects the domains of [`FREE_MAIL_DOMAINS`]: a contract for
+/// `gmail.com` would grant access to anyone.
+#[derive(Debug, Clone)]
+pub struct DomainPolicy {
+    free_mail_domains: HashSet<String>,
+}
+
+impl Default for DomainPolicy {
+    fn default() -> Self {
+        Self {
+            free_mail_domains: FREE_MAIL_DOMAINS.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl DomainPolicy {
+    /// Create a policy rejecting the domains of [`FREE_MAIL_DOMAINS`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a policy accepting every well-formed domain
+    pub fn permissive() -> Self {
+        Self {
+            free_mail_domains: HashSet::new(),
+        }
+    }
+
+    /// Reject an additional free email provider
+    pub fn with_free_mail_domain(mut self, domain: &str) -> Self {
+        if let Ok(domain) = to_ascii(domain) {
+            self.free_mail_domains.insert(domain);
+        }
+        self
+    }
+
+    /// Accept a domain of the free email providers
+    pub fn without_free_mail_domain(mut self, domain: &str) -> Self {
+        if let Ok(domain) = to_ascii(domain) {
+            self.free_mail_domains.remove(&domain);
+        }
+        self
+    }
+
+    /// Check whether a normalized domain belongs to a free email provider
+    pub fn is_free_mail_domain(&self, domain: &str) -> bool {
+        let domain = domain.strip_prefix("*.").unwrap_or(domain);
+        self.free_mail_domains.contains(domain)
+    }
+
+    /// Normalize a contract domain
+    ///
+    /// Returns an [`std::io::ErrorKind::InvalidInput`] error if the domain is malformed or belongs
+    /// to a free email provider.
+    pub fn normalize(&self, domain: &str) -> Result<String, crate::Error> {
+        let domain = normalize_domain(domain)?;
+        if self.is_free_mail_domain(&domain) {
+            return Err(invalid_input(format!(
+                "{domain} is the domain of a free email provider"
+            )));
+        }
+        Ok(domain)
+    }
+}
+
+/// Normalize a domain without rejecting free email providers
+///
+/// Trims the domain, removes a leading `@` and a trailing `.`, then lowercases it and
+/// converts it to punycode. Returns an [`std::io::ErrorKind::InvalidInput`] error if the domain
+/// is malformed.
+pub fn normalize_domain(domain: &str) -> Result<String, crate::Error> {
+    let domain = domain.trim();
+    let domain = domain.strip_prefix('@').unwrap_or(domain);
+    match domain.strip_prefix("*.") {
+        Some(base) => Ok(format!("*.{}", to_ascii(base)?)),
+        None => to_ascii(domain),
+    }
+}
+
+/// Get the normalized domain of an email address
+pub fn email_domain(email: &str) -> Result<String, crate::Error> {
+    match email.trim().rsplit_once('@') {
+        Some((local, domain)) if !local.is_empty() => to_ascii(domain),
+        _ => Err(invalid_input(format!("{email:?} is not an email address"))),
+    }
+}
+
+/// Check whether a normalized contract domain matches the normalized domain of an email
+///
+/// `*.example.com` matches the subdomains of `example.com` but not `example.com` itself.
+pub fn domain_matches(contract_domain: &str, email_domain: &str) -> bool {
+    match contract_domain.strip_prefix("*.") {
+        Some(base) => email_domain
+            .strip_suffix(base)
+            .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+        None => contract_domain == email_domain,
+    }
+}
+
+fn to_ascii(domain: &str) -> Result<String, crate::Error> {
+    let domain = domain.trim().trim_end_matches('.');
+    let ascii = idna::domain_to_ascii(domain)
+        .map_err(|_| invalid_input(format!("{domain:?} is not a valid domain")))?;
+    let labels = ascii.split('.').collect::<Vec<_>>();
+    let well_formed = ascii.len() <= 253
+        && labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+        });
+    if !well_formed {
+        return Err(invalid_input(format!("{domain:?} is not a valid domain")));
+    }
+    Ok(ascii)
+}
+
+/// Email domain of one of the contracts of a licensee
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseeDomain {
+    licensee_id: String,
+    contract_id: String,
+    contract_is_active: bool,
+    contract_domain_id: String,
+    domain: String,
+    is_valid: bool,
+}
+
+impl LicenseeDomain {
+    /// Create a licensee domain, normalizing the domain when it is well-formed
+    pub fn new(
+        licensee_id: &str,
+        contract_id: &str,
+        contract_is_active: bool,
+        contract_domain: &super::ContractDomain,
+    ) -> Self {
+        let domain = normalize_domain(&contract_domain.contract_domain_value)
+            .unwrap_or_else(|_| contract_domain.contract_domain_value.to_lowercase());
+        Self {
+            licensee_id: licensee_id.to_string(),
+            contract_id: contract_id.to_string(),
+            contract_is_active,
+            contract_domain_id: contract_domain.contract_domain_id.clone(),
+            domain,
+            is_valid: contract_domain.status == "ACTIVE",
+        }
+    }
+    pub fn licensee_id(&self) -> &str {
+        &self.licensee_id
+    }
+    pub fn contract_id(&self) -> &str {
+        &self.contract_id
+    }
+    pub fn contract_is_active(&self) -> bool {
+        self.contract_is_active
+    }
+    pub fn contract_domain_id(&self) -> &str {
+        &self.contract_domain_id
+    }
+    /// Get the normalized domain
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+    /// Check whether Piano accepted the domain
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+}
+
+/// Domain set on more than one contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateDomain {
+    pub domain: String,
+    pub entries: Vec<LicenseeDomain>,
+}
+
+/// Find the domains set on more than one contract, ordered by domain
+pub fn find_duplicate_domains(domains: &[LicenseeDomain]) -> Vec<DuplicateDomain> {
+    let mut by_domain = BTreeMap::<&str, Vec<LicenseeDomain>>::new();
+    for domain in domains {
+        by_domain
+            .entry(&domain.domain)
+            .or_default()
+            .push(domain.clone());
+    }
+    by_domain
+        .into_iter()
+        .filter(|(_, entries)| {
+            entries
+                .iter()
+                .any(|entry| entry.contract_id != entries[0].contract_id)
+        })
+        .map(|(domain, entries)| DuplicateDomain {
+            domain: domain.to_string(),
+            entries,
+        })
+        .collect()
+}
+
+/// Find the contract domain granting access to the owner of an email address
+///
+/// Only valid domains of active contracts are considered. An exact domain is preferred
+/// to a wildcard, and a longer wildcard to a shorter one.
+pub fn resolve_domain<'a>(
+    domains: &'a [LicenseeDomain],
+    email: &str,
+) -> Result<Option<&'a LicenseeDomain>, crate::Error> {
+    let email_domain = email_domain(email)?;
+    Ok(domains
+        .iter()
+        .filter(|domain| domain.is_valid && domain.contract_is_active)
+        .filter(|domain| domain_matches(&domain.domain, &email_domain))
+        .max_by_key(|domain| (!domain.domain.starts_with("*."), domain.domain.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::publisher::licensing::contract_domain::{
+        ContractDomain, CreateContractDomainRequest, ListContractDomainRequest,
+        UpdateContractDomainRequest,
+    };
+
+    #[test]
+    fn test_normalize() {
+        let policy = DomainPolicy::new();
+        assert_eq!(
+            policy.normalize(" @Example.COM. ").expect("OK"),
+            "example.com"
+        );
+        assert_eq!(
+            policy.normalize("Bücher.Example").expect("OK"),
+            "xn--bcher-kva.example"
+        );
+        assert_eq!(policy.normalize("*.Uni.edu").expect("OK"), "*.uni.edu");
+        for invalid in [
+            "",
+            "localhost",
+            "exa mple.com",
+            "-a.com",
+            "a..com",
+            "GMail.com",
+        ] {
+            assert!(policy.normalize(invalid).is_err(), "{invalid:?} is valid");
+        }
+        assert!(DomainPolicy::permissive().normalize("gmail.com").is_ok());
+        assert!(DomainPolicy::new()
+            .with_free_mail_domain("Example.org")
+            .normalize("*.example.org")
+            .is_err());
+    }
+
+    #[test]
+    fn test_request_domain() {
+        let req = CreateContractDomainRequest::new("C1", "Example.COM");
+        assert_eq!(req.normalized_domain().expect("OK"), "example.com");
+        assert_eq!(
+            CreateContractDomainRequest::new("C1", "GMail.com")
+                .normalized_domain()
+                .expect("OK"),
+            "gmail.com"
+        );
+        assert!(UpdateContractDomainRequest::new("D1", "C1", "exa mple.com")
+            .normalized_domain()
+            .is_err());
+        let policy = DomainPolicy::new();
+        assert!(CreateContractDomainRequest::new("C1", "gmail.com")
+            .with_policy(&policy)
+            .normalized_domain()
+            .is_err());
+        assert!(UpdateContractDomainRequest::new("D1", "C1", "example.com")
+            .with_policy(&policy)
+            .normalized_domain()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_list_request() {
+        let request = ListContractDomainRequest::new("C1").with_offset(100);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({ "contract_id": "C1", "offset": 100, "limit": 100 })
+        );
+    }
+
+    #[test]
+    fn test_domain_matches() {
+        assert!(domain_matches("example.com", "example.com"));
+        assert!(!domain_matches("example.com", "mail.example.com"));
+        assert!(domain_matches("*.example.com", "mail.example.com"));
+        assert!(!domain_matches("*.example.com", "example.com"));
+        assert!(!domain_matches("*.example.com", "badexample.com"));
+    }
+
+    #[test]
+    fn test_duplicates_and_resolution() {
+        let domain = |licensee_id: &str, contract_id: &str, active: bool, value: &str| {
+            let contract_domain = ContractDomain {
+                contract_domain_id: format!("{contract_id}-{value}"),
+                contract_domain_value: value.to_string(),
+                status: "ACTIVE".to_string(),
+                contract_users_count: 0,
+                active_contract_users_count: 0,
+            };
+            LicenseeDomain::new(licensee_id, contract_id, active, &contract_domain)
+        };
+        let domains = [
+            domain("L1", "C1", true, "*.uni.edu"),
+            domain("L1", "C2", true, "Law.Uni.edu"),
+            domain("L2", "C3", false, "law.uni.edu"),
+            domain("L2", "C4", true, "corp.com"),
+        ];
+
+        let duplicates = find_duplicate_domains(&domains);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].domain, "law.uni.edu");
+        assert_eq!(duplicates[0].entries.len(), 2);
+
+        let resolve = |email: &str| {
+            resolve_domain(&domains, email)
+                .expect("OK")
+                .map(|domain| domain.contract_id().to_string())
+        };
+        assert_eq!(resolve("jane@law.uni.edu").as_deref(), Some("C2"));
+        assert_eq!(resolve("john@med.uni.edu").as_deref(), Some("C1"));
+        assert_eq!(resolve("ceo@Corp.com").as_deref(), Some("C4"));
+        assert_eq!(resolve("someone@gmail.com"), None);
+        assert!(resolve_domain(&domains, "not-an-email").is_err());
+    }
+}
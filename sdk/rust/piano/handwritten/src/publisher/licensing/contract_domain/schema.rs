@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::{normalize_domain, DomainPolicy};
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ContractDomainListResult {
     #[serde(rename = "ContractDomainList")]
@@ -9,11 +11,27 @@ pub struct ContractDomainListResult {
 #[derive(Debug, Serialize)]
 pub struct ListContractDomainRequest<'a> {
     contract_id: &'a str,
+    offset: usize,
+    limit: usize,
 }
 
 impl<'a> ListContractDomainRequest<'a> {
     pub fn new(contract_id: &'a str) -> Self {
-        Self { contract_id }
+        Self {
+            contract_id,
+            offset: 0,
+            limit: 100,
+        }
+    }
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
     }
 }
 
@@ -21,6 +39,8 @@ impl<'a> ListContractDomainRequest<'a> {
 pub struct CreateContractDomainRequest<'a> {
     contract_id: &'a str,
     contract_domain_value: &'a str,
+    #[serde(skip)]
+    policy: Option<&'a DomainPolicy>,
 }
 
 #[derive(Debug, Serialize)]
@@ -28,6 +48,8 @@ pub struct UpdateContractDomainRequest<'a> {
     contract_domain_id: &'a str,
     contract_id: &'a str,
     contract_domain_value: &'a str,
+    #[serde(skip)]
+    policy: Option<&'a DomainPolicy>,
 }
 
 #[derive(Debug, Serialize)]
@@ -48,8 +70,19 @@ impl<'a> CreateContractDomainRequest<'a> {
         Self {
             contract_id,
             contract_domain_value,
+            policy: None,
         }
     }
+    /// Reject the domains refused by a policy, such as the free email providers of
+    /// [`DomainPolicy::new`]
+    pub fn with_policy(mut self, policy: &'a DomainPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+    /// Normalize the domain with the policy of the request
+    pub(super) fn normalized_domain(&self) -> Result<String, crate::Error> {
+        normalize_with(self.policy, self.contract_domain_value)
+    }
 }
 
 impl<'a> UpdateContractDomainRequest<'a> {
@@ -68,8 +101,29 @@ impl<'a> UpdateContractDomainRequest<'a> {
             contract_domain_id,
             contract_id,
             contract_domain_value,
+            policy: None,
         }
     }
+    /// Reject the domains refused by a policy, such as the free email providers of
+    /// [`DomainPolicy::new`]
+    pub fn with_policy(mut self, policy: &'a DomainPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+    /// Normalize the domain with the policy of the request
+    pub(super) fn normalized_domain(&self) -> Result<String, crate::Error> {
+        normalize_with(self.policy, self.contract_domain_value)
+    }
+    pub fn contract_domain_id(&self) -> &str {
+        self.contract_domain_id
+    }
+}
+
+fn normalize_with(policy: Option<&DomainPolicy>, domain: &str) -> Result<String, crate::Error> {
+    match policy {
+        Some(policy) => policy.normalize(domain),
+        None => normalize_domain(domain),
+    }
 }
 
 impl<'a> RemoveContractDomainRequest<'a> {
@@ -92,6 +146,35 @@ impl<'a> RemoveContractDomainRequest<'a> {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct RemoveAndRevokeContractDomainRequest<'a> {
+    contract_id: &'a str,
+    contract_domain_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract_user_session_id: Option<&'a str>,
+}
+
+impl<'a> RemoveAndRevokeContractDomainRequest<'a> {
+    pub fn contract_id(&self) -> &str {
+        self.contract_id
+    }
+    pub fn contract_domain_id(&self) -> &str {
+        self.contract_domain_id
+    }
+    pub fn new(contract_id: &'a str, contract_domain_id: &'a str) -> Self {
+        Self {
+            contract_id,
+            contract_domain_id,
+            contract_user_session_id: None,
+        }
+    }
+    /// Set the ID of the session where contract users are being changed
+    pub fn with_contract_user_session_id(mut self, contract_user_session_id: &'a str) -> Self {
+        self.contract_user_session_id = Some(contract_user_session_id);
+        self
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ContractDomainResult {
     #[serde(rename = "ContractDomain")]
@@ -1,7 +1,46 @@
+mod range;
 mod schema;
+pub use self::range::*;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoResponse};
+use std::net::IpAddr;
+
+use futures_util::{stream, StreamExt, TryStreamExt};
+
+use super::contract::PianoContractType;
+use crate::{fetch_all_pages, PianoAPI, PianoPaginated, PianoResponse, CONCURRENCY};
+
+/// Parse the stored IP ranges of a licensee, keyed by the IDs of their contracts
+///
+/// Fails listing every IP range which cannot be parsed.
+fn parse_licensee_ip_ranges<'a>(
+    ip_ranges: impl IntoIterator<Item = (&'a str, ContractIpRange)>,
+) -> Result<Vec<LicenseeIpRange>, crate::Error> {
+    let mut ranges = vec![];
+    let mut invalid = vec![];
+    for (contract_id, ip_range) in ip_ranges {
+        match ip_range.range() {
+            Ok(range) => ranges.push(LicenseeIpRange::new(
+                contract_id,
+                ip_range.contract_ip_range_id(),
+                range,
+            )),
+            Err(_) => invalid.push(format!(
+                "{} ({} of contract {})",
+                ip_range.ip_range(),
+                ip_range.contract_ip_range_id(),
+                contract_id
+            )),
+        }
+    }
+    if !invalid.is_empty() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("malformed IP ranges: {}", invalid.join(", ")),
+        )));
+    }
+    Ok(ranges)
+}
 
 impl PianoAPI {
     /// List contract IP ranges
@@ -30,9 +69,32 @@ impl PianoAPI {
         Ok(result)
     }
 
+    /// List all the IP ranges of a contract
+    ///
+    /// Follows the pagination of [`PianoAPI::list_contract_ip_ranges`] until every IP range
+    /// has been read.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_contract_ip_ranges(
+        &self,
+        contract_id: &str,
+    ) -> Result<Vec<ContractIpRange>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListContractIpRangeRequest::new(contract_id)
+                    .with_limit(100)
+                    .with_offset(offset);
+                async move { self.list_contract_ip_ranges(&params).await }
+            },
+            |page| page.contract_ip_ranges,
+        )
+        .await
+    }
+
     /// Create contract IP range
     ///
-    /// Creates an IP range for a given contract.
+    /// Creates an IP range for a given contract. The IP range is parsed with [`IpRange::parse`]
+    /// first, and an [`std::io::ErrorKind::InvalidInput`] error is returned without calling
+    /// Piano if it is malformed.
     ///
     /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractIpRange~2Fcreate)
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -40,6 +102,7 @@ impl PianoAPI {
         &self,
         req: &CreateContractIpRangeRequest<'_>,
     ) -> Result<ContractIpRange, crate::Error> {
+        IpRange::parse(req.ip_range)?;
         let result = self
             .client
             .post(format!(
@@ -58,7 +121,9 @@ impl PianoAPI {
 
     /// Update contract IP range
     ///
-    /// Updates an IP range for a given contract.
+    /// Updates an IP range for a given contract. The IP range is parsed with [`IpRange::parse`]
+    /// first, and an [`std::io::ErrorKind::InvalidInput`] error is returned without calling
+    /// Piano if it is malformed.
     ///
     /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractIpRange~2Fupdate)
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -66,6 +131,7 @@ impl PianoAPI {
         &self,
         req: &UpdateContractIpRangeRequest<'_>,
     ) -> Result<ContractIpRange, crate::Error> {
+        IpRange::parse(req.ip_range)?;
         let result = self
             .client
             .post(format!(
@@ -103,4 +169,138 @@ impl PianoAPI {
             .await?;
         Ok(())
     }
+
+    /// List the IP ranges of all the IP range contracts of a licensee
+    ///
+    /// The IP ranges of up to eight contracts are requested at the same time. Fails with
+    /// an [`std::io::ErrorKind::InvalidData`] error naming every stored IP range which
+    /// cannot be parsed, so that overlap checks never skip a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_licensee_ip_ranges(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Vec<LicenseeIpRange>, crate::Error> {
+        let contracts = self.list_all_contracts(licensee_id).await?;
+        let ranges = stream::iter(contracts.iter().filter(|contract| {
+            matches!(
+                contract.contract_type(),
+                PianoContractType::IP_RANGE_CONTRACT
+            )
+        }))
+        .map(|contract| async move {
+            let ip_ranges = self
+                .list_all_contract_ip_ranges(contract.contract_id())
+                .await?;
+            Ok::<_, crate::Error>(
+                ip_ranges
+                    .into_iter()
+                    .map(|ip_range| (contract.contract_id(), ip_range))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .buffered(CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await?;
+        parse_licensee_ip_ranges(ranges.into_iter().flatten())
+    }
+
+    /// Find the overlapping IP ranges among the contracts of a licensee
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn find_licensee_ip_range_overlaps(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Vec<IpRangeOverlap>, crate::Error> {
+        let ranges = self.list_licensee_ip_ranges(licensee_id).await?;
+        Ok(find_ip_range_overlaps(&ranges))
+    }
+
+    /// Find the existing IP ranges of a licensee which overlap a new IP range
+    ///
+    /// Use it before [`PianoAPI::create_contract_ip_range`] to keep the ranges of the
+    /// contracts apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    /// * `range` - The new IP range
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn check_licensee_ip_range(
+        &self,
+        licensee_id: &str,
+        range: &IpRange,
+    ) -> Result<Vec<LicenseeIpRange>, crate::Error> {
+        let ranges = self.list_licensee_ip_ranges(licensee_id).await?;
+        Ok(ranges
+            .into_iter()
+            .filter(|existing| existing.range().overlaps(range))
+            .collect())
+    }
+
+    /// Find the IP ranges of a licensee which contain an address
+    ///
+    /// Returns the matching IP ranges with the IDs of their contracts; more than one means
+    /// the ranges of the licensee overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    /// * `address` - The IP address
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn find_contracts_covering_ip(
+        &self,
+        licensee_id: &str,
+        address: IpAddr,
+    ) -> Result<Vec<LicenseeIpRange>, crate::Error> {
+        let ranges = self.list_licensee_ip_ranges(licensee_id).await?;
+        Ok(ranges
+            .into_iter()
+            .filter(|existing| existing.range().contains(address))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{parse_licensee_ip_ranges, ContractIpRange};
+
+    fn ip_range(id: &str, value: &str) -> ContractIpRange {
+        serde_json::from_value(json!({
+            "contract_ip_range_id": id,
+            "status": "VALID",
+            "ip_range": value,
+        }))
+        .expect("OK")
+    }
+
+    #[test]
+    fn test_parse_licensee_ip_ranges() {
+        let ranges = parse_licensee_ip_ranges([
+            ("TMA", ip_range("IPA", "10.0.0.0/8")),
+            ("TMB", ip_range("IPB", "192.168.1.1")),
+        ])
+        .expect("OK");
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[1].contract_id(), "TMB");
+        assert_eq!(ranges[1].contract_ip_range_id(), "IPB");
+
+        let error = parse_licensee_ip_ranges([
+            ("TMA", ip_range("IPA", "10.0.0.0/8")),
+            ("TMB", ip_range("IPB", "not a range")),
+        ])
+        .expect_err("malformed range");
+        assert_eq!(
+            error.to_string(),
+            "malformed IP ranges: not a range (IPB of contract TMB)"
+        );
+    }
 }
@@ -0,0 +1,368 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use crate::invalid_input;
+
+/// IPv4 or IPv6 address range
+///
+/// Parsed from the formats accepted by Piano for contract IP ranges:
+/// - a single address (`192.168.0.1`)
+/// - a CIDR block (`192.168.0.0/24`); the host bits of the address are ignored
+/// - a start and an end address (`192.168.0.1-192.168.0.100`)
+///
+/// Both ends of the range are inclusive. Ranges are equal when they cover the same
+/// addresses, whatever format they were parsed from.
+#[derive(Debug, Clone, Copy)]
+pub struct IpRange {
+    start: IpAddr,
+    end: IpAddr,
+    prefix: Option<u8>,
+}
+
+impl IpRange {
+    /// Create a range from its first and last addresses
+    ///
+    /// Returns an [`std::io::ErrorKind::InvalidInput`] error if the addresses are of different
+    /// families or if `start` is after `end`.
+    pub fn new(start: IpAddr, end: IpAddr) -> Result<Self, crate::Error> {
+        match (start, end) {
+            (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_)) => {}
+            _ => {
+                return Err(invalid_input(format!(
+                    "{start} and {end} are not of the same IP version"
+                )))
+            }
+        }
+        if to_bits(start) > to_bits(end) {
+            return Err(invalid_input(format!("{start} is after {end}")));
+        }
+        Ok(Self {
+            start,
+            end,
+            prefix: None,
+        })
+    }
+
+    /// Create the range of a CIDR block
+    ///
+    /// Returns an [`std::io::ErrorKind::InvalidInput`] error if the prefix length is longer than
+    /// the address.
+    pub fn cidr(address: IpAddr, prefix: u8) -> Result<Self, crate::Error> {
+        let bits = address_bits(address);
+        if u32::from(prefix) > bits {
+            return Err(invalid_input(format!(
+                "/{prefix} is longer than the {bits} bits of {address}"
+            )));
+        }
+        let host_mask = u128::MAX
+            .checked_shr(u32::from(prefix) + 128 - bits)
+            .unwrap_or(0);
+        let network = to_bits(address) & !host_mask;
+        Ok(Self {
+            start: from_bits(address, network),
+            end: from_bits(address, network | host_mask),
+            prefix: Some(prefix),
+        })
+    }
+
+    /// Create the range of a single address
+    pub fn single(address: IpAddr) -> Self {
+        Self {
+            start: address,
+            end: address,
+            prefix: None,
+        }
+    }
+
+    /// Parse a range in any of the formats accepted by Piano
+    pub fn parse(value: &str) -> Result<Self, crate::Error> {
+        let value = value.trim();
+        let address = |value: &str| {
+            IpAddr::from_str(value.trim())
+                .map_err(|_| invalid_input(format!("{value:?} is not an IP address")))
+        };
+        if let Some((start, end)) = value.split_once('-') {
+            Self::new(address(start)?, address(end)?)
+        } else if let Some((network, prefix)) = value.split_once('/') {
+            let prefix = prefix
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| invalid_input(format!("{prefix:?} is not a prefix length")))?;
+            Self::cidr(address(network)?, prefix)
+        } else {
+            Ok(Self::single(address(value)?))
+        }
+    }
+
+    /// Get the first address of the range
+    pub fn start(&self) -> IpAddr {
+        self.start
+    }
+
+    /// Get the last address of the range
+    pub fn end(&self) -> IpAddr {
+        self.end
+    }
+
+    /// Get the prefix length if the range was created from a CIDR block
+    pub fn prefix(&self) -> Option<u8> {
+        self.prefix
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        self.start.is_ipv4()
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        self.start.is_ipv6()
+    }
+
+    /// Count the addresses in the range, saturating for the whole IPv6 address space
+    pub fn len(&self) -> u128 {
+        (to_bits(self.end) - to_bits(self.start)).saturating_add(1)
+    }
+
+    /// Always false: a range contains at least one address
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Check whether the range contains an address
+    pub fn contains(&self, address: IpAddr) -> bool {
+        self.is_ipv4() == address.is_ipv4()
+            && to_bits(self.start) <= to_bits(address)
+            && to_bits(address) <= to_bits(self.end)
+    }
+
+    /// Check whether the ranges have at least one address in common
+    pub fn overlaps(&self, other: &IpRange) -> bool {
+        self.is_ipv4() == other.is_ipv4()
+            && to_bits(self.start) <= to_bits(other.end)
+            && to_bits(other.start) <= to_bits(self.end)
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = crate::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+/// Formats the range the way Piano accepts it
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.prefix {
+            Some(prefix) => write!(f, "{}/{prefix}", self.start),
+            None if self.start == self.end => write!(f, "{}", self.start),
+            None => write!(f, "{}-{}", self.start, self.end),
+        }
+    }
+}
+
+impl PartialEq for IpRange {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl Eq for IpRange {}
+
+impl Hash for IpRange {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+/// Orders IPv4 ranges before IPv6 ranges, then by first and last address
+impl Ord for IpRange {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.is_ipv6(), to_bits(self.start), to_bits(self.end)).cmp(&(
+            other.is_ipv6(),
+            to_bits(other.start),
+            to_bits(other.end),
+        ))
+    }
+}
+
+impl PartialOrd for IpRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// IP range of one of the contracts of a licensee
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseeIpRange {
+    contract_id: String,
+    contract_ip_range_id: String,
+    range: IpRange,
+}
+
+impl LicenseeIpRange {
+    pub fn new(contract_id: &str, contract_ip_range_id: &str, range: IpRange) -> Self {
+        Self {
+            contract_id: contract_id.to_string(),
+            contract_ip_range_id: contract_ip_range_id.to_string(),
+            range,
+        }
+    }
+    pub fn contract_id(&self) -> &str {
+        &self.contract_id
+    }
+    pub fn contract_ip_range_id(&self) -> &str {
+        &self.contract_ip_range_id
+    }
+    pub fn range(&self) -> &IpRange {
+        &self.range
+    }
+}
+
+/// Pair of IP ranges having addresses in common
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpRangeOverlap {
+    pub first: LicenseeIpRange,
+    pub second: LicenseeIpRange,
+}
+
+impl IpRangeOverlap {
+    /// Check whether both ranges belong to the same contract
+    pub fn is_within_contract(&self) -> bool {
+        self.first.contract_id == self.second.contract_id
+    }
+}
+
+/// Find all the pairs of overlapping ranges
+///
+/// The pairs are ordered by the first address of their first range.
+pub fn find_ip_range_overlaps(ranges: &[LicenseeIpRange]) -> Vec<IpRangeOverlap> {
+    let mut ranges = ranges.iter().collect::<Vec<_>>();
+    ranges.sort_by(|a, b| a.range.cmp(&b.range));
+    let mut overlaps = vec![];
+    for (i, first) in ranges.iter().enumerate() {
+        for second in &ranges[i + 1..] {
+            // Later ranges start even later or are IPv6 ranges, so none of them overlaps
+            if !first.range.overlaps(&second.range) {
+                break;
+            }
+            overlaps.push(IpRangeOverlap {
+                first: (*first).clone(),
+                second: (*second).clone(),
+            });
+        }
+    }
+    overlaps
+}
+
+fn address_bits(address: IpAddr) -> u32 {
+    match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn to_bits(address: IpAddr) -> u128 {
+    match address {
+        IpAddr::V4(address) => u128::from(u32::from(address)),
+        IpAddr::V6(address) => u128::from(address),
+    }
+}
+
+/// Build an address of the same family as `family` from its bits
+fn from_bits(family: IpAddr, bits: u128) -> IpAddr {
+    match family {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(bits as u32)),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().expect("OK")
+    }
+
+    #[test]
+    fn test_parse() {
+        let range = IpRange::parse("192.168.0.1/24").expect("OK");
+        assert_eq!(range.start(), ip("192.168.0.0"));
+        assert_eq!(range.end(), ip("192.168.0.255"));
+        assert_eq!(range.len(), 256);
+        assert_eq!(range.to_string(), "192.168.0.0/24");
+
+        let range = IpRange::parse("10.0.0.1 - 10.0.0.9").expect("OK");
+        assert_eq!(range.len(), 9);
+        assert_eq!(range.to_string(), "10.0.0.1-10.0.0.9");
+
+        let range = IpRange::parse("2001:db8::/32").expect("OK");
+        assert_eq!(range.end(), ip("2001:db8:ffff:ffff:ffff:ffff:ffff:ffff"));
+        assert_eq!(IpRange::parse("::/0").expect("OK").len(), u128::MAX);
+        assert_eq!(IpRange::parse("0.0.0.0/0").expect("OK").len(), 1 << 32);
+        assert_eq!(IpRange::parse("8.8.8.8").expect("OK").len(), 1);
+
+        for invalid in [
+            "",
+            "192.168.0",
+            "192.168.0.1/33",
+            "192.168.0.1/x",
+            "10.0.0.9-10.0.0.1",
+            "10.0.0.1-::1",
+            "example.com",
+        ] {
+            assert!(IpRange::parse(invalid).is_err(), "{invalid:?} is valid");
+        }
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let range = IpRange::parse("192.168.0.0/24").expect("OK");
+        assert!(range.contains(ip("192.168.0.42")));
+        assert!(!range.contains(ip("192.168.1.0")));
+        assert!(!range.contains(ip("::ffff:192.168.0.42")));
+
+        assert!(range.overlaps(&IpRange::parse("192.168.0.255-192.168.1.10").expect("OK")));
+        assert!(!range.overlaps(&IpRange::parse("192.168.1.0/24").expect("OK")));
+        assert!(!range.overlaps(&IpRange::parse("::/0").expect("OK")));
+    }
+
+    #[test]
+    fn test_find_ip_range_overlaps() {
+        let range = |contract_id: &str, id: &str, value: &str| {
+            LicenseeIpRange::new(contract_id, id, IpRange::parse(value).expect("OK"))
+        };
+        let ranges = [
+            range("C1", "R1", "10.0.0.0/24"),
+            range("C2", "R2", "10.0.1.0/24"),
+            range("C2", "R3", "10.0.0.128-10.0.1.5"),
+            range("C3", "R4", "2001:db8::/32"),
+            range("C3", "R5", "2001:db8::1"),
+        ];
+        let overlaps = find_ip_range_overlaps(&ranges)
+            .iter()
+            .map(|overlap| {
+                (
+                    overlap.first.contract_ip_range_id().to_string(),
+                    overlap.second.contract_ip_range_id().to_string(),
+                    overlap.is_within_contract(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            overlaps,
+            vec![
+                ("R1".to_string(), "R3".to_string(), false),
+                ("R3".to_string(), "R2".to_string(), true),
+                ("R4".to_string(), "R5".to_string(), true),
+            ]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::IpRange;
+
 /// Request to list contract IP ranges
 #[derive(Debug, Serialize, Default)]
 pub struct ListContractIpRangeRequest<'a> {
@@ -57,81 +59,47 @@ impl<'a> ListContractIpRangeRequest<'a> {
 }
 
 /// Request to create a contract IP range
+///
+/// The IP range is a single address, a CIDR block (`192.168.0.0/24`) or a start and an
+/// end address (`192.168.0.1-192.168.0.100`). See [`IpRange`].
 #[derive(Debug, Serialize)]
 pub struct CreateContractIpRangeRequest<'a> {
     /// The contract ID
     pub contract_id: &'a str,
-    /// The IP range start
-    pub ip_range_start: &'a str,
-    /// The IP range end
-    pub ip_range_end: &'a str,
-    /// The IP range description
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    /// The IP range
+    pub ip_range: &'a str,
 }
 
 impl<'a> CreateContractIpRangeRequest<'a> {
     /// Create a new create contract IP range request
-    pub fn new(contract_id: &'a str, ip_range_start: &'a str, ip_range_end: &'a str) -> Self {
+    pub fn new(contract_id: &'a str, ip_range: &'a str) -> Self {
         Self {
             contract_id,
-            ip_range_start,
-            ip_range_end,
-            description: None,
+            ip_range,
         }
     }
-
-    /// Set the description
-    pub fn with_description(mut self, description: &'a str) -> Self {
-        self.description = Some(description);
-        self
-    }
 }
 
 /// Request to update a contract IP range
 #[derive(Debug, Serialize)]
 pub struct UpdateContractIpRangeRequest<'a> {
+    /// The contract ID
+    pub contract_id: &'a str,
     /// The contract IP range ID
     pub contract_ip_range_id: &'a str,
-    /// The IP range start
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_range_start: Option<&'a str>,
-    /// The IP range end
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_range_end: Option<&'a str>,
-    /// The IP range description
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    /// The IP range
+    pub ip_range: &'a str,
 }
 
 impl<'a> UpdateContractIpRangeRequest<'a> {
     /// Create a new update contract IP range request
-    pub fn new(contract_ip_range_id: &'a str) -> Self {
+    pub fn new(contract_id: &'a str, contract_ip_range_id: &'a str, ip_range: &'a str) -> Self {
         Self {
+            contract_id,
             contract_ip_range_id,
-            ip_range_start: None,
-            ip_range_end: None,
-            description: None,
+            ip_range,
         }
     }
-
-    /// Set the IP range start
-    pub fn with_ip_range_start(mut self, ip_range_start: &'a str) -> Self {
-        self.ip_range_start = Some(ip_range_start);
-        self
-    }
-
-    /// Set the IP range end
-    pub fn with_ip_range_end(mut self, ip_range_end: &'a str) -> Self {
-        self.ip_range_end = Some(ip_range_end);
-        self
-    }
-
-    /// Set the description
-    pub fn with_description(mut self, description: &'a str) -> Self {
-        self.description = Some(description);
-        self
-    }
 }
 
 /// Request to remove a contract IP range
@@ -173,6 +141,16 @@ impl ContractIpRange {
     pub fn status(&self) -> &str {
         &self.status
     }
+
+    /// Check whether Piano accepted the IP range
+    pub fn is_valid(&self) -> bool {
+        self.status == "VALID"
+    }
+
+    /// Parse the IP range
+    pub fn range(&self) -> Result<IpRange, crate::Error> {
+        IpRange::parse(&self.ip_range)
+    }
 }
 
 /// Response wrapper for contract IP range operations
@@ -196,14 +174,25 @@ mod tests {
 
     #[test]
     fn test_create_contract_ip_range_request() {
-        let request =
-            CreateContractIpRangeRequest::new("contract123", "192.168.1.0", "192.168.1.255")
-                .with_description("Test IP range");
+        let request = CreateContractIpRangeRequest::new("contract123", "192.168.1.0/24");
 
         assert_eq!(request.contract_id, "contract123");
-        assert_eq!(request.ip_range_start, "192.168.1.0");
-        assert_eq!(request.ip_range_end, "192.168.1.255");
-        assert_eq!(request.description, Some("Test IP range"));
+        assert_eq!(request.ip_range, "192.168.1.0/24");
+    }
+
+    #[test]
+    fn test_contract_ip_range_range() {
+        let value = serde_json::json!({
+            "contract_ip_range_id": "CIR1",
+            "status": "VALID",
+            "ip_range": "192.168.1.0-192.168.1.255"
+        });
+        let ip_range = serde_json::from_value::<ContractIpRange>(value).expect("OK");
+        assert!(ip_range.is_valid());
+        assert_eq!(
+            ip_range.range().expect("OK"),
+            IpRange::parse("192.168.1.0/24").expect("OK")
+        );
     }
 
     #[test]
@@ -1,7 +1,7 @@
 mod schema;
 pub use schema::*;
 
-use crate::{Empty, PianoAPI, PianoPaginated, PianoResponse};
+use crate::{fetch_all_pages, Empty, PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -25,6 +25,31 @@ impl PianoAPI {
         Ok(result)
     }
 
+    /// List all the users of a contract
+    ///
+    /// Follows the pagination of [`PianoAPI::list_contract_user`] until every contract user
+    /// has been read.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The public ID of the contract
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_contract_users(
+        &self,
+        contract_id: &str,
+    ) -> Result<Vec<ContractUser>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListContractUserRequest::new(contract_id)
+                    .with_limit(100)
+                    .with_offset(offset);
+                async move { self.list_contract_user(&params).await }
+            },
+            |page| page.contract_user_list,
+        )
+        .await
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn create_contract_user<'a>(
         &self,
@@ -189,4 +214,73 @@ impl PianoAPI {
             .value()?;
         Ok(())
     }
+
+    /// Invite contract users
+    ///
+    /// Sends an invitation email to all contract users in the PENDING status, or only to
+    /// those added in a given contract user session.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The invite contract users request
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once the invitations are sent.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractUser~2Finvite) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn invite_contract_users<'a>(
+        &self,
+        req: &InviteContractUsersRequest<'a>,
+    ) -> Result<(), crate::Error> {
+        self.client
+            .post(format!(
+                "{}/publisher/licensing/contractUser/invite",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// Restore a removed contract user
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The restore contract user request
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful restoration.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractUser~2Frestore) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn restore_contract_user<'a>(
+        &self,
+        req: &RestoreContractUserRequest<'a>,
+    ) -> Result<(), crate::Error> {
+        self.client
+            .post(format!(
+                "{}/publisher/licensing/contractUser/restore",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
 }
@@ -126,6 +126,61 @@ impl<'a> RemoveAndRevokeContractUserRequest<'a> {
     }
 }
 
+/// Request to invite the pending users of a contract
+///
+/// Sends an invitation email to the contract users in the PENDING status.
+///
+/// # Reference
+///
+/// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractUser~2Finvite) for more details.
+#[derive(Debug, Serialize)]
+pub struct InviteContractUsersRequest<'a> {
+    /// The public ID of the contract
+    pub contract_id: &'a str,
+    /// Only invite the users added in this contract user session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_user_session_id: Option<&'a str>,
+}
+
+impl<'a> InviteContractUsersRequest<'a> {
+    /// Create a new invite contract users request
+    pub fn new(contract_id: &'a str) -> Self {
+        Self {
+            contract_id,
+            contract_user_session_id: None,
+        }
+    }
+
+    /// Only invite the users added in a contract user session
+    pub fn with_contract_user_session_id(mut self, contract_user_session_id: &'a str) -> Self {
+        self.contract_user_session_id = Some(contract_user_session_id);
+        self
+    }
+}
+
+/// Request to restore a removed contract user
+///
+/// # Reference
+///
+/// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FcontractUser~2Frestore) for more details.
+#[derive(Debug, Serialize)]
+pub struct RestoreContractUserRequest<'a> {
+    /// The public ID of the contract
+    pub contract_id: &'a str,
+    /// The contract user's public ID
+    pub contract_user_id: &'a str,
+}
+
+impl<'a> RestoreContractUserRequest<'a> {
+    /// Create a new restore contract user request
+    pub fn new(contract_id: &'a str, contract_user_id: &'a str) -> Self {
+        Self {
+            contract_id,
+            contract_user_id,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub(super) struct ContractUserResult {
     #[serde(alias = "ContractUser")]
@@ -171,6 +226,15 @@ impl ContractUser {
     pub fn status(&self) -> &ContractUserStatus {
         &self.status
     }
+    /// Check whether the user takes a seat of the contract
+    ///
+    /// Invited users take a seat before they redeem their access.
+    pub fn occupies_seat(&self) -> bool {
+        matches!(
+            self.status,
+            ContractUserStatus::ACTIVE | ContractUserStatus::PENDING
+        )
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -248,7 +312,24 @@ pub struct ContractUserListResult {
 mod tests {
     use crate::{PianoPaginated, PianoResponse};
 
-    use super::ContractUserListResult;
+    use super::{ContractUserListResult, InviteContractUsersRequest};
+
+    #[test]
+    fn test_invite_contract_users_request() {
+        let request = InviteContractUsersRequest::new("TMXXXXXXXXXX");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({ "contract_id": "TMXXXXXXXXXX" })
+        );
+        let request = request.with_contract_user_session_id("SESSION");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "contract_id": "TMXXXXXXXXXX",
+                "contract_user_session_id": "SESSION",
+            })
+        );
+    }
 
     #[test]
     fn sanity_check_list_deserialization() {
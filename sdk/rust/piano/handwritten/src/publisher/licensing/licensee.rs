@@ -1,7 +1,8 @@
+pub mod overview;
 mod schema;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+use crate::{fetch_all_pages, Empty, PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
 
 impl PianoAPI {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -24,6 +25,26 @@ impl PianoAPI {
             .value()?;
         Ok(result)
     }
+
+    /// List all the licensees of the application
+    ///
+    /// Follows the pagination of [`PianoAPI::list_licensees`] until every licensee has
+    /// been read.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_licensees(&self) -> Result<Vec<Licensee>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListLicenseeRequest {
+                    limit: Some(100),
+                    offset: Some(offset),
+                    ..Default::default()
+                };
+                async move { self.list_licensees(&params).await }
+            },
+            |page| page.licensees,
+        )
+        .await
+    }
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn create_licensee(
         &self,
@@ -94,4 +115,83 @@ impl PianoAPI {
             .value()?;
         Ok(result.licensee)
     }
+
+    /// Get a licensee by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some(Licensee))` if the licensee is found, `Ok(None)` if not found, or an error.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Flicensing~2Flicensee~2Fget) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_licensee(&self, licensee_id: &str) -> Result<Option<Licensee>, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/licensing/licensee/get",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .query(&GetLicenseeRequest { licensee_id })
+            .send()
+            .await?
+            .json::<PianoResponse<LicenseeResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.licensee))
+    }
+
+    /// Count the licensees of the application
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Flicensing~2Flicensee~2Fcount) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn count_licensees(&self) -> Result<i64, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/licensing/licensee/count",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<LicenseeCountResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Archive a licensee
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2Flicensee~2Farchive) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn archive_licensee(&self, licensee_id: &str) -> Result<(), crate::Error> {
+        self.client
+            .post(format!(
+                "{}/publisher/licensing/licensee/archive",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(&ArchiveLicenseeRequest { licensee_id })
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
 }
@@ -0,0 +1,310 @@
+//! Aggregated view of a licensee.
+//!
+//! [`PianoAPI::licensee_overview`] gathers the contracts of a licensee with their periods,
+//! users, email domains and IP ranges into a [`LicenseeOverview`], requesting the contracts
+//! concurrently.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::PianoAPI;
+//!
+//! # async fn example(licensee_id: &str) -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! if let Some(overview) = api.licensee_overview(licensee_id).await? {
+//!     for contract in overview.contracts() {
+//!         println!(
+//!             "{}: {}/{} seats used",
+//!             contract.contract().contract_name(),
+//!             contract.seats_used(),
+//!             contract.contract().seats_number(),
+//!         );
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures_util::{stream, StreamExt, TryStreamExt};
+
+use super::Licensee;
+use crate::{
+    publisher::licensing::{
+        contract::{Contract, ContractPeriod, PianoContractType, SchedulePeriodStatus},
+        contract_domain::ContractDomain,
+        contract_ip_range::ContractIpRange,
+        contract_user::{ContractUser, ContractUserStatus},
+    },
+    PianoAPI, CONCURRENCY,
+};
+
+/// A licensee with all of its contracts
+#[derive(Debug, Clone)]
+pub struct LicenseeOverview {
+    licensee: Licensee,
+    contracts: Vec<ContractOverview>,
+}
+
+impl LicenseeOverview {
+    pub fn licensee(&self) -> &Licensee {
+        &self.licensee
+    }
+    pub fn contracts(&self) -> &[ContractOverview] {
+        &self.contracts
+    }
+    /// Get the number of seats of the active contracts
+    pub fn seats_number(&self) -> usize {
+        self.active_contracts()
+            .map(|contract| contract.contract.seats_number())
+            .sum()
+    }
+    /// Get the number of seats used in the active contracts
+    pub fn seats_used(&self) -> usize {
+        self.active_contracts()
+            .map(ContractOverview::seats_used)
+            .sum()
+    }
+    fn active_contracts(&self) -> impl Iterator<Item = &ContractOverview> {
+        self.contracts
+            .iter()
+            .filter(|contract| contract.contract.contract_is_active())
+    }
+}
+
+/// A contract with its users, email domains and IP ranges
+///
+/// Only the entities matching the contract type are loaded: users for specific email
+/// address contracts, domains for email domain contracts and IP ranges for IP range
+/// contracts. The others are empty.
+#[derive(Debug, Clone)]
+pub struct ContractOverview {
+    contract: Contract,
+    users: Vec<ContractUser>,
+    domains: Vec<ContractDomain>,
+    ip_ranges: Vec<ContractIpRange>,
+}
+
+impl ContractOverview {
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+    pub fn periods(&self) -> &[ContractPeriod] {
+        self.contract.contract_periods()
+    }
+    /// Get the period which currently grants access, if any
+    ///
+    /// See [`ContractOverview::period_at`].
+    pub fn current_period(&self) -> Option<&ContractPeriod> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or_default();
+        self.period_at(now)
+    }
+    /// Get the period which grants access at a UNIX timestamp, if any
+    ///
+    /// Only an `ACTIVE` period whose dates contain the timestamp is returned. `ACTIVATED`
+    /// periods have not started yet.
+    pub fn period_at(&self, timestamp: i64) -> Option<&ContractPeriod> {
+        self.periods().iter().find(|period| {
+            *period.status() == SchedulePeriodStatus::Active
+                && period.begin_date() <= timestamp
+                && timestamp < period.end_date()
+        })
+    }
+    pub fn users(&self) -> &[ContractUser] {
+        &self.users
+    }
+    pub fn domains(&self) -> &[ContractDomain] {
+        &self.domains
+    }
+    pub fn ip_ranges(&self) -> &[ContractIpRange] {
+        &self.ip_ranges
+    }
+    /// Count the contract users in the given status
+    pub fn count_users(&self, status: &ContractUserStatus) -> usize {
+        self.users
+            .iter()
+            .filter(|user| std::mem::discriminant(user.status()) == std::mem::discriminant(status))
+            .count()
+    }
+    /// Get the number of seats taken by active and invited users
+    ///
+    /// See [`ContractUser::occupies_seat`]. IP range contracts do not track users, so they
+    /// always use no seat.
+    pub fn seats_used(&self) -> usize {
+        match self.contract.contract_type() {
+            PianoContractType::SPECIFIC_EMAIL_ADDRESSES_CONTRACT => self
+                .users
+                .iter()
+                .filter(|user| user.occupies_seat())
+                .count(),
+            PianoContractType::EMAIL_DOMAIN_CONTRACT => self
+                .domains
+                .iter()
+                .map(|domain| domain.active_contract_users_count)
+                .sum(),
+            PianoContractType::IP_RANGE_CONTRACT => 0,
+        }
+    }
+    /// Get the number of seats left
+    pub fn seats_available(&self) -> usize {
+        self.contract
+            .seats_number()
+            .saturating_sub(self.seats_used())
+    }
+}
+
+impl PianoAPI {
+    /// Gather a licensee with all of its contracts
+    ///
+    /// Lists the contracts of the licensee and loads the users, email domains or IP ranges
+    /// of up to eight contracts at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if the licensee is not found.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn licensee_overview(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Option<LicenseeOverview>, crate::Error> {
+        let Some(licensee) = self.get_licensee(licensee_id).await? else {
+            return Ok(None);
+        };
+        let contracts = self.list_all_contracts(licensee_id).await?;
+        let contracts = stream::iter(contracts)
+            .map(|contract| self.contract_overview(contract))
+            .buffered(CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(Some(LicenseeOverview {
+            licensee,
+            contracts,
+        }))
+    }
+
+    async fn contract_overview(
+        &self,
+        contract: Contract,
+    ) -> Result<ContractOverview, crate::Error> {
+        let mut overview = ContractOverview {
+            contract,
+            users: vec![],
+            domains: vec![],
+            ip_ranges: vec![],
+        };
+        let contract_id = overview.contract.contract_id();
+        match overview.contract.contract_type() {
+            PianoContractType::SPECIFIC_EMAIL_ADDRESSES_CONTRACT => {
+                overview.users = self.list_all_contract_users(contract_id).await?;
+            }
+            PianoContractType::EMAIL_DOMAIN_CONTRACT => {
+                overview.domains = self.list_all_contract_domains(contract_id).await?;
+            }
+            PianoContractType::IP_RANGE_CONTRACT => {
+                overview.ip_ranges = self.list_all_contract_ip_ranges(contract_id).await?;
+            }
+        }
+        Ok(overview)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn contract(contract_type: &str, seats_number: usize) -> Contract {
+        serde_json::from_value(json!({
+            "contract_id": "C1",
+            "aid": "AID",
+            "contract_type": contract_type,
+            "name": "Contract",
+            "create_date": 1700000000,
+            "licensee_id": "L1",
+            "seats_number": seats_number,
+            "is_hard_seats_limit_type": true,
+            "rid": "R1",
+            "contract_is_active": true,
+            "contract_periods": [
+                { "period_id": "P1", "name": "2023", "sell_date": 1600000000, "begin_date": 1600000000, "end_date": 1700000000, "status": "ENDED" },
+                { "period_id": "P2", "name": "2024", "sell_date": 1700000000, "begin_date": 1700000000, "end_date": 1800000000, "status": "ACTIVE" },
+                { "period_id": "P3", "name": "2025", "sell_date": 1700000000, "begin_date": 1800000000, "end_date": 1900000000, "status": "ACTIVATED" }
+            ]
+        }))
+        .expect("OK")
+    }
+
+    fn user(status: &str) -> ContractUser {
+        serde_json::from_value(json!({
+            "email": "user@example.com",
+            "first_name": null,
+            "last_name": null,
+            "contract_user_id": "CU",
+            "status": status
+        }))
+        .expect("OK")
+    }
+
+    #[test]
+    fn test_contract_overview_seats() {
+        let overview = ContractOverview {
+            contract: contract("SPECIFIC_EMAIL_ADDRESSES_CONTRACT", 3),
+            users: vec![
+                user("ACTIVE"),
+                user("PENDING"),
+                user("REVOKED"),
+                user("INVALID"),
+            ],
+            domains: vec![],
+            ip_ranges: vec![],
+        };
+        assert_eq!(overview.seats_used(), 2);
+        assert_eq!(overview.seats_available(), 1);
+        assert_eq!(overview.count_users(&ContractUserStatus::PENDING), 1);
+        assert_eq!(
+            overview
+                .period_at(1750000000)
+                .map(ContractPeriod::period_id),
+            Some("P2")
+        );
+        // The next period is activated but has not started yet
+        assert_eq!(
+            overview
+                .period_at(1850000000)
+                .map(ContractPeriod::period_id),
+            None
+        );
+        assert_eq!(
+            overview
+                .period_at(1650000000)
+                .map(ContractPeriod::period_id),
+            None
+        );
+
+        let domain = ContractDomain {
+            contract_domain_id: "D1".to_string(),
+            contract_domain_value: "example.com".to_string(),
+            status: "ACTIVE".to_string(),
+            contract_users_count: 5,
+            active_contract_users_count: 4,
+        };
+        let overview = ContractOverview {
+            contract: contract("EMAIL_DOMAIN_CONTRACT", 3),
+            users: vec![],
+            domains: vec![domain],
+            ip_ranges: vec![],
+        };
+        assert_eq!(overview.seats_used(), 4);
+        assert_eq!(overview.seats_available(), 0);
+    }
+}
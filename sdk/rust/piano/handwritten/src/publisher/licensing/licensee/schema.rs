@@ -51,10 +51,33 @@ pub struct ListLicenseeResult {
     pub licensees: Vec<Licensee>,
 }
 
+#[derive(Debug, Serialize)]
+pub(super) struct GetLicenseeRequest<'a> {
+    pub licensee_id: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ArchiveLicenseeRequest<'a> {
+    pub licensee_id: &'a str,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct LicenseeCountResult {
+    pub data: i64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct Licensee {
     licensee_id: String,
     name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    logo_url: Option<String>,
+    #[serde(default)]
+    representatives: Vec<LicenseeRepresentative>,
+    #[serde(default)]
+    managers: Vec<LicenseeManager>,
 }
 
 impl Licensee {
@@ -64,6 +87,59 @@ impl Licensee {
     pub fn name(&self) -> &str {
         &self.name
     }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    /// Get the relative URL of the licensee's logo
+    pub fn logo_url(&self) -> Option<&str> {
+        self.logo_url.as_deref()
+    }
+    pub fn representatives(&self) -> &[LicenseeRepresentative] {
+        &self.representatives
+    }
+    pub fn managers(&self) -> &[LicenseeManager] {
+        &self.managers
+    }
+}
+
+/// Licensee contact with permissions to manage the licensee's contracts
+#[derive(Debug, Deserialize, Clone)]
+pub struct LicenseeRepresentative {
+    email: String,
+}
+
+impl LicenseeRepresentative {
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
+
+/// Team member managing the relationship with a licensee
+#[derive(Debug, Deserialize, Clone)]
+pub struct LicenseeManager {
+    uid: String,
+    #[serde(default)]
+    first_name: Option<String>,
+    #[serde(default)]
+    last_name: Option<String>,
+    #[serde(default)]
+    personal_name: Option<String>,
+}
+
+impl LicenseeManager {
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+    pub fn first_name(&self) -> Option<&str> {
+        self.first_name.as_deref()
+    }
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
+    }
+    /// Get the name and surname ordered as per locale
+    pub fn personal_name(&self) -> Option<&str> {
+        self.personal_name.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +176,10 @@ mod tests {
         match response {
             PianoResponse::Succeed(data) => {
                 assert_eq!(data.value.licensees.len(), 1);
+                let licensee = &data.value.licensees[0];
+                assert_eq!(licensee.representatives().len(), 3);
+                assert_eq!(licensee.managers().len(), 1);
+                assert_eq!(licensee.logo_url(), None);
             }
             PianoResponse::Failure { code, message, .. } => {
                 panic!("Expected success but got failure: {} - {}", code, message);
@@ -0,0 +1,205 @@
+//! Notification rules decide which licensing events show up as notifications in the
+//! Piano dashboard. A rule has a trigger (e.g. more than 90% of the seats are used, or the
+//! access period ends in 30 days) and applies to all or some contracts of a licensee.
+//!
+//! Piano composes the notification messages itself and does not send them to anyone, so
+//! rules have neither recipients nor message templates. Read the notifications with
+//! [`PianoAPI::list_notifications`].
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{publisher::licensing::notification_rule::*, PianoAPI};
+//!
+//! # async fn example(licensee_id: &str) -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! // Make sure every licensee has the standard alert rules
+//! let created = api
+//!     .provision_notification_rules(
+//!         licensee_id,
+//!         &[
+//!             NotificationTrigger::seats_used_percentage_exceeds(90),
+//!             NotificationTrigger::days_before_period_end(30),
+//!         ],
+//!     )
+//!     .await?;
+//! println!("created {} rules", created.len());
+//! # Ok(())
+//! # }
+//! ```
+mod schema;
+pub use self::schema::*;
+
+use crate::{Empty, PianoAPI, PianoResponse};
+
+impl PianoAPI {
+    /// Create a notification rule for a licensee
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The create notification rule request
+    ///
+    /// # Returns
+    ///
+    /// Returns the created notification rule.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Fcreate) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_notification_rule(
+        &self,
+        req: &CreateNotificationRuleRequest<'_>,
+    ) -> Result<NotificationRule, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/licensing/notificationRule/create",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<NotificationRuleResult>>()
+            .await?
+            .value()?;
+        Ok(result.notification_rule)
+    }
+
+    /// List the notification rules of a licensee
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    ///
+    /// # Returns
+    ///
+    /// Returns all the active notification rules of the licensee.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Flist) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_notification_rules(
+        &self,
+        licensee_id: &str,
+    ) -> Result<Vec<NotificationRule>, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/licensing/notificationRule/list",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .query(&ListNotificationRuleRequest { licensee_id })
+            .send()
+            .await?
+            .json::<PianoResponse<NotificationRuleListResult>>()
+            .await?
+            .value()?;
+        Ok(result.notification_rules)
+    }
+
+    /// Update a notification rule of a licensee
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The update notification rule request
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated notification rule.
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Fupdate) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_notification_rule(
+        &self,
+        req: &UpdateNotificationRuleRequest<'_>,
+    ) -> Result<NotificationRule, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/licensing/notificationRule/update",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<NotificationRuleResult>>()
+            .await?
+            .value()?;
+        Ok(result.notification_rule)
+    }
+
+    /// Archive a notification rule of a licensee
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The archive notification rule request
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Farchive) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn archive_notification_rule(
+        &self,
+        req: &ArchiveNotificationRuleRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        self.client
+            .post(format!(
+                "{}/publisher/licensing/notificationRule/archive",
+                self.endpoint,
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// Create the notification rules a licensee is missing
+    ///
+    /// Every trigger is created as a rule applying to all the contracts of the licensee,
+    /// unless the licensee already has such a rule. Running it again for the same licensee
+    /// creates nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `licensee_id` - The public ID of the licensee
+    /// * `triggers` - The triggers of the rules every licensee should have
+    ///
+    /// # Returns
+    ///
+    /// Returns the created notification rules.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn provision_notification_rules(
+        &self,
+        licensee_id: &str,
+        triggers: &[NotificationTrigger],
+    ) -> Result<Vec<NotificationRule>, crate::Error> {
+        let mut existing = self
+            .list_notification_rules(licensee_id)
+            .await?
+            .into_iter()
+            .filter(NotificationRule::is_for_all_contracts)
+            .map(|rule| rule.trigger())
+            .collect::<std::collections::HashSet<_>>();
+        let mut created = vec![];
+        for trigger in triggers {
+            if !existing.insert(*trigger) {
+                continue;
+            }
+            let req = CreateNotificationRuleRequest::new(licensee_id, *trigger);
+            created.push(self.create_notification_rule(&req).await?);
+        }
+        Ok(created)
+    }
+}
@@ -0,0 +1,401 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Value a notification rule watches
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotificationRuleParameter {
+    /// The share of the contract seats which are occupied, in percent
+    OccupiedSeatsNumber,
+    /// The end of the current access period of the contract
+    PeriodOfAccess,
+    /// The date of sale of the contract
+    DateOfSale,
+}
+
+/// Condition on the watched value which triggers a notification
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotificationRuleCondition {
+    Exceeds,
+    DropsBelow,
+    ReachesCapacity,
+    WillExpireIn,
+    HasExpired,
+    Expires,
+    WillOccurIn,
+    HasOccurred,
+    Occurs,
+}
+
+/// Trigger of a notification rule
+///
+/// Combines the watched parameter, the condition and the value of the condition
+/// (a percentage of seats or a number of days).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotificationTrigger {
+    /// The watched value
+    pub parameter: NotificationRuleParameter,
+    /// The condition on the watched value
+    pub condition: NotificationRuleCondition,
+    /// The value of the condition
+    pub condition_value: Option<i64>,
+}
+
+impl NotificationTrigger {
+    /// Create a trigger without condition value
+    pub fn new(parameter: NotificationRuleParameter, condition: NotificationRuleCondition) -> Self {
+        Self {
+            parameter,
+            condition,
+            condition_value: None,
+        }
+    }
+
+    /// Set the value of the condition
+    pub fn with_condition_value(mut self, condition_value: i64) -> Self {
+        self.condition_value = Some(condition_value);
+        self
+    }
+
+    /// Notify when more than `percentage` percent of the seats are used
+    pub fn seats_used_percentage_exceeds(percentage: i64) -> Self {
+        Self::new(
+            NotificationRuleParameter::OccupiedSeatsNumber,
+            NotificationRuleCondition::Exceeds,
+        )
+        .with_condition_value(percentage)
+    }
+
+    /// Notify when less than `percentage` percent of the seats are used
+    pub fn seats_used_percentage_drops_below(percentage: i64) -> Self {
+        Self::new(
+            NotificationRuleParameter::OccupiedSeatsNumber,
+            NotificationRuleCondition::DropsBelow,
+        )
+        .with_condition_value(percentage)
+    }
+
+    /// Notify when all the seats are used
+    pub fn seats_reach_capacity() -> Self {
+        Self::new(
+            NotificationRuleParameter::OccupiedSeatsNumber,
+            NotificationRuleCondition::ReachesCapacity,
+        )
+    }
+
+    /// Notify `days` days before the end of the access period
+    pub fn days_before_period_end(days: i64) -> Self {
+        Self::new(
+            NotificationRuleParameter::PeriodOfAccess,
+            NotificationRuleCondition::WillExpireIn,
+        )
+        .with_condition_value(days)
+    }
+
+    /// Notify on the day the access period ends
+    pub fn period_end() -> Self {
+        Self::new(
+            NotificationRuleParameter::PeriodOfAccess,
+            NotificationRuleCondition::Expires,
+        )
+    }
+
+    /// Notify `days` days before the date of sale
+    pub fn days_before_date_of_sale(days: i64) -> Self {
+        Self::new(
+            NotificationRuleParameter::DateOfSale,
+            NotificationRuleCondition::WillOccurIn,
+        )
+        .with_condition_value(days)
+    }
+}
+
+/// Request to create a notification rule for a licensee
+///
+/// The rule applies to all the contracts of the licensee unless contracts are set
+/// with [`CreateNotificationRuleRequest::with_contract_ids`].
+///
+/// # Reference
+///
+/// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Fcreate) for more details.
+#[derive(Debug, Serialize)]
+pub struct CreateNotificationRuleRequest<'a> {
+    /// The public ID of the licensee
+    pub licensee_id: &'a str,
+    /// The public IDs of the contracts the rule applies to
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_json_str_slice"
+    )]
+    pub contract_id_list: Option<&'a [&'a str]>,
+    /// Whether the rule applies to all the contracts of the licensee
+    pub is_for_all_contracts: bool,
+    /// The watched value
+    pub parameter: NotificationRuleParameter,
+    /// The condition on the watched value
+    pub condition: NotificationRuleCondition,
+    /// The value of the condition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_value: Option<i64>,
+}
+
+impl<'a> CreateNotificationRuleRequest<'a> {
+    /// Create a request for a rule applying to all the contracts of the licensee
+    pub fn new(licensee_id: &'a str, trigger: NotificationTrigger) -> Self {
+        Self {
+            licensee_id,
+            contract_id_list: None,
+            is_for_all_contracts: true,
+            parameter: trigger.parameter,
+            condition: trigger.condition,
+            condition_value: trigger.condition_value,
+        }
+    }
+
+    /// Restrict the rule to the given contracts
+    pub fn with_contract_ids(mut self, contract_ids: &'a [&'a str]) -> Self {
+        self.contract_id_list = Some(contract_ids);
+        self.is_for_all_contracts = false;
+        self
+    }
+}
+
+/// Request to update a notification rule of a licensee
+///
+/// # Reference
+///
+/// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Fupdate) for more details.
+#[derive(Debug, Serialize)]
+pub struct UpdateNotificationRuleRequest<'a> {
+    /// The public ID of the licensee
+    pub licensee_id: &'a str,
+    /// The notification rule ID
+    pub notification_rule_id: &'a str,
+    /// The public IDs of the contracts the rule applies to
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_json_str_slice"
+    )]
+    pub contract_id_list: Option<&'a [&'a str]>,
+    /// Whether the rule applies to all the contracts of the licensee
+    pub is_for_all_contracts: bool,
+    /// The watched value
+    pub parameter: NotificationRuleParameter,
+    /// The condition on the watched value
+    pub condition: NotificationRuleCondition,
+    /// The value of the condition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_value: Option<i64>,
+}
+
+impl<'a> UpdateNotificationRuleRequest<'a> {
+    /// Create a request replacing the rule with one applying to all the contracts of the licensee
+    pub fn new(
+        licensee_id: &'a str,
+        notification_rule_id: &'a str,
+        trigger: NotificationTrigger,
+    ) -> Self {
+        Self {
+            licensee_id,
+            notification_rule_id,
+            contract_id_list: None,
+            is_for_all_contracts: true,
+            parameter: trigger.parameter,
+            condition: trigger.condition,
+            condition_value: trigger.condition_value,
+        }
+    }
+
+    /// Restrict the rule to the given contracts
+    pub fn with_contract_ids(mut self, contract_ids: &'a [&'a str]) -> Self {
+        self.contract_id_list = Some(contract_ids);
+        self.is_for_all_contracts = false;
+        self
+    }
+}
+
+/// Request to archive a notification rule of a licensee
+///
+/// # Reference
+///
+/// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Flicensing~2FnotificationRule~2Farchive) for more details.
+#[derive(Debug, Serialize)]
+pub struct ArchiveNotificationRuleRequest<'a> {
+    /// The public ID of the licensee
+    pub licensee_id: &'a str,
+    /// The notification rule ID
+    pub notification_rule_id: &'a str,
+}
+
+impl<'a> ArchiveNotificationRuleRequest<'a> {
+    /// Create a new archive notification rule request
+    pub fn new(licensee_id: &'a str, notification_rule_id: &'a str) -> Self {
+        Self {
+            licensee_id,
+            notification_rule_id,
+        }
+    }
+}
+
+/// Request to list the notification rules of a licensee
+#[derive(Debug, Serialize)]
+pub(super) struct ListNotificationRuleRequest<'a> {
+    pub licensee_id: &'a str,
+}
+
+/// Notification rule of a licensee
+///
+/// Piano shows a notification in the dashboard when the trigger of the rule is met
+/// for one of the contracts the rule applies to.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct NotificationRule {
+    notification_rule_id: String,
+    licensee_id: String,
+    #[serde(default)]
+    contract_id_list: Vec<String>,
+    parameter: NotificationRuleParameter,
+    condition: NotificationRuleCondition,
+    #[serde(default)]
+    condition_value: Option<i64>,
+    is_for_all_contracts: bool,
+}
+
+impl NotificationRule {
+    /// Get the notification rule ID
+    pub fn notification_rule_id(&self) -> &str {
+        &self.notification_rule_id
+    }
+
+    /// Get the public ID of the licensee
+    pub fn licensee_id(&self) -> &str {
+        &self.licensee_id
+    }
+
+    /// Get the public IDs of the contracts the rule applies to
+    pub fn contract_ids(&self) -> &[String] {
+        &self.contract_id_list
+    }
+
+    /// Check whether the rule applies to all the contracts of the licensee
+    pub fn is_for_all_contracts(&self) -> bool {
+        self.is_for_all_contracts
+    }
+
+    /// Check whether the rule applies to the given contract
+    pub fn applies_to(&self, contract_id: &str) -> bool {
+        self.is_for_all_contracts || self.contract_id_list.iter().any(|id| id == contract_id)
+    }
+
+    /// Get the watched value
+    pub fn parameter(&self) -> NotificationRuleParameter {
+        self.parameter
+    }
+
+    /// Get the condition on the watched value
+    pub fn condition(&self) -> NotificationRuleCondition {
+        self.condition
+    }
+
+    /// Get the value of the condition
+    pub fn condition_value(&self) -> Option<i64> {
+        self.condition_value
+    }
+
+    /// Get the trigger of the rule
+    pub fn trigger(&self) -> NotificationTrigger {
+        NotificationTrigger {
+            parameter: self.parameter,
+            condition: self.condition,
+            condition_value: self.condition_value,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct NotificationRuleResult {
+    #[serde(rename = "LicenseeNotificationRule")]
+    pub notification_rule: NotificationRule,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct NotificationRuleListResult {
+    #[serde(rename = "LicenseeNotificationRule")]
+    pub notification_rules: Vec<NotificationRule>,
+}
+
+/// Serialize a slice of strings as the JSON array Piano expects in a form parameter
+fn serialize_json_str_slice<S>(slice: &Option<&[&str]>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match slice {
+        Some(slice) => serializer
+            .serialize_str(&serde_json::to_string(slice).map_err(serde::ser::Error::custom)?),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_create_notification_rule_request() {
+        let request = CreateNotificationRuleRequest::new(
+            "LICENSEE",
+            NotificationTrigger::seats_used_percentage_exceeds(90),
+        );
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "licensee_id": "LICENSEE",
+                "is_for_all_contracts": true,
+                "parameter": "OCCUPIED_SEATS_NUMBER",
+                "condition": "EXCEEDS",
+                "condition_value": 90,
+            })
+        );
+
+        let contract_ids = ["C1", "C2"];
+        let request =
+            CreateNotificationRuleRequest::new("LICENSEE", NotificationTrigger::period_end())
+                .with_contract_ids(&contract_ids);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "licensee_id": "LICENSEE",
+                "contract_id_list": r#"["C1","C2"]"#,
+                "is_for_all_contracts": false,
+                "parameter": "PERIOD_OF_ACCESS",
+                "condition": "EXPIRES",
+            })
+        );
+    }
+
+    #[test]
+    fn test_notification_rule_deserialization() {
+        let value = json!({
+            "LicenseeNotificationRule": {
+                "notification_rule_id": "NR1",
+                "licensee_id": "LICENSEE",
+                "contract_id_list": ["C1"],
+                "parameter": "PERIOD_OF_ACCESS",
+                "condition": "WILL_EXPIRE_IN",
+                "condition_value": 30,
+                "is_for_all_contracts": false
+            }
+        });
+        let rule = serde_json::from_value::<NotificationRuleResult>(value)
+            .expect("OK")
+            .notification_rule;
+        assert_eq!(rule.notification_rule_id(), "NR1");
+        assert_eq!(
+            rule.trigger(),
+            NotificationTrigger::days_before_period_end(30)
+        );
+        assert!(rule.applies_to("C1"));
+        assert!(!rule.applies_to("C2"));
+    }
+}
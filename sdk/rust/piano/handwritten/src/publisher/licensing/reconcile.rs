@@ -0,0 +1,768 @@
+//! Reconciliation of the users of a specific email address contract with a roster.
+//!
+//! A [`ReconcilePlan`] lists the calls needed to make the users of a contract match the
+//! desired roster: users to add, to restore, to rename and to remove. The plan respects the
+//! seats of the contract: with a hard seats limit, the users which do not fit are skipped
+//! instead of being added.
+//!
+//! Plans can be printed or serialized for a dry run, then applied with
+//! [`PianoAPI::apply_reconcile_plan`], which reports the outcome for every user.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{publisher::licensing::reconcile::*, PianoAPI, SyncOptions};
+//!
+//! # async fn example(contract_id: &str) -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! let roster = vec![
+//!     DesiredContractUser::new("jane@example.com").with_name("Jane", "Doe"),
+//!     DesiredContractUser::new("john@example.com"),
+//! ];
+//! let options = SyncOptions::new().with_dry_run(true);
+//! let report = api
+//!     .reconcile_contract_users(contract_id, &roster, &options)
+//!     .await?;
+//! for result in report.results() {
+//!     println!("{} {:?} {:?}", result.email(), result.action(), result.outcome());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::HashMap, fmt, io::ErrorKind};
+
+use futures_util::{stream, StreamExt};
+use serde::Serialize;
+
+use super::{
+    contract::Contract,
+    contract_user::{
+        ContractUser, ContractUserStatus, CreateContractUserRequest,
+        RemoveAndRevokeContractUserRequest, RestoreContractUserRequest, UpdateContractUserRequest,
+    },
+};
+use crate::{PianoAPI, SyncOptions};
+
+/// User expected in a contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DesiredContractUser {
+    email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+}
+
+impl DesiredContractUser {
+    /// Create a user without name. The names of an existing user are left unchanged.
+    pub fn new(email: &str) -> Self {
+        Self {
+            email: email.trim().to_string(),
+            first_name: None,
+            last_name: None,
+        }
+    }
+
+    /// Set the first and last name of the user
+    pub fn with_name(mut self, first_name: &str, last_name: &str) -> Self {
+        self.first_name = Some(first_name.to_string());
+        self.last_name = Some(last_name.to_string());
+        self
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+    pub fn first_name(&self) -> Option<&str> {
+        self.first_name.as_deref()
+    }
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
+    }
+}
+
+/// Change to a contract user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ReconcileAction {
+    /// Invite a user who is not in the contract
+    Add(DesiredContractUser),
+    /// Restore a revoked user
+    Restore {
+        contract_user_id: String,
+        email: String,
+    },
+    /// Update the names of a user
+    Update {
+        contract_user_id: String,
+        #[serde(flatten)]
+        user: DesiredContractUser,
+    },
+    /// Remove a user missing from the roster and revoke their access
+    Remove {
+        contract_user_id: String,
+        email: String,
+    },
+}
+
+impl ReconcileAction {
+    /// Get the email address of the user
+    pub fn email(&self) -> &str {
+        match self {
+            ReconcileAction::Add(user) | ReconcileAction::Update { user, .. } => user.email(),
+            ReconcileAction::Restore { email, .. } | ReconcileAction::Remove { email, .. } => email,
+        }
+    }
+
+    /// Get the kind of change
+    pub fn kind(&self) -> ReconcileActionKind {
+        match self {
+            ReconcileAction::Add(_) => ReconcileActionKind::Add,
+            ReconcileAction::Restore { .. } => ReconcileActionKind::Restore,
+            ReconcileAction::Update { .. } => ReconcileActionKind::Update,
+            ReconcileAction::Remove { .. } => ReconcileActionKind::Remove,
+        }
+    }
+}
+
+impl fmt::Display for ReconcileAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.kind() {
+            ReconcileActionKind::Add => '+',
+            ReconcileActionKind::Restore => '^',
+            ReconcileActionKind::Update => '~',
+            ReconcileActionKind::Remove => '-',
+            ReconcileActionKind::Skip => '!',
+        };
+        write!(f, "{sign} {}", self.email())?;
+        if let ReconcileAction::Add(user) | ReconcileAction::Update { user, .. } = self {
+            if let (Some(first_name), Some(last_name)) = (user.first_name(), user.last_name()) {
+                write!(f, " ({first_name} {last_name})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Kind of change to a contract user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconcileActionKind {
+    Add,
+    Restore,
+    Update,
+    Remove,
+    Skip,
+}
+
+/// Reason why a user of the roster is not added
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// The contract has a hard seats limit and no seat is left
+    SeatLimit,
+    /// The email address is not valid
+    InvalidEmail,
+}
+
+/// User of the roster which is not added
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedContractUser {
+    pub email: String,
+    pub reason: SkipReason,
+}
+
+/// Changes making the users of a contract match a roster
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconcilePlan {
+    contract_id: String,
+    seats_number: usize,
+    is_hard_seats_limit_type: bool,
+    seats_used: usize,
+    actions: Vec<ReconcileAction>,
+    skipped: Vec<SkippedContractUser>,
+}
+
+impl ReconcilePlan {
+    /// Compute the changes making the users of a contract match the roster
+    ///
+    /// Email addresses are compared case-insensitively and the last entry wins when the
+    /// roster contains an address twice. When the contract has several users with the same
+    /// address, the active or pending one is compared with the roster. Active and pending
+    /// users take a seat; invalid users of the roster are skipped since Piano rejected
+    /// their address. Only active and pending users missing from the roster are removed,
+    /// so that applying the plan again has nothing left to do.
+    pub fn new(
+        contract: &Contract,
+        existing: &[ContractUser],
+        desired: &[DesiredContractUser],
+        options: &SyncOptions,
+    ) -> Self {
+        let mut roster: Vec<&DesiredContractUser> = vec![];
+        let mut positions = HashMap::new();
+        for user in desired {
+            let key = user.email.to_lowercase();
+            match positions.get(&key) {
+                Some(&position) => roster[position] = user,
+                None => {
+                    positions.insert(key, roster.len());
+                    roster.push(user);
+                }
+            }
+        }
+        let mut existing_by_email: HashMap<String, &ContractUser> = HashMap::new();
+        for user in existing {
+            existing_by_email
+                .entry(user.email().to_lowercase())
+                .and_modify(|kept| {
+                    if !kept.occupies_seat() && user.occupies_seat() {
+                        *kept = user;
+                    }
+                })
+                .or_insert(user);
+        }
+
+        let mut removals = vec![];
+        let mut seats_used = 0;
+        // Revoked and invalid users take no seat and have nothing left to remove
+        for user in existing.iter().filter(|user| user.occupies_seat()) {
+            if options.remove_missing && !positions.contains_key(&user.email().to_lowercase()) {
+                removals.push(ReconcileAction::Remove {
+                    contract_user_id: user.contract_user_id().to_string(),
+                    email: user.email().to_string(),
+                });
+            } else {
+                seats_used += 1;
+            }
+        }
+
+        let mut plan = ReconcilePlan {
+            contract_id: contract.contract_id().to_string(),
+            seats_number: contract.seats_number(),
+            is_hard_seats_limit_type: contract.is_hard_seats_limit_type(),
+            seats_used,
+            actions: removals,
+            skipped: vec![],
+        };
+        let mut updates = vec![];
+        let mut additions = vec![];
+        for desired in roster {
+            let existing = existing_by_email.get(&desired.email.to_lowercase());
+            if let Some(existing) = existing {
+                let is_valid = !matches!(existing.status(), ContractUserStatus::INVALID);
+                if is_valid && names_differ(existing, desired) {
+                    updates.push(ReconcileAction::Update {
+                        contract_user_id: existing.contract_user_id().to_string(),
+                        user: desired.clone(),
+                    });
+                }
+            }
+            match existing.map(|user| user.status()) {
+                Some(ContractUserStatus::ACTIVE | ContractUserStatus::PENDING) => {}
+                Some(ContractUserStatus::INVALID) => plan.skip(desired, SkipReason::InvalidEmail),
+                Some(ContractUserStatus::REVOKED) if plan.take_seat() => {
+                    plan.actions.push(ReconcileAction::Restore {
+                        contract_user_id: existing
+                            .map(|user| user.contract_user_id().to_string())
+                            .unwrap_or_default(),
+                        email: desired.email.clone(),
+                    });
+                }
+                None if !is_valid_email(&desired.email) => {
+                    plan.skip(desired, SkipReason::InvalidEmail)
+                }
+                None if plan.take_seat() => additions.push(ReconcileAction::Add(desired.clone())),
+                Some(ContractUserStatus::REVOKED) | None => {
+                    plan.skip(desired, SkipReason::SeatLimit)
+                }
+            }
+        }
+        plan.actions.extend(updates);
+        plan.actions.extend(additions);
+        plan
+    }
+
+    /// Take a seat for a user, unless no seat is left with a hard seats limit
+    fn take_seat(&mut self) -> bool {
+        if self.is_hard_seats_limit_type && self.seats_used >= self.seats_number {
+            return false;
+        }
+        self.seats_used += 1;
+        true
+    }
+
+    fn skip(&mut self, user: &DesiredContractUser, reason: SkipReason) {
+        self.skipped.push(SkippedContractUser {
+            email: user.email.clone(),
+            reason,
+        });
+    }
+
+    pub fn contract_id(&self) -> &str {
+        &self.contract_id
+    }
+    /// Get the changes, removals first so that their seats are freed before additions
+    pub fn actions(&self) -> &[ReconcileAction] {
+        &self.actions
+    }
+    pub fn skipped(&self) -> &[SkippedContractUser] {
+        &self.skipped
+    }
+    /// Get the number of seats taken once the plan is applied
+    pub fn seats_used(&self) -> usize {
+        self.seats_used
+    }
+    /// Check whether more seats than the contract has are taken once the plan is applied
+    ///
+    /// This only happens with a soft seats limit.
+    pub fn exceeds_seats(&self) -> bool {
+        self.seats_used > self.seats_number
+    }
+    /// Check whether the plan has nothing to do
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty() && self.skipped.is_empty()
+    }
+    /// Count the changes of the given kind
+    pub fn count(&self, kind: ReconcileActionKind) -> usize {
+        match kind {
+            ReconcileActionKind::Skip => self.skipped.len(),
+            kind => self
+                .actions
+                .iter()
+                .filter(|action| action.kind() == kind)
+                .count(),
+        }
+    }
+}
+
+impl fmt::Display for ReconcilePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "contract {}: {}/{} seats used{}",
+            self.contract_id,
+            self.seats_used,
+            self.seats_number,
+            if self.is_hard_seats_limit_type {
+                " (hard limit)"
+            } else {
+                ""
+            }
+        )?;
+        for action in &self.actions {
+            writeln!(f, "{action}")?;
+        }
+        for skipped in &self.skipped {
+            writeln!(f, "! {} ({:?})", skipped.email, skipped.reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of a change to a contract user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ReconcileOutcome {
+    /// The change was not applied because of a dry run
+    Planned,
+    /// The change was applied
+    Applied,
+    /// The user was not added
+    Skipped { reason: SkipReason },
+    /// The call failed
+    Failed { error: String },
+}
+
+/// Outcome of the reconciliation of a user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReconcileResult {
+    email: String,
+    action: ReconcileActionKind,
+    #[serde(flatten)]
+    outcome: ReconcileOutcome,
+}
+
+impl ReconcileResult {
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+    pub fn action(&self) -> ReconcileActionKind {
+        self.action
+    }
+    pub fn outcome(&self) -> &ReconcileOutcome {
+        &self.outcome
+    }
+}
+
+/// Outcome of a reconciliation, with one result per change
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconcileReport {
+    contract_id: String,
+    dry_run: bool,
+    results: Vec<ReconcileResult>,
+}
+
+impl ReconcileReport {
+    /// Create the report of a dry run, where every change is planned
+    pub fn dry_run(plan: &ReconcilePlan) -> Self {
+        let results = plan
+            .actions
+            .iter()
+            .map(|action| ReconcileResult {
+                email: action.email().to_string(),
+                action: action.kind(),
+                outcome: ReconcileOutcome::Planned,
+            })
+            .collect();
+        Self::new(plan, true, results)
+    }
+
+    fn new(plan: &ReconcilePlan, dry_run: bool, mut results: Vec<ReconcileResult>) -> Self {
+        results.extend(plan.skipped.iter().map(|skipped| ReconcileResult {
+            email: skipped.email.clone(),
+            action: ReconcileActionKind::Skip,
+            outcome: ReconcileOutcome::Skipped {
+                reason: skipped.reason,
+            },
+        }));
+        Self {
+            contract_id: plan.contract_id.clone(),
+            dry_run,
+            results,
+        }
+    }
+
+    pub fn contract_id(&self) -> &str {
+        &self.contract_id
+    }
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+    pub fn results(&self) -> &[ReconcileResult] {
+        &self.results
+    }
+    /// Get the results of the failed calls
+    pub fn failures(&self) -> impl Iterator<Item = &ReconcileResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.outcome, ReconcileOutcome::Failed { .. }))
+    }
+}
+
+impl PianoAPI {
+    /// Compute the changes making the users of a contract match a roster
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The public ID of the contract
+    /// * `desired` - The users expected in the contract
+    /// * `options` - The reconciliation options
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`ErrorKind::NotFound`] error if the contract does not exist.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, desired)))]
+    pub async fn plan_contract_user_reconciliation(
+        &self,
+        contract_id: &str,
+        desired: &[DesiredContractUser],
+        options: &SyncOptions,
+    ) -> Result<ReconcilePlan, crate::Error> {
+        let (contract, existing) = futures_util::future::try_join(
+            self.get_contract(contract_id),
+            self.list_all_contract_users(contract_id),
+        )
+        .await?;
+        let contract = contract.ok_or_else(|| {
+            Box::new(std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("contract {contract_id} not found"),
+            ))
+        })?;
+        Ok(ReconcilePlan::new(&contract, &existing, desired, options))
+    }
+
+    /// Apply the changes of a plan
+    ///
+    /// Removals are applied first so that their seats are freed, then restorations, updates
+    /// and additions. Up to `concurrency` calls are made at the same time. A failed call does
+    /// not stop the others and is reported in the result of its user.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, plan)))]
+    pub async fn apply_reconcile_plan(
+        &self,
+        plan: &ReconcilePlan,
+        concurrency: usize,
+    ) -> ReconcileReport {
+        let concurrency = concurrency.max(1);
+        let (removals, others): (Vec<_>, Vec<_>) = plan
+            .actions
+            .iter()
+            .partition(|action| action.kind() == ReconcileActionKind::Remove);
+        let mut results = vec![];
+        for actions in [removals, others] {
+            let outcomes = stream::iter(actions)
+                .map(|action| async move {
+                    let outcome = match self.apply_reconcile_action(&plan.contract_id, action).await
+                    {
+                        Ok(()) => ReconcileOutcome::Applied,
+                        Err(error) => ReconcileOutcome::Failed {
+                            error: error.to_string(),
+                        },
+                    };
+                    ReconcileResult {
+                        email: action.email().to_string(),
+                        action: action.kind(),
+                        outcome,
+                    }
+                })
+                .buffered(concurrency)
+                .collect::<Vec<_>>()
+                .await;
+            results.extend(outcomes);
+        }
+        ReconcileReport::new(plan, false, results)
+    }
+
+    /// Make the users of a contract match a roster
+    ///
+    /// Computes the plan and applies it, unless the options ask for a dry run.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The public ID of the contract
+    /// * `desired` - The users expected in the contract
+    /// * `options` - The reconciliation options
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, desired)))]
+    pub async fn reconcile_contract_users(
+        &self,
+        contract_id: &str,
+        desired: &[DesiredContractUser],
+        options: &SyncOptions,
+    ) -> Result<ReconcileReport, crate::Error> {
+        let plan = self
+            .plan_contract_user_reconciliation(contract_id, desired, options)
+            .await?;
+        if options.dry_run {
+            return Ok(ReconcileReport::dry_run(&plan));
+        }
+        Ok(self.apply_reconcile_plan(&plan, options.concurrency).await)
+    }
+
+    async fn apply_reconcile_action(
+        &self,
+        contract_id: &str,
+        action: &ReconcileAction,
+    ) -> Result<(), crate::Error> {
+        match action {
+            ReconcileAction::Add(user) => {
+                let req = CreateContractUserRequest {
+                    contract_id,
+                    email: &user.email,
+                    first_name: user.first_name(),
+                    last_name: user.last_name(),
+                };
+                self.create_contract_user(&req).await?;
+            }
+            ReconcileAction::Restore {
+                contract_user_id, ..
+            } => {
+                let req = RestoreContractUserRequest::new(contract_id, contract_user_id);
+                self.restore_contract_user(&req).await?;
+            }
+            ReconcileAction::Update {
+                contract_user_id,
+                user,
+            } => {
+                let mut req =
+                    UpdateContractUserRequest::new(contract_id, contract_user_id, &user.email);
+                req.first_name = user.first_name();
+                req.last_name = user.last_name();
+                self.update_contract_user(&req).await?;
+            }
+            ReconcileAction::Remove {
+                contract_user_id, ..
+            } => {
+                let req = RemoveAndRevokeContractUserRequest::new(contract_id, contract_user_id);
+                self.remove_and_revoke_contract_user(&req).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn names_differ(existing: &ContractUser, desired: &DesiredContractUser) -> bool {
+    let differs = |current: &Option<String>, wanted: Option<&str>| {
+        wanted.is_some_and(|wanted| current.as_deref() != Some(wanted))
+    };
+    differs(existing.first_name(), desired.first_name())
+        || differs(existing.last_name(), desired.last_name())
+}
+
+fn is_valid_email(email: &str) -> bool {
+    match email.rsplit_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty() && domain.contains('.') && !email.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn contract(seats_number: usize, is_hard_seats_limit_type: bool) -> Contract {
+        serde_json::from_value(json!({
+            "contract_id": "C1",
+            "aid": "AID",
+            "contract_type": "SPECIFIC_EMAIL_ADDRESSES_CONTRACT",
+            "name": "Contract",
+            "create_date": 1700000000,
+            "licensee_id": "L1",
+            "seats_number": seats_number,
+            "is_hard_seats_limit_type": is_hard_seats_limit_type,
+            "rid": "R1",
+            "contract_is_active": true
+        }))
+        .expect("OK")
+    }
+
+    fn user(id: &str, email: &str, first_name: &str, status: &str) -> ContractUser {
+        serde_json::from_value(json!({
+            "email": email,
+            "first_name": first_name,
+            "last_name": "Doe",
+            "contract_user_id": id,
+            "status": status
+        }))
+        .expect("OK")
+    }
+
+    #[test]
+    fn test_plan() {
+        let existing = vec![
+            user("CU1", "jane@example.com", "Jane", "ACTIVE"),
+            user("CU2", "gone@example.com", "Gone", "PENDING"),
+            user("CU3", "back@example.com", "Back", "REVOKED"),
+        ];
+        let desired = vec![
+            DesiredContractUser::new("JANE@example.com").with_name("Janet", "Doe"),
+            DesiredContractUser::new("back@example.com"),
+            DesiredContractUser::new("new@example.com").with_name("New", "User"),
+            DesiredContractUser::new("late@example.com"),
+            DesiredContractUser::new("not-an-email"),
+        ];
+        let plan = ReconcilePlan::new(&contract(3, true), &existing, &desired, &SyncOptions::new());
+        assert_eq!(
+            plan.actions()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "- gone@example.com",
+                "^ back@example.com",
+                "~ JANE@example.com (Janet Doe)",
+                "+ new@example.com (New User)",
+            ]
+        );
+        assert_eq!(
+            plan.skipped(),
+            &[
+                SkippedContractUser {
+                    email: "late@example.com".to_string(),
+                    reason: SkipReason::SeatLimit,
+                },
+                SkippedContractUser {
+                    email: "not-an-email".to_string(),
+                    reason: SkipReason::InvalidEmail,
+                },
+            ]
+        );
+        assert_eq!(plan.seats_used(), 3);
+        assert!(!plan.exceeds_seats());
+
+        let report = ReconcileReport::dry_run(&plan);
+        assert_eq!(report.results().len(), 6);
+        assert_eq!(
+            serde_json::to_value(&report.results()[4]).expect("OK"),
+            json!({ "email": "late@example.com", "action": "skip", "outcome": "skipped", "reason": "seat_limit" })
+        );
+    }
+
+    #[test]
+    fn test_plan_is_idempotent() {
+        // The contract once `test_plan` is applied
+        let existing = vec![
+            user("CU1", "jane@example.com", "Janet", "ACTIVE"),
+            user("CU2", "gone@example.com", "Gone", "REVOKED"),
+            user("CU3", "back@example.com", "Back", "ACTIVE"),
+            user("CU4", "new@example.com", "New", "PENDING"),
+            user("CU5", "broken@example", "Broken", "INVALID"),
+        ];
+        let desired = vec![
+            DesiredContractUser::new("jane@example.com").with_name("Janet", "Doe"),
+            DesiredContractUser::new("back@example.com"),
+            DesiredContractUser::new("new@example.com").with_name("New", "Doe"),
+        ];
+        let plan = ReconcilePlan::new(&contract(3, true), &existing, &desired, &SyncOptions::new());
+        assert!(plan.actions().is_empty(), "{plan}");
+        assert!(plan.is_empty());
+        assert_eq!(plan.seats_used(), 3);
+    }
+
+    #[test]
+    fn test_plan_with_duplicate_emails() {
+        let desired = vec![DesiredContractUser::new("dup@example.com").with_name("Dup", "Doe")];
+        for existing in [
+            vec![
+                user("CU1", "dup@example.com", "Dup", "ACTIVE"),
+                user("CU2", "DUP@example.com", "Old", "REVOKED"),
+            ],
+            vec![
+                user("CU2", "DUP@example.com", "Old", "REVOKED"),
+                user("CU1", "dup@example.com", "Dup", "ACTIVE"),
+            ],
+        ] {
+            let plan =
+                ReconcilePlan::new(&contract(1, true), &existing, &desired, &SyncOptions::new());
+            assert!(plan.is_empty(), "{plan}");
+            assert_eq!(plan.seats_used(), 1);
+        }
+
+        let existing = vec![
+            user("CU1", "dup@example.com", "Dupe", "PENDING"),
+            user("CU2", "dup@example.com", "Dup", "REVOKED"),
+        ];
+        let plan = ReconcilePlan::new(&contract(1, true), &existing, &desired, &SyncOptions::new());
+        assert_eq!(
+            plan.actions(),
+            &[ReconcileAction::Update {
+                contract_user_id: "CU1".to_string(),
+                user: desired[0].clone(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_plan_with_soft_limit_and_kept_users() {
+        let existing = vec![user("CU1", "jane@example.com", "Jane", "ACTIVE")];
+        let desired = vec![
+            DesiredContractUser::new("new@example.com"),
+            DesiredContractUser::new("other@example.com"),
+        ];
+        let options = SyncOptions::new().with_remove_missing(false);
+        let plan = ReconcilePlan::new(&contract(2, false), &existing, &desired, &options);
+        assert_eq!(plan.count(ReconcileActionKind::Add), 2);
+        assert_eq!(plan.count(ReconcileActionKind::Remove), 0);
+        assert_eq!(plan.seats_used(), 3);
+        assert!(plan.exceeds_seats());
+        assert_eq!(
+            serde_json::to_value(&plan.actions()[0]).expect("OK"),
+            json!({ "action": "add", "email": "new@example.com" })
+        );
+    }
+}
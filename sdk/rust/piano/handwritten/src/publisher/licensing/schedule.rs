@@ -1 +1,39 @@
 pub mod contract;
+mod schema;
+use self::schema::*;
+
+use crate::{publisher::licensing::contract::Contract, PianoAPI, PianoResponse};
+
+impl PianoAPI {
+    /// List the contracts using a schedule
+    ///
+    /// Check which contracts are affected before changing the periods of a schedule or
+    /// deleting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule_id` - The ID of the schedule
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Flicensing~2Fschedule~2Fcontracts) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_schedule_contracts(
+        &self,
+        schedule_id: &str,
+    ) -> Result<Vec<Contract>, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/licensing/schedule/contracts",
+                self.endpoint,
+            ))
+            .query(&[("aid", self.app_id.as_str()), ("schedule_id", schedule_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<ScheduleContractsResult>>()
+            .await?
+            .value()?;
+        Ok(result.contracts)
+    }
+}
@@ -7,8 +7,6 @@ pub struct ActivatePeriodRequest<'a> {
 
 impl<'a> ActivatePeriodRequest<'a> {
     pub fn new(schedule_id: &'a str) -> Self {
-        Self {
-            schedule_id: schedule_id,
-        }
+        Self { schedule_id }
     }
 }
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+use crate::publisher::licensing::contract::Contract;
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ScheduleContractsResult {
+    #[serde(alias = "Contract")]
+    pub contracts: Vec<Contract>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PianoResponse;
+
+    #[test]
+    fn test_schedule_contracts_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "ts": 1749017266,
+            "Contract": [
+                {
+                    "contract_id": "C1",
+                    "aid": "AID",
+                    "contract_type": "IP_RANGE_CONTRACT",
+                    "name": "Campus",
+                    "create_date": 1700000000,
+                    "licensee_id": "L1",
+                    "seats_number": 10,
+                    "is_hard_seats_limit_type": false,
+                    "rid": "R1",
+                    "schedule_id": "S1",
+                    "contract_is_active": true,
+                    "contract_periods": []
+                }
+            ]
+        });
+        let result = serde_json::from_value::<PianoResponse<ScheduleContractsResult>>(json)
+            .expect("OK")
+            .value()
+            .expect("OK");
+        assert_eq!(result.contracts.len(), 1);
+        assert_eq!(result.contracts[0].schedule_id(), Some("S1"));
+    }
+}
@@ -0,0 +1,139 @@
+/// Linked term endpoints for Piano Publisher API.
+///
+/// A [linked term](https://docs.piano.io/linked-term/) grants access in Piano for a
+/// subscription managed by an external billing system. The external system reports the
+/// lifecycle of its subscriptions to Piano as events.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use piano_handwritten_api::{PianoAPI, publisher::linked_term::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// // Report a subscription created by the billing system
+/// let event = LinkedTermEvent::new(
+///     LinkedTermEventAction::Create,
+///     "external_term_id",
+///     "external_subscription_id",
+///     "user_uid",
+/// )
+/// .with_state(SubscriptionStatus::Active)
+/// .with_valid_to(1735689600)
+/// .with_payment(LinkedTermPayment::new("9.99", "USD").with_external_tx_id("tx_1"));
+/// api.send_linked_term_event(&event).await?;
+///
+/// // Look up the Piano subscription
+/// let subscription = api
+///     .get_linked_term_subscription("external_subscription_id")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+mod schema;
+pub use self::schema::*;
+
+use crate::{PianoAPI, PianoResponse};
+
+impl PianoAPI {
+    /// Create or update a linked term.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FlinkedTerm~2Fconfiguration)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn configure_linked_term(
+        &self,
+        configuration: &LinkedTermConfiguration,
+    ) -> Result<String, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/linkedTerm/configuration",
+                self.endpoint
+            ))
+            .query(&[("aid", &self.app_id)])
+            .json(configuration)
+            .send()
+            .await?
+            .json::<PianoResponse<LinkedTermStringResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Create or update the custom fields of linked terms.
+    ///
+    /// The configuration is sent as is, see the
+    /// [schemas and examples](https://docs.piano.io/linked-term-schemas-and-examples/).
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FlinkedTerm~2FcustomField~2Fconfiguration)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn configure_linked_term_custom_fields(
+        &self,
+        configuration: &serde_json::Value,
+    ) -> Result<String, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/linkedTerm/customField/configuration",
+                self.endpoint
+            ))
+            .query(&[("aid", &self.app_id)])
+            .json(configuration)
+            .send()
+            .await?
+            .json::<PianoResponse<LinkedTermStringResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Synchronize a linked term subscription with an event of the external subscription.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FlinkedTerm~2Fevent)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn send_linked_term_event(
+        &self,
+        event: &LinkedTermEvent,
+    ) -> Result<String, crate::Error> {
+        let result = self
+            .client
+            .post(format!("{}/publisher/linkedTerm/event", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .json(event)
+            .send()
+            .await?
+            .json::<PianoResponse<LinkedTermStringResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Get the subscription linked with an external subscription.
+    ///
+    /// Returns `None` if no subscription is linked with the external subscription.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2FlinkedTerm~2Fsubscription)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_linked_term_subscription(
+        &self,
+        external_sub_id: &str,
+    ) -> Result<Option<UserSubscription>, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/linkedTerm/subscription",
+                self.endpoint
+            ))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("external_sub_id", external_sub_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<UserSubscriptionResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.subscription))
+    }
+}
@@ -0,0 +1,410 @@
+use serde::{Deserialize, Serialize};
+
+/// Status of a subscription
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    Active,
+    Cancelled,
+    FailAndRetry,
+    ExpiredWithError,
+    Expired,
+    Completed,
+    Deleted,
+    WaitConfirmed,
+    Upgraded,
+    Pending,
+    NotStarted,
+    /// A status without a dedicated variant
+    #[serde(other)]
+    Other,
+}
+
+/// Kind of change of an external subscription
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkedTermEventAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// Configuration of a linked term
+///
+/// Fields without a dedicated member can be set with [`LinkedTermConfiguration::with_field`],
+/// see the [schemas and examples](https://docs.piano.io/linked-term-schemas-and-examples/).
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FlinkedTerm~2Fconfiguration)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LinkedTermConfiguration {
+    /// The ID of the term in the external system
+    pub external_term_id: String,
+    /// The term name
+    pub name: String,
+    /// The ID of the resource the term grants access to
+    pub rid: String,
+    /// The term description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The URL where users manage their external subscription
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_management_url: Option<String>,
+    /// Other fields of the configuration
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl LinkedTermConfiguration {
+    /// Create a new linked term configuration
+    pub fn new(external_term_id: &str, name: &str, rid: &str) -> Self {
+        Self {
+            external_term_id: external_term_id.to_string(),
+            name: name.to_string(),
+            rid: rid.to_string(),
+            description: None,
+            subscription_management_url: None,
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Set the term description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Set the URL where users manage their external subscription
+    pub fn with_subscription_management_url(mut self, url: &str) -> Self {
+        self.subscription_management_url = Some(url.to_string());
+        self
+    }
+
+    /// Set a field without a dedicated member
+    pub fn with_field(mut self, name: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+/// Payment of an external subscription
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LinkedTermPayment {
+    /// The amount paid
+    pub amount: String,
+    /// The currency of the payment (ISO 4217)
+    pub currency: String,
+    /// The ID of the transaction in the external system
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_tx_id: Option<String>,
+    /// The tax included in the amount
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax: Option<String>,
+}
+
+impl LinkedTermPayment {
+    /// Create a new payment
+    pub fn new(amount: &str, currency: &str) -> Self {
+        Self {
+            amount: amount.to_string(),
+            currency: currency.to_string(),
+            external_tx_id: None,
+            tax: None,
+        }
+    }
+
+    /// Set the ID of the transaction in the external system
+    pub fn with_external_tx_id(mut self, external_tx_id: &str) -> Self {
+        self.external_tx_id = Some(external_tx_id.to_string());
+        self
+    }
+
+    /// Set the tax included in the amount
+    pub fn with_tax(mut self, tax: &str) -> Self {
+        self.tax = Some(tax.to_string());
+        self
+    }
+}
+
+/// Event of an external subscription, synchronized to its linked term subscription
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2FlinkedTerm~2Fevent)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LinkedTermEvent {
+    /// The kind of change
+    pub action: LinkedTermEventAction,
+    /// The ID of the term in the external system
+    pub external_term_id: String,
+    /// The ID of the subscription in the external system
+    pub external_sub_id: String,
+    /// The ID of the Piano user holding the subscription
+    pub uid: String,
+    /// The status of the subscription
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<SubscriptionStatus>,
+    /// When the access starts (UNIX timestamp)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<i64>,
+    /// When the access ends (UNIX timestamp)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<i64>,
+    /// Whether the subscription renews automatically
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_renew: Option<bool>,
+    /// Custom data of the access
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_custom_data: Option<String>,
+    /// The last payment of the subscription
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_last_payment: Option<LinkedTermPayment>,
+    /// Other fields of the event
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl LinkedTermEvent {
+    /// Create a new linked term event
+    pub fn new(
+        action: LinkedTermEventAction,
+        external_term_id: &str,
+        external_sub_id: &str,
+        uid: &str,
+    ) -> Self {
+        Self {
+            action,
+            external_term_id: external_term_id.to_string(),
+            external_sub_id: external_sub_id.to_string(),
+            uid: uid.to_string(),
+            state: None,
+            valid_from: None,
+            valid_to: None,
+            auto_renew: None,
+            access_custom_data: None,
+            subscription_last_payment: None,
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Set the status of the subscription
+    pub fn with_state(mut self, state: SubscriptionStatus) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Set when the access starts
+    pub fn with_valid_from(mut self, valid_from: i64) -> Self {
+        self.valid_from = Some(valid_from);
+        self
+    }
+
+    /// Set when the access ends
+    pub fn with_valid_to(mut self, valid_to: i64) -> Self {
+        self.valid_to = Some(valid_to);
+        self
+    }
+
+    /// Set whether the subscription renews automatically
+    pub fn with_auto_renew(mut self, auto_renew: bool) -> Self {
+        self.auto_renew = Some(auto_renew);
+        self
+    }
+
+    /// Set the custom data of the access
+    pub fn with_access_custom_data(mut self, access_custom_data: &str) -> Self {
+        self.access_custom_data = Some(access_custom_data.to_string());
+        self
+    }
+
+    /// Set the last payment of the subscription
+    pub fn with_payment(mut self, payment: LinkedTermPayment) -> Self {
+        self.subscription_last_payment = Some(payment);
+        self
+    }
+
+    /// Set a field without a dedicated member
+    pub fn with_field(mut self, name: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+/// Subscription of a user
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserSubscription {
+    subscription_id: String,
+    status: SubscriptionStatus,
+    #[serde(default)]
+    auto_renew: bool,
+    #[serde(default)]
+    billing_plan: Option<String>,
+    #[serde(default)]
+    payment_method: Option<String>,
+    #[serde(default)]
+    next_bill_date: Option<i64>,
+    #[serde(default)]
+    start_date: Option<i64>,
+    #[serde(default)]
+    create_date: Option<i64>,
+    #[serde(default)]
+    end_date: Option<i64>,
+    #[serde(default)]
+    is_active: bool,
+    #[serde(default)]
+    is_in_trial: bool,
+    #[serde(default)]
+    external_sub_id: Option<String>,
+    #[serde(default)]
+    access_custom_data: Option<String>,
+    #[serde(default)]
+    term: Option<serde_json::Value>,
+    #[serde(default)]
+    resource: Option<serde_json::Value>,
+    #[serde(default)]
+    user: Option<serde_json::Value>,
+}
+
+impl UserSubscription {
+    /// Get the subscription ID
+    pub fn subscription_id(&self) -> &str {
+        &self.subscription_id
+    }
+    /// Get the status of the subscription
+    pub fn status(&self) -> SubscriptionStatus {
+        self.status
+    }
+    /// Check if the subscription renews automatically
+    pub fn auto_renew(&self) -> bool {
+        self.auto_renew
+    }
+    /// Get the billing plan
+    pub fn billing_plan(&self) -> Option<&str> {
+        self.billing_plan.as_deref()
+    }
+    /// Get the payment method
+    pub fn payment_method(&self) -> Option<&str> {
+        self.payment_method.as_deref()
+    }
+    /// Get the next bill date
+    pub fn next_bill_date(&self) -> Option<i64> {
+        self.next_bill_date
+    }
+    /// Get the start date
+    pub fn start_date(&self) -> Option<i64> {
+        self.start_date
+    }
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<i64> {
+        self.create_date
+    }
+    /// Get the end date
+    pub fn end_date(&self) -> Option<i64> {
+        self.end_date
+    }
+    /// Check if the subscription is active
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+    /// Check if the subscription is in trial period
+    pub fn is_in_trial(&self) -> bool {
+        self.is_in_trial
+    }
+    /// Get the ID of the linked external subscription
+    pub fn external_sub_id(&self) -> Option<&str> {
+        self.external_sub_id.as_deref()
+    }
+    /// Get the custom data of the access
+    pub fn access_custom_data(&self) -> Option<&str> {
+        self.access_custom_data.as_deref()
+    }
+    /// Get the term of the subscription
+    pub fn term(&self) -> Option<&serde_json::Value> {
+        self.term.as_ref()
+    }
+    /// Get the ID of the term of the subscription
+    pub fn term_id(&self) -> Option<&str> {
+        self.term.as_ref()?.get("term_id")?.as_str()
+    }
+    /// Get the resource of the subscription
+    pub fn resource(&self) -> Option<&serde_json::Value> {
+        self.resource.as_ref()
+    }
+    /// Get the ID of the resource of the subscription
+    pub fn rid(&self) -> Option<&str> {
+        self.resource.as_ref()?.get("rid")?.as_str()
+    }
+    /// Get the user holding the subscription
+    pub fn user(&self) -> Option<&serde_json::Value> {
+        self.user.as_ref()
+    }
+    /// Get the ID of the user holding the subscription
+    pub fn uid(&self) -> Option<&str> {
+        self.user.as_ref()?.get("uid")?.as_str()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct LinkedTermStringResult {
+    #[serde(default)]
+    pub data: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserSubscriptionResult {
+    #[serde(rename = "UserSubscription")]
+    pub subscription: UserSubscription,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_linked_term_event() {
+        let event = LinkedTermEvent::new(LinkedTermEventAction::Update, "ET1", "ES1", "PNI1")
+            .with_state(SubscriptionStatus::Cancelled)
+            .with_valid_to(1735689600)
+            .with_payment(LinkedTermPayment::new("9.99", "USD").with_external_tx_id("TX1"))
+            .with_field("external_customer_id", "C1");
+        assert_eq!(
+            serde_json::to_value(&event).expect("OK"),
+            json!({
+                "action": "update",
+                "external_term_id": "ET1",
+                "external_sub_id": "ES1",
+                "uid": "PNI1",
+                "state": "cancelled",
+                "valid_to": 1735689600,
+                "subscription_last_payment": {
+                    "amount": "9.99",
+                    "currency": "USD",
+                    "external_tx_id": "TX1",
+                },
+                "external_customer_id": "C1",
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_user_subscription() {
+        let result: UserSubscriptionResult = serde_json::from_value(json!({
+            "UserSubscription": {
+                "subscription_id": "RCS1",
+                "auto_renew": true,
+                "status": "active",
+                "term": { "term_id": "TM1", "name": "Linked" },
+                "resource": { "rid": "RID1" },
+                "user": { "uid": "PNI1" },
+                "start_date": 1700000000,
+                "is_active": true,
+                "external_sub_id": "ES1",
+                "external_api_name": null
+            }
+        }))
+        .expect("OK");
+        let subscription = result.subscription;
+        assert_eq!(subscription.status(), SubscriptionStatus::Active);
+        assert_eq!(subscription.term_id(), Some("TM1"));
+        assert_eq!(subscription.external_sub_id(), Some("ES1"));
+    }
+}
@@ -0,0 +1,110 @@
+/// Payment and refund endpoints for Piano Publisher API.
+///
+/// This module provides functionality to look up user payments and refund them,
+/// either fully or partially.
+///
+/// ## Example
+///
+/// ```rust
+/// use piano_handwritten_api::{PianoAPI, publisher::payment::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// // Get a payment by ID
+/// let payment = api.get_payment("user_payment_id").await?;
+///
+/// // Refund $5.00 of the payment. The amount is checked against the payment first.
+/// let refund_req = RefundPaymentRequest::partial("user_payment_id", Money::from_minor_units(500, "USD"))
+///     .with_revoke_access(false);
+/// let refund = api.refund_payment(&refund_req).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub mod method;
+mod schema;
+pub use self::schema::*;
+
+use crate::{invalid_input, PianoAPI, PianoResponse};
+
+impl PianoAPI {
+    /// Get a user payment by ID.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fpayment~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_payment(&self, user_payment_id: &str) -> Result<Payment, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/payment/get", self.endpoint))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("user_payment_id", user_payment_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_payment)
+    }
+
+    /// Check whether a user payment can be refunded partially.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fpayment~2FisPartialRefund)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn is_partial_refund(&self, user_payment_id: &str) -> Result<bool, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/payment/isPartialRefund",
+                self.endpoint
+            ))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("user_payment_id", user_payment_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Refund a user payment.
+    ///
+    /// The payment is fetched first and an [`std::io::ErrorKind::InvalidInput`] error is returned
+    /// without issuing the refund if the payment is not refundable, or if a partial refund
+    /// amount is not positive, is in another currency than the payment or exceeds the
+    /// amount that has not been refunded yet (see [`Payment::refundable_amount`]). An
+    /// [`std::io::ErrorKind::InvalidData`] error is returned if the payment was already
+    /// refunded in another currency.
+    /// A partial refund is also checked with [`PianoAPI::is_partial_refund`].
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fpayment~2Frefund)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn refund_payment(
+        &self,
+        req: &RefundPaymentRequest<'_>,
+    ) -> Result<PaymentRefund, crate::Error> {
+        let payment = self.get_payment(req.user_payment_id).await?;
+        req.check_refundable(&payment)?;
+        if req.is_partial() && !self.is_partial_refund(req.user_payment_id).await? {
+            return Err(invalid_input(format!(
+                "payment {} is not eligible for a partial refund",
+                req.user_payment_id
+            )));
+        }
+        let result = self
+            .client
+            .get(format!("{}/publisher/payment/refund", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(req)
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentRefundResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_payment_refund_dto)
+    }
+}
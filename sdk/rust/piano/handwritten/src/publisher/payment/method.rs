@@ -0,0 +1,215 @@
+mod schema;
+pub use self::schema::*;
+
+use super::{BillingAddress, PaymentBooleanResult};
+use crate::{Empty, PianoAPI, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Get a payment method of a user.
+    ///
+    /// Returns `None` if the payment method is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fpayment~2Fmethod~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_payment_method(
+        &self,
+        uid: &str,
+        user_payment_info_id: &str,
+    ) -> Result<Option<PaymentMethod>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/payment/method/get", self.endpoint))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("uid", uid),
+                ("user_payment_info_id", user_payment_info_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentMethodResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.user_payment_info))
+    }
+
+    /// Add a payment method to a user.
+    ///
+    /// Returns the ID of the created payment method.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2Fadd)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn add_payment_method(
+        &self,
+        params: &AddPaymentMethodRequest<'_>,
+    ) -> Result<String, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/payment/method/add", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentMethodStringResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Update a payment method of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2Fupdate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_payment_method(
+        &self,
+        params: &UpdatePaymentMethodRequest<'_>,
+    ) -> Result<bool, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/payment/method/update", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Remove a payment method of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2Fremove)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn remove_payment_method(
+        &self,
+        params: &RemovePaymentMethodRequest<'_>,
+    ) -> Result<bool, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/payment/method/remove", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<PaymentBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Get the billing address of a payment method.
+    ///
+    /// Returns `None` if the payment method has no billing address.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_payment_method_billing_address(
+        &self,
+        params: &GetPaymentMethodBillingAddressRequest<'_>,
+    ) -> Result<Option<BillingAddress>, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/payment/method/billingAddress/get",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<BillingAddressResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.user_billing_address))
+    }
+
+    /// Attach a billing address to a payment method.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2Fattach)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn attach_billing_address(
+        &self,
+        params: &AttachBillingAddressRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        self.client
+            .post(format!(
+                "{}/publisher/payment/method/billingAddress/attach",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// Detach the billing address from a payment method.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2Fdetach)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn detach_billing_address(
+        &self,
+        params: &DetachBillingAddressRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        self.client
+            .post(format!(
+                "{}/publisher/payment/method/billingAddress/detach",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// Apply a billing address to all payment methods of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2FapplyToAll)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn apply_billing_address_to_all(
+        &self,
+        params: &ApplyBillingAddressToAllRequest<'_>,
+    ) -> Result<(), crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        self.client
+            .post(format!(
+                "{}/publisher/payment/method/billingAddress/applyToAll",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,491 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to add a payment method to a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2Fadd)
+#[derive(Debug, Serialize)]
+pub struct AddPaymentMethodRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The payment method token
+    pub token: &'a str,
+    /// The payment provider source ID
+    pub source_id: i64,
+    /// Set this payment method as the default method and apply it to all active subscriptions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_as_default: Option<bool>,
+    /// A JSON object containing the user's billing address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_billing_address: Option<&'a str>,
+    /// The payment method expiration month
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_month: Option<u32>,
+    /// The payment method expiration year
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_year: Option<u32>,
+    /// The payment method last four digits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_four_digits: Option<&'a str>,
+    /// A JSON object that contains additional information required by providers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<&'a str>,
+    /// The payment method issuer country
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer_country: Option<&'a str>,
+}
+
+impl<'a> AddPaymentMethodRequest<'a> {
+    /// Create a new add payment method request
+    pub fn new(uid: &'a str, token: &'a str, source_id: i64) -> Self {
+        Self {
+            uid,
+            token,
+            source_id,
+            set_as_default: None,
+            user_billing_address: None,
+            expiration_month: None,
+            expiration_year: None,
+            last_four_digits: None,
+            additional_info: None,
+            issuer_country: None,
+        }
+    }
+
+    /// Set whether this payment method becomes the default one
+    pub fn with_set_as_default(mut self, set_as_default: bool) -> Self {
+        self.set_as_default = Some(set_as_default);
+        self
+    }
+
+    /// Set the billing address (as JSON string)
+    pub fn with_user_billing_address(mut self, user_billing_address: &'a str) -> Self {
+        self.user_billing_address = Some(user_billing_address);
+        self
+    }
+
+    /// Set the expiration month and year
+    pub fn with_expiration(mut self, month: u32, year: u32) -> Self {
+        self.expiration_month = Some(month);
+        self.expiration_year = Some(year);
+        self
+    }
+
+    /// Set the last four digits
+    pub fn with_last_four_digits(mut self, last_four_digits: &'a str) -> Self {
+        self.last_four_digits = Some(last_four_digits);
+        self
+    }
+
+    /// Set provider specific additional information (as JSON string)
+    pub fn with_additional_info(mut self, additional_info: &'a str) -> Self {
+        self.additional_info = Some(additional_info);
+        self
+    }
+
+    /// Set the issuer country
+    pub fn with_issuer_country(mut self, issuer_country: &'a str) -> Self {
+        self.issuer_country = Some(issuer_country);
+        self
+    }
+}
+
+/// Request to update a payment method of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2Fupdate)
+#[derive(Debug, Serialize)]
+pub struct UpdatePaymentMethodRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The payment method ID
+    pub payment_method_id: &'a str,
+    /// The payment method token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<&'a str>,
+    /// Set this payment method as the default method and apply it to all active subscriptions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_as_default: Option<bool>,
+    /// A JSON object containing the user's billing address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_billing_address: Option<&'a str>,
+    /// The payment method expiration month
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_month: Option<u32>,
+    /// The payment method expiration year
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_year: Option<u32>,
+    /// The payment method last four digits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_four_digits: Option<&'a str>,
+    /// A JSON object that contains additional information required by providers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<&'a str>,
+    /// The payment method issuer country
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer_country: Option<&'a str>,
+}
+
+impl<'a> UpdatePaymentMethodRequest<'a> {
+    /// Create a new update payment method request
+    pub fn new(uid: &'a str, payment_method_id: &'a str) -> Self {
+        Self {
+            uid,
+            payment_method_id,
+            token: None,
+            set_as_default: None,
+            user_billing_address: None,
+            expiration_month: None,
+            expiration_year: None,
+            last_four_digits: None,
+            additional_info: None,
+            issuer_country: None,
+        }
+    }
+
+    /// Set the payment method token
+    pub fn with_token(mut self, token: &'a str) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Set whether this payment method becomes the default one
+    pub fn with_set_as_default(mut self, set_as_default: bool) -> Self {
+        self.set_as_default = Some(set_as_default);
+        self
+    }
+
+    /// Set the billing address (as JSON string)
+    pub fn with_user_billing_address(mut self, user_billing_address: &'a str) -> Self {
+        self.user_billing_address = Some(user_billing_address);
+        self
+    }
+
+    /// Set the expiration month and year
+    pub fn with_expiration(mut self, month: u32, year: u32) -> Self {
+        self.expiration_month = Some(month);
+        self.expiration_year = Some(year);
+        self
+    }
+
+    /// Set the last four digits
+    pub fn with_last_four_digits(mut self, last_four_digits: &'a str) -> Self {
+        self.last_four_digits = Some(last_four_digits);
+        self
+    }
+
+    /// Set provider specific additional information (as JSON string)
+    pub fn with_additional_info(mut self, additional_info: &'a str) -> Self {
+        self.additional_info = Some(additional_info);
+        self
+    }
+
+    /// Set the issuer country
+    pub fn with_issuer_country(mut self, issuer_country: &'a str) -> Self {
+        self.issuer_country = Some(issuer_country);
+        self
+    }
+}
+
+/// Request to remove a payment method of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2Fremove)
+#[derive(Debug, Serialize)]
+pub struct RemovePaymentMethodRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The payment method ID
+    pub payment_method_id: &'a str,
+}
+
+impl<'a> RemovePaymentMethodRequest<'a> {
+    /// Create a new remove payment method request
+    pub fn new(uid: &'a str, payment_method_id: &'a str) -> Self {
+        Self {
+            uid,
+            payment_method_id,
+        }
+    }
+}
+
+/// Request to get the billing address of a payment method
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2Fget)
+#[derive(Debug, Serialize)]
+pub struct GetPaymentMethodBillingAddressRequest<'a> {
+    /// The payment method ID
+    pub upi_identifier: &'a str,
+    /// The user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+}
+
+impl<'a> GetPaymentMethodBillingAddressRequest<'a> {
+    /// Create a new get billing address request
+    pub fn new(upi_identifier: &'a str) -> Self {
+        Self {
+            upi_identifier,
+            uid: None,
+        }
+    }
+
+    /// Set the user ID
+    pub fn with_uid(mut self, uid: &'a str) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+}
+
+/// Request to attach a billing address to a payment method
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2Fattach)
+#[derive(Debug, Serialize)]
+pub struct AttachBillingAddressRequest<'a> {
+    /// The payment method ID
+    pub upi_identifier: &'a str,
+    /// The public ID of the address
+    pub address_pub_id: &'a str,
+    /// The user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+}
+
+impl<'a> AttachBillingAddressRequest<'a> {
+    /// Create a new attach billing address request
+    pub fn new(upi_identifier: &'a str, address_pub_id: &'a str) -> Self {
+        Self {
+            upi_identifier,
+            address_pub_id,
+            uid: None,
+        }
+    }
+
+    /// Set the user ID
+    pub fn with_uid(mut self, uid: &'a str) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+}
+
+/// Request to detach the billing address from a payment method
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2Fdetach)
+#[derive(Debug, Serialize)]
+pub struct DetachBillingAddressRequest<'a> {
+    /// The payment method ID
+    pub upi_identifier: &'a str,
+    /// The user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+}
+
+impl<'a> DetachBillingAddressRequest<'a> {
+    /// Create a new detach billing address request
+    pub fn new(upi_identifier: &'a str) -> Self {
+        Self {
+            upi_identifier,
+            uid: None,
+        }
+    }
+
+    /// Set the user ID
+    pub fn with_uid(mut self, uid: &'a str) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+}
+
+/// Request to apply a billing address to all payment methods of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpayment~2Fmethod~2FbillingAddress~2FapplyToAll)
+#[derive(Debug, Serialize)]
+pub struct ApplyBillingAddressToAllRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The public ID of the address
+    pub address_pub_id: &'a str,
+}
+
+impl<'a> ApplyBillingAddressToAllRequest<'a> {
+    /// Create a new apply billing address to all request
+    pub fn new(uid: &'a str, address_pub_id: &'a str) -> Self {
+        Self {
+            uid,
+            address_pub_id,
+        }
+    }
+}
+
+/// Payment method (user payment info) object
+#[derive(Debug, Deserialize, Clone)]
+pub struct PaymentMethod {
+    user_payment_info_id: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    upi_nickname: Option<String>,
+    #[serde(default)]
+    upi_number: Option<String>,
+    #[serde(default)]
+    upi_expiration_month: Option<u32>,
+    #[serde(default)]
+    upi_expiration_year: Option<u32>,
+    #[serde(default)]
+    upi_postal_code: Option<String>,
+    #[serde(default)]
+    upi_identifier: Option<String>,
+    #[serde(default)]
+    payment_method: Option<String>,
+    #[serde(default)]
+    payment_type: Option<String>,
+    #[serde(default)]
+    issuer_country_code: Option<String>,
+    #[serde(default)]
+    is_mock: bool,
+}
+
+impl PaymentMethod {
+    /// Get the user payment info ID
+    pub fn user_payment_info_id(&self) -> &str {
+        &self.user_payment_info_id
+    }
+
+    /// Get the description
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Get the card nickname
+    pub fn nickname(&self) -> Option<&str> {
+        self.upi_nickname.as_deref()
+    }
+
+    /// Get the masked card number
+    pub fn number(&self) -> Option<&str> {
+        self.upi_number.as_deref()
+    }
+
+    /// Get the expiration month
+    pub fn expiration_month(&self) -> Option<u32> {
+        self.upi_expiration_month
+    }
+
+    /// Get the expiration year
+    pub fn expiration_year(&self) -> Option<u32> {
+        self.upi_expiration_year
+    }
+
+    /// Get the postal code of the card
+    pub fn postal_code(&self) -> Option<&str> {
+        self.upi_postal_code.as_deref()
+    }
+
+    /// Get the payment method ID used by billing address operations
+    pub fn upi_identifier(&self) -> Option<&str> {
+        self.upi_identifier.as_deref()
+    }
+
+    /// Get the payment method identifier (e.g. `CC`, `ACH`)
+    pub fn payment_method(&self) -> Option<&str> {
+        self.payment_method.as_deref()
+    }
+
+    /// Get the payment type identifier (e.g. `V`, `MC`, `AMEX`)
+    pub fn payment_type(&self) -> Option<&str> {
+        self.payment_type.as_deref()
+    }
+
+    /// Get the issuer country code
+    pub fn issuer_country_code(&self) -> Option<&str> {
+        self.issuer_country_code.as_deref()
+    }
+
+    /// Check if the payment method is mocked
+    pub fn is_mock(&self) -> bool {
+        self.is_mock
+    }
+}
+
+/// Response wrapper for payment method operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct PaymentMethodResult {
+    #[serde(alias = "UserPaymentInfo")]
+    pub user_payment_info: PaymentMethod,
+}
+
+/// Response wrapper for billing address operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct BillingAddressResult {
+    #[serde(alias = "UserBillingAddress")]
+    pub user_billing_address: super::BillingAddress,
+}
+
+/// Response wrapper for operations returning a string
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct PaymentMethodStringResult {
+    pub data: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_payment_method_request_builder() {
+        let request = AddPaymentMethodRequest::new("user123", "tok_123", 39)
+            .with_set_as_default(true)
+            .with_expiration(12, 2030)
+            .with_last_four_digits("4242");
+
+        assert_eq!(request.uid, "user123");
+        assert_eq!(request.token, "tok_123");
+        assert_eq!(request.source_id, 39);
+        assert_eq!(request.set_as_default, Some(true));
+        assert_eq!(request.expiration_month, Some(12));
+        assert_eq!(request.expiration_year, Some(2030));
+        assert_eq!(request.last_four_digits, Some("4242"));
+    }
+
+    #[test]
+    fn test_billing_address_requests() {
+        let attach = AttachBillingAddressRequest::new("upi123", "addr123").with_uid("user123");
+        assert_eq!(attach.upi_identifier, "upi123");
+        assert_eq!(attach.address_pub_id, "addr123");
+        assert_eq!(attach.uid, Some("user123"));
+
+        let detach = DetachBillingAddressRequest::new("upi123");
+        assert_eq!(
+            serde_json::to_value(&detach).expect("OK"),
+            serde_json::json!({ "upi_identifier": "upi123" })
+        );
+    }
+
+    #[test]
+    fn test_payment_method_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "UserPaymentInfo": {
+                "user_payment_info_id": "UPIXXXXXXX",
+                "description": "Visa ending in 4242",
+                "upi_nickname": null,
+                "upi_number": "****4242",
+                "upi_expiration_month": 12,
+                "upi_expiration_year": 2030,
+                "upi_postal_code": "10001",
+                "upi_identifier": "pm_XXXXXXXX",
+                "payment_method": "CC",
+                "payment_type": "V",
+                "issuer_country_code": "US",
+                "is_mock": false
+            }
+        });
+
+        let result = serde_json::from_value::<crate::PianoResponse<PaymentMethodResult>>(json)
+            .expect("Failed to deserialize payment method")
+            .value()
+            .expect("OK");
+        let method = result.user_payment_info;
+        assert_eq!(method.user_payment_info_id(), "UPIXXXXXXX");
+        assert_eq!(method.number(), Some("****4242"));
+        assert_eq!(method.payment_type(), Some("V"));
+        assert!(!method.is_mock());
+    }
+}
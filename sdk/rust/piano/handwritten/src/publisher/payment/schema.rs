@@ -0,0 +1,800 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+
+use crate::{invalid_input, publisher::user::User};
+
+/// Monetary amount expressed in the minor units of its currency.
+///
+/// Amounts are kept as integers so that refunds are never affected by floating point
+/// rounding. The number of decimal places is derived from the ISO 4217 currency code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor_units: i64,
+    currency: String,
+}
+
+impl Money {
+    /// Create an amount from minor units (e.g. cents for USD, yen for JPY).
+    pub fn from_minor_units(minor_units: i64, currency: &str) -> Self {
+        Self {
+            minor_units,
+            currency: currency.to_ascii_uppercase(),
+        }
+    }
+
+    /// Create an amount from a major unit value as returned by the Piano API.
+    ///
+    /// The value is rounded to the nearest minor unit.
+    pub fn from_major_units(amount: f64, currency: &str) -> Self {
+        let currency = currency.to_ascii_uppercase();
+        let scale = 10_i64.pow(Self::exponent_of(&currency)) as f64;
+        Self {
+            minor_units: (amount * scale).round() as i64,
+            currency,
+        }
+    }
+
+    /// Get the amount in minor units.
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Get the ISO 4217 currency code.
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Get the number of decimal places used by the currency.
+    pub fn exponent(&self) -> u32 {
+        Self::exponent_of(&self.currency)
+    }
+
+    /// Check if the amount is strictly positive.
+    pub fn is_positive(&self) -> bool {
+        self.minor_units > 0
+    }
+
+    /// Format the amount as a decimal string without currency (e.g. `"12.50"`).
+    pub fn to_decimal_string(&self) -> String {
+        let exponent = self.exponent();
+        if exponent == 0 {
+            return self.minor_units.to_string();
+        }
+        let scale = 10_i64.pow(exponent);
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let abs = self.minor_units.unsigned_abs();
+        format!(
+            "{sign}{}.{:0width$}",
+            abs / scale as u64,
+            abs % scale as u64,
+            width = exponent as usize
+        )
+    }
+
+    fn exponent_of(currency: &str) -> u32 {
+        match currency {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency)
+    }
+}
+
+/// Request to refund a user payment.
+///
+/// A full refund refunds the remaining amount of the payment. A partial refund refunds
+/// the given amount, and is only issued after the payment has been checked to be
+/// eligible for a partial refund.
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fpayment~2Frefund)
+#[derive(Debug, Serialize)]
+pub struct RefundPaymentRequest<'a> {
+    /// The user payment ID
+    pub user_payment_id: &'a str,
+    /// The refunded amount (omitted for a full refund)
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_money_amount"
+    )]
+    pub amount: Option<Money>,
+    /// Whether to revoke user access
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_access: Option<bool>,
+}
+
+impl<'a> RefundPaymentRequest<'a> {
+    /// Create a request to refund the whole payment
+    pub fn full(user_payment_id: &'a str) -> Self {
+        Self {
+            user_payment_id,
+            amount: None,
+            revoke_access: None,
+        }
+    }
+
+    /// Create a request to refund part of the payment
+    pub fn partial(user_payment_id: &'a str, amount: Money) -> Self {
+        Self {
+            user_payment_id,
+            amount: Some(amount),
+            revoke_access: None,
+        }
+    }
+
+    /// Set whether to revoke user access
+    pub fn with_revoke_access(mut self, revoke_access: bool) -> Self {
+        self.revoke_access = Some(revoke_access);
+        self
+    }
+
+    /// Check if this is a partial refund
+    pub fn is_partial(&self) -> bool {
+        self.amount.is_some()
+    }
+
+    /// Check the request against the payment it refunds.
+    ///
+    /// The payment must be refundable, and a partial refund amount must be positive,
+    /// in the currency of the payment and at most [`Payment::refundable_amount`], which
+    /// fails if the payment was already refunded in another currency.
+    pub fn check_refundable(&self, payment: &Payment) -> Result<(), crate::Error> {
+        if !payment.is_refundable() {
+            return Err(invalid_input(format!(
+                "payment {} is not refundable",
+                self.user_payment_id
+            )));
+        }
+        let Some(amount) = &self.amount else {
+            return Ok(());
+        };
+        if !amount.is_positive() {
+            return Err(invalid_input(format!(
+                "refund amount must be positive: {amount}"
+            )));
+        }
+        let refundable = payment.refundable_amount()?;
+        if amount.currency() != refundable.currency() {
+            return Err(invalid_input(format!(
+                "refund amount {amount} is not in the payment currency {}",
+                refundable.currency()
+            )));
+        }
+        if amount.minor_units() > refundable.minor_units() {
+            return Err(invalid_input(format!(
+                "refund amount {amount} exceeds the refundable amount {refundable}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn serialize_money_amount<S>(amount: &Option<Money>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match amount {
+        Some(money) => serializer.serialize_str(&money.to_decimal_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// User payment status
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum PaymentStatus {
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "disputed")]
+    Disputed,
+    #[serde(rename = "refunded")]
+    Refunded,
+    #[serde(rename = "verified")]
+    Verified,
+    #[serde(rename = "canceled")]
+    Canceled,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "refund requested")]
+    RefundRequested,
+    #[serde(rename = "initiated")]
+    Initiated,
+    #[serde(rename = "aborted")]
+    Aborted,
+}
+
+/// User payment object
+#[derive(Debug, Deserialize, Clone)]
+pub struct Payment {
+    user_payment_id: String,
+    /// The creation date (ISO 8601)
+    #[serde(default)]
+    create_date: Option<String>,
+    #[serde(default)]
+    renewal: bool,
+    amount: f64,
+    currency: String,
+    #[serde(default)]
+    price: Option<String>,
+    #[serde(default)]
+    original_price: Option<String>,
+    #[serde(default)]
+    refundable: bool,
+    #[serde(default)]
+    refund_amount: Option<f64>,
+    #[serde(default)]
+    refunded_amount: Option<f64>,
+    #[serde(default)]
+    remaining_amount: Option<f64>,
+    #[serde(default)]
+    refund_currency: Option<String>,
+    #[serde(default)]
+    tax: Option<f64>,
+    #[serde(default)]
+    tax_rate: Option<f64>,
+    #[serde(default)]
+    billing_plan: Option<String>,
+    #[serde(default)]
+    user_payment_info_id: Option<String>,
+    #[serde(default)]
+    payment_method: Option<String>,
+    #[serde(default)]
+    source_id: Option<i64>,
+    #[serde(default)]
+    source_name: Option<String>,
+    #[serde(default)]
+    external_transaction_id: Option<String>,
+    #[serde(default)]
+    invoice_number: Option<String>,
+    status: PaymentStatus,
+    #[serde(default)]
+    user: Option<User>,
+    #[serde(default)]
+    term: Option<serde_json::Value>,
+    #[serde(default)]
+    subscription: Option<serde_json::Value>,
+}
+
+impl Payment {
+    /// Get the user payment ID
+    pub fn user_payment_id(&self) -> &str {
+        &self.user_payment_id
+    }
+
+    /// Get the creation date (ISO 8601)
+    pub fn create_date(&self) -> Option<&str> {
+        self.create_date.as_deref()
+    }
+
+    /// Check if the payment is a renewal
+    pub fn is_renewal(&self) -> bool {
+        self.renewal
+    }
+
+    /// Get the payment amount
+    pub fn amount(&self) -> Money {
+        Money::from_major_units(self.amount, &self.currency)
+    }
+
+    /// Get the payment currency
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Get the formatted price
+    pub fn price(&self) -> Option<&str> {
+        self.price.as_deref()
+    }
+
+    /// Get the original price
+    pub fn original_price(&self) -> Option<&str> {
+        self.original_price.as_deref()
+    }
+
+    /// Check if the payment is refundable
+    pub fn is_refundable(&self) -> bool {
+        self.refundable
+    }
+
+    /// Get the amount already refunded, after tax
+    pub fn refunded_amount(&self) -> Option<Money> {
+        self.refunded_amount
+            .map(|amount| Money::from_major_units(amount, self.refund_currency()))
+    }
+
+    /// Get the amount already refunded, before tax
+    pub fn refund_amount(&self) -> Option<Money> {
+        self.refund_amount
+            .map(|amount| Money::from_major_units(amount, self.refund_currency()))
+    }
+
+    /// Get the amount that can still be refunded.
+    ///
+    /// This is the payment amount minus [`Payment::refunded_amount`], in the payment currency.
+    /// Fails with an [`std::io::ErrorKind::InvalidData`] error if an amount was already
+    /// refunded in another currency, since the two amounts cannot be subtracted.
+    pub fn refundable_amount(&self) -> Result<Money, crate::Error> {
+        let amount = self.amount();
+        let Some(refunded) = self
+            .refunded_amount()
+            .filter(|refunded| refunded.minor_units() != 0)
+        else {
+            return Ok(amount);
+        };
+        if refunded.currency() != amount.currency() {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "payment {} of {amount} was refunded {refunded} in another currency",
+                    self.user_payment_id
+                ),
+            )));
+        }
+        Ok(Money::from_minor_units(
+            (amount.minor_units() - refunded.minor_units()).max(0),
+            amount.currency(),
+        ))
+    }
+
+    /// Get the remaining amount for the current subscription
+    pub fn remaining_amount(&self) -> Option<Money> {
+        self.remaining_amount
+            .map(|amount| Money::from_major_units(amount, &self.currency))
+    }
+
+    /// Get the currency refunds are made in
+    pub fn refund_currency(&self) -> &str {
+        self.refund_currency.as_deref().unwrap_or(&self.currency)
+    }
+
+    /// Get the tax amount
+    pub fn tax(&self) -> Option<Money> {
+        self.tax
+            .map(|amount| Money::from_major_units(amount, &self.currency))
+    }
+
+    /// Get the tax rate
+    pub fn tax_rate(&self) -> Option<f64> {
+        self.tax_rate
+    }
+
+    /// Get the billing plan
+    pub fn billing_plan(&self) -> Option<&str> {
+        self.billing_plan.as_deref()
+    }
+
+    /// Get the user payment info (payment method) ID
+    pub fn user_payment_info_id(&self) -> Option<&str> {
+        self.user_payment_info_id.as_deref()
+    }
+
+    /// Get the payment method
+    pub fn payment_method(&self) -> Option<&str> {
+        self.payment_method.as_deref()
+    }
+
+    /// Get the payment provider source ID
+    pub fn source_id(&self) -> Option<i64> {
+        self.source_id
+    }
+
+    /// Get the payment provider name
+    pub fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
+    /// Get the external transaction ID
+    pub fn external_transaction_id(&self) -> Option<&str> {
+        self.external_transaction_id.as_deref()
+    }
+
+    /// Get the invoice number
+    pub fn invoice_number(&self) -> Option<&str> {
+        self.invoice_number.as_deref()
+    }
+
+    /// Get the payment status
+    pub fn status(&self) -> &PaymentStatus {
+        &self.status
+    }
+
+    /// Get the user who made the payment
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Get the raw term the payment was made for
+    pub fn term(&self) -> Option<&serde_json::Value> {
+        self.term.as_ref()
+    }
+
+    /// Get the raw subscription the payment belongs to
+    pub fn subscription(&self) -> Option<&serde_json::Value> {
+        self.subscription.as_ref()
+    }
+}
+
+/// Result of a refund operation
+#[derive(Debug, Deserialize, Clone)]
+pub struct PaymentRefund {
+    status: PaymentStatus,
+    #[serde(default)]
+    refund_external_tx_id: Option<String>,
+    #[serde(default)]
+    refund_downstream_external_tx_id: Option<String>,
+}
+
+impl PaymentRefund {
+    /// Get the payment status after the refund
+    pub fn status(&self) -> &PaymentStatus {
+        &self.status
+    }
+
+    /// Get the ID of the external refund transaction
+    pub fn refund_external_tx_id(&self) -> Option<&str> {
+        self.refund_external_tx_id.as_deref()
+    }
+
+    /// Get the ID of the external refund downstream transaction
+    pub fn refund_downstream_external_tx_id(&self) -> Option<&str> {
+        self.refund_downstream_external_tx_id.as_deref()
+    }
+}
+
+/// Country of a billing address
+#[derive(Debug, Deserialize, Clone)]
+pub struct Country {
+    country_code: String,
+    pub_id: String,
+}
+
+impl Country {
+    /// Get the country code
+    pub fn country_code(&self) -> &str {
+        &self.country_code
+    }
+
+    /// Get the public ID
+    pub fn pub_id(&self) -> &str {
+        &self.pub_id
+    }
+}
+
+/// Region of a billing address
+#[derive(Debug, Deserialize, Clone)]
+pub struct Region {
+    region_name: String,
+    pub_id: String,
+}
+
+impl Region {
+    /// Get the region name
+    pub fn region_name(&self) -> &str {
+        &self.region_name
+    }
+
+    /// Get the public ID
+    pub fn pub_id(&self) -> &str {
+        &self.pub_id
+    }
+}
+
+/// Billing address object
+#[derive(Debug, Deserialize, Clone)]
+pub struct BillingAddress {
+    address_pub_id: String,
+    #[serde(default)]
+    address_line1: Option<String>,
+    #[serde(default)]
+    address_line2: Option<String>,
+    #[serde(default)]
+    address_line3: Option<String>,
+    #[serde(default)]
+    country: Option<Country>,
+    #[serde(default)]
+    region: Option<Region>,
+    #[serde(default)]
+    region_name: Option<String>,
+    #[serde(default)]
+    city: Option<String>,
+    #[serde(default)]
+    postal_code: Option<String>,
+    #[serde(default, rename = "type")]
+    provider_type: Option<String>,
+    #[serde(default)]
+    verified: Option<String>,
+}
+
+impl BillingAddress {
+    /// Get the public ID of the address
+    pub fn address_pub_id(&self) -> &str {
+        &self.address_pub_id
+    }
+
+    /// Get the first address line
+    pub fn address_line1(&self) -> Option<&str> {
+        self.address_line1.as_deref()
+    }
+
+    /// Get the second address line
+    pub fn address_line2(&self) -> Option<&str> {
+        self.address_line2.as_deref()
+    }
+
+    /// Get the third address line
+    pub fn address_line3(&self) -> Option<&str> {
+        self.address_line3.as_deref()
+    }
+
+    /// Get the country
+    pub fn country(&self) -> Option<&Country> {
+        self.country.as_ref()
+    }
+
+    /// Get the region
+    pub fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    /// Get the region name
+    pub fn region_name(&self) -> Option<&str> {
+        self.region_name.as_deref()
+    }
+
+    /// Get the city
+    pub fn city(&self) -> Option<&str> {
+        self.city.as_deref()
+    }
+
+    /// Get the postal code
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    /// Get the address provider type: Experian (1), Avalara (2)
+    pub fn provider_type(&self) -> Option<&str> {
+        self.provider_type.as_deref()
+    }
+
+    /// Check if the address is verified
+    pub fn is_verified(&self) -> bool {
+        self.verified.as_deref() == Some("true")
+    }
+}
+
+/// Response wrapper for payment operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct PaymentResult {
+    #[serde(alias = "UserPayment")]
+    pub user_payment: Payment,
+}
+
+/// Response wrapper for refund operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct PaymentRefundResult {
+    #[serde(alias = "UserPaymentRefundDTO")]
+    pub user_payment_refund_dto: PaymentRefund,
+}
+
+/// Response wrapper for boolean operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct PaymentBooleanResult {
+    pub data: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_money_decimal_string() {
+        assert_eq!(
+            Money::from_minor_units(1250, "usd").to_decimal_string(),
+            "12.50"
+        );
+        assert_eq!(
+            Money::from_minor_units(5, "EUR").to_decimal_string(),
+            "0.05"
+        );
+        assert_eq!(
+            Money::from_minor_units(-199, "EUR").to_decimal_string(),
+            "-1.99"
+        );
+        assert_eq!(
+            Money::from_minor_units(1200, "JPY").to_decimal_string(),
+            "1200"
+        );
+        assert_eq!(
+            Money::from_minor_units(1500, "KWD").to_decimal_string(),
+            "1.500"
+        );
+        assert_eq!(
+            Money::from_minor_units(1250, "usd").to_string(),
+            "12.50 USD"
+        );
+    }
+
+    #[test]
+    fn test_money_from_major_units() {
+        assert_eq!(Money::from_major_units(19.99, "USD").minor_units(), 1999);
+        assert_eq!(Money::from_major_units(0.1 + 0.2, "USD").minor_units(), 30);
+        assert_eq!(Money::from_major_units(980.0, "JPY").minor_units(), 980);
+    }
+
+    #[test]
+    fn test_refund_payment_request() {
+        let full = RefundPaymentRequest::full("UP123").with_revoke_access(true);
+        assert!(!full.is_partial());
+        assert_eq!(
+            serde_json::to_value(&full).expect("OK"),
+            serde_json::json!({ "user_payment_id": "UP123", "revoke_access": true })
+        );
+
+        let partial = RefundPaymentRequest::partial("UP123", Money::from_minor_units(500, "USD"));
+        assert!(partial.is_partial());
+        assert_eq!(
+            serde_json::to_value(&partial).expect("OK"),
+            serde_json::json!({ "user_payment_id": "UP123", "amount": "5.00" })
+        );
+    }
+
+    #[test]
+    fn test_payment_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "ts": 1749016879,
+            "UserPayment": {
+                "user_payment_id": "UPXXXXXXXX",
+                "create_date": "2024-11-06T02:54:24.000+00:00",
+                "renewal": false,
+                "amount": 19.99,
+                "currency": "USD",
+                "price": "$19.99",
+                "refundable": true,
+                "refund_amount": 0,
+                "refunded_amount": 0,
+                "remaining_amount": 19.99,
+                "refund_currency": "USD",
+                "tax": 1.5,
+                "user_payment_info_id": "UPIXXXXXXX",
+                "payment_method": "Visa",
+                "source_id": 39,
+                "source_name": "STRP",
+                "status": "completed",
+                "user": {
+                    "uid": "XXXXXXXXXX",
+                    "email": "user@example.com",
+                    "create_date": 1626840015
+                },
+                "term": { "term_id": "TMXXXXXXXX" }
+            }
+        });
+
+        let result = serde_json::from_value::<crate::PianoResponse<PaymentResult>>(json)
+            .expect("Failed to deserialize payment")
+            .value()
+            .expect("OK");
+        let payment = result.user_payment;
+        assert_eq!(payment.user_payment_id(), "UPXXXXXXXX");
+        assert_eq!(payment.amount(), Money::from_minor_units(1999, "USD"));
+        assert_eq!(
+            payment.remaining_amount(),
+            Some(Money::from_minor_units(1999, "USD"))
+        );
+        assert_eq!(payment.status(), &PaymentStatus::Completed);
+        assert!(payment.is_refundable());
+        assert_eq!(payment.user().map(|u| u.uid()), Some("XXXXXXXXXX"));
+    }
+
+    #[test]
+    fn test_check_refundable() {
+        let payment_in = |refundable: bool, refunded_amount: f64, refund_currency: &str| {
+            serde_json::from_value::<Payment>(serde_json::json!({
+                "user_payment_id": "UPXXXXXXXX",
+                "amount": 19.99,
+                "currency": "USD",
+                "refundable": refundable,
+                "refunded_amount": refunded_amount,
+                "refund_currency": refund_currency,
+                "status": "completed"
+            }))
+            .expect("Failed to deserialize payment")
+        };
+        let payment =
+            |refundable: bool, refunded_amount: f64| payment_in(refundable, refunded_amount, "USD");
+        let partial = |minor_units: i64, currency: &str| {
+            RefundPaymentRequest::partial(
+                "UPXXXXXXXX",
+                Money::from_minor_units(minor_units, currency),
+            )
+        };
+
+        let paid = payment(true, 0.0);
+        assert_eq!(
+            paid.refundable_amount().expect("OK"),
+            Money::from_minor_units(1999, "USD")
+        );
+        assert!(RefundPaymentRequest::full("UPXXXXXXXX")
+            .check_refundable(&paid)
+            .is_ok());
+        assert!(partial(1999, "USD").check_refundable(&paid).is_ok());
+        assert!(partial(2000, "USD").check_refundable(&paid).is_err());
+        assert!(partial(500, "EUR").check_refundable(&paid).is_err());
+        assert!(partial(0, "USD").check_refundable(&paid).is_err());
+
+        let partially_refunded = payment(true, 15.0);
+        assert_eq!(
+            partially_refunded.refundable_amount().expect("OK"),
+            Money::from_minor_units(499, "USD")
+        );
+        assert!(partial(499, "USD")
+            .check_refundable(&partially_refunded)
+            .is_ok());
+        assert!(partial(500, "USD")
+            .check_refundable(&partially_refunded)
+            .is_err());
+
+        let refunded_in_euros = payment_in(true, 15.0, "EUR");
+        assert!(refunded_in_euros.refundable_amount().is_err());
+        assert!(partial(100, "USD")
+            .check_refundable(&refunded_in_euros)
+            .is_err());
+        let unrefunded_in_euros = payment_in(true, 0.0, "EUR");
+        assert_eq!(
+            unrefunded_in_euros.refundable_amount().expect("OK"),
+            Money::from_minor_units(1999, "USD")
+        );
+
+        let not_refundable = payment(false, 0.0);
+        assert!(RefundPaymentRequest::full("UPXXXXXXXX")
+            .check_refundable(&not_refundable)
+            .is_err());
+    }
+
+    #[test]
+    fn test_payment_refund_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "UserPaymentRefundDTO": {
+                "status": "refunded",
+                "refund_external_tx_id": "re_XXXXXXXX",
+                "refund_downstream_external_tx_id": null
+            }
+        });
+
+        let result = serde_json::from_value::<crate::PianoResponse<PaymentRefundResult>>(json)
+            .expect("Failed to deserialize refund")
+            .value()
+            .expect("OK");
+        let refund = result.user_payment_refund_dto;
+        assert_eq!(refund.status(), &PaymentStatus::Refunded);
+        assert_eq!(refund.refund_external_tx_id(), Some("re_XXXXXXXX"));
+    }
+
+    #[test]
+    fn test_billing_address_deserialization() {
+        let json = serde_json::json!({
+            "address_pub_id": "ADXXXXXXXX",
+            "address_line1": "1 Main St",
+            "address_line2": null,
+            "country": { "country_code": "US", "pub_id": "CXXXXXXXXX" },
+            "region": { "region_name": "New York", "pub_id": "RXXXXXXXXX" },
+            "city": "New York",
+            "postal_code": "10001",
+            "type": "1",
+            "verified": "true"
+        });
+
+        let address: BillingAddress =
+            serde_json::from_value(json).expect("Failed to deserialize billing address");
+        assert_eq!(address.address_pub_id(), "ADXXXXXXXX");
+        assert_eq!(address.country().map(|c| c.country_code()), Some("US"));
+        assert_eq!(address.region().map(|r| r.region_name()), Some("New York"));
+        assert!(address.is_verified());
+    }
+}
@@ -1,9 +1,11 @@
+pub mod campaign;
 pub mod code;
+pub mod definition;
 mod schema;
 pub mod term;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoResponse};
+use crate::{Empty, PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
     /// Get a promotion by ID
@@ -163,14 +165,15 @@ impl PianoAPI {
 
     /// Generate promotion codes
     ///
-    /// Generates promotion codes for a given promotion.
+    /// Generates promotion codes for a given promotion and returns the promotion. The codes
+    /// are listed with [`PianoAPI::list_promotion_codes`] once they are generated.
     ///
     /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpromotion~2Fgenerate)
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn generate_promotion_codes(
         &self,
         req: &GeneratePromotionRequest<'_>,
-    ) -> Result<GeneratePromotionResult, crate::Error> {
+    ) -> Result<Promotion, crate::Error> {
         let result = self
             .client
             .post(format!("{}/publisher/promotion/generate", self.endpoint))
@@ -178,9 +181,60 @@ impl PianoAPI {
             .form(req)
             .send()
             .await?
-            .json::<PianoResponse<GeneratePromotionResult>>()
+            .json::<PianoResponse<PromotionResult>>()
             .await?
             .value()?;
-        Ok(result)
+        Ok(result.promotion)
+    }
+
+    /// Add a fixed amount discount to a promotion
+    ///
+    /// Adds the discount of a fixed amount promotion in a given currency.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fpromotion~2FfixedDiscount~2Fadd)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn add_promotion_fixed_discount(
+        &self,
+        params: &AddPromotionFixedDiscountRequest<'_>,
+    ) -> Result<PromotionFixedDiscount, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/promotion/fixedDiscount/add",
+                self.endpoint
+            ))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PromotionFixedDiscountResult>>()
+            .await?
+            .value()?;
+        Ok(result.promotion_fixed_discount)
+    }
+
+    /// Delete a fixed amount discount
+    ///
+    /// Deletes the discount of a fixed amount promotion in one currency.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fpromotion~2FfixedDiscount~2Fdelete)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_promotion_fixed_discount(
+        &self,
+        fixed_discount_id: &str,
+    ) -> Result<(), crate::Error> {
+        self.client
+            .post(format!(
+                "{}/publisher/promotion/fixedDiscount/delete",
+                self.endpoint
+            ))
+            .query(&[("aid", &self.app_id)])
+            .form(&[("fixed_discount_id", fixed_discount_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
     }
 }
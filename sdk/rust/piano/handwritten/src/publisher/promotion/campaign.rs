@@ -0,0 +1,598 @@
+//! Promotion campaigns with many promo codes.
+//!
+//! [`PianoAPI::create_promotion_campaign`] creates a promotion, adds its terms and generates
+//! the promo codes in chunks, then waits until every code is listed by
+//! [`PianoAPI::list_promotion_codes`]. If a step fails after the promotion is created, the
+//! error is a [`PromotionCampaignError`] holding the progress of the campaign, which
+//! [`PianoAPI::resume_promotion_campaign`] continues from. The codes are exported to CSV with
+//! [`PianoAPI::export_promotion_codes_csv`], and [`PianoAPI::promotion_code_summary`] reports
+//! how many of them have been redeemed on later runs.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{
+//!     publisher::promotion::{campaign::PromotionCampaignRequest, CreatePromotionRequest},
+//!     PianoAPI,
+//! };
+//!
+//! # async fn example() -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! let promotion = CreatePromotionRequest::new("Spring sale").with_promotion_code_prefix("SPRING");
+//! let request = PromotionCampaignRequest::new(promotion, 10_000).with_terms(&["TMXXXXXXXXXX"]);
+//! let promotion = api.create_promotion_campaign(&request).await?;
+//!
+//! let file = std::fs::File::create("spring-sale.csv")?;
+//! api.export_promotion_codes_csv(promotion.promotion_id(), file)
+//!     .await?;
+//!
+//! // On a later run
+//! let summary = api.promotion_code_summary(promotion.promotion_id()).await?;
+//! println!("{summary}");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    io::{ErrorKind, Write},
+    time::{Duration, Instant},
+};
+
+use futures_util::future;
+use serde::Serialize;
+
+use super::{
+    code::{CountPromotionCodeRequest, ListPromotionCodeRequest, PromotionCode},
+    term::AddPromotionTermRequest,
+    CreatePromotionRequest, GeneratePromotionRequest, Promotion, TermDependencyType,
+};
+use crate::{fetch_all_pages, PianoAPI};
+
+/// Promotion to create with its terms and promo codes
+#[derive(Debug)]
+pub struct PromotionCampaignRequest<'a> {
+    /// The promotion to create
+    pub promotion: CreatePromotionRequest<'a>,
+    /// The IDs of the terms the promotion applies to
+    pub term_ids: Vec<&'a str>,
+    /// The number of promo codes to generate
+    pub code_count: usize,
+    /// The number of promo codes generated per request, 1000 by default
+    pub chunk_size: usize,
+    /// The delay between two checks of the generated codes, 5 seconds by default
+    pub poll_interval: Duration,
+    /// The time to wait for the generated codes, 10 minutes by default
+    pub timeout: Duration,
+}
+
+impl<'a> PromotionCampaignRequest<'a> {
+    /// Create a campaign generating `code_count` promo codes
+    pub fn new(promotion: CreatePromotionRequest<'a>, code_count: usize) -> Self {
+        Self {
+            promotion,
+            term_ids: vec![],
+            code_count,
+            chunk_size: 1000,
+            poll_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    /// Apply the promotion to the given terms only
+    ///
+    /// Sets the term dependency type of the promotion to `include`.
+    pub fn with_terms(mut self, term_ids: &[&'a str]) -> Self {
+        self.term_ids = term_ids.to_vec();
+        self.promotion = self
+            .promotion
+            .with_term_dependency_type(TermDependencyType::Include);
+        self
+    }
+
+    /// Set the number of promo codes generated per request
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Set the delay between two checks of the generated codes
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the time to wait for the generated codes
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Progress of a promotion campaign
+///
+/// The terms of the request are added in order, so the first `terms_added` terms are on the
+/// promotion.
+#[derive(Debug, Clone)]
+pub struct PromotionCampaignProgress {
+    /// The created promotion
+    pub promotion: Promotion,
+    /// The number of terms of the request added to the promotion
+    pub terms_added: usize,
+    /// The number of promo codes requested from Piano
+    pub codes_generated: usize,
+}
+
+impl PromotionCampaignProgress {
+    /// Start a campaign for a created promotion
+    pub fn new(promotion: Promotion) -> Self {
+        Self {
+            promotion,
+            terms_added: 0,
+            codes_generated: 0,
+        }
+    }
+}
+
+/// Error of a promotion campaign which failed after its promotion was created
+///
+/// The promotion is kept. Pass the progress to [`PianoAPI::resume_promotion_campaign`] to
+/// finish the campaign, or delete the promotion.
+#[derive(Debug)]
+pub struct PromotionCampaignError {
+    /// What was done before the failure
+    pub progress: PromotionCampaignProgress,
+    /// The error of the failed step
+    pub error: crate::Error,
+}
+
+impl fmt::Display for PromotionCampaignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "promotion campaign {} failed after adding {} terms and generating {} promo codes: {}",
+            self.progress.promotion.promotion_id(),
+            self.progress.terms_added,
+            self.progress.codes_generated,
+            self.error
+        )
+    }
+}
+
+impl std::error::Error for PromotionCampaignError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+/// Redemption counts of the promo codes of a promotion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PromotionCodeSummary {
+    /// The number of promo codes
+    pub total: usize,
+    /// The number of redeemed promo codes
+    pub redeemed: usize,
+    /// The number of promo codes which can still be redeemed
+    pub unused: usize,
+}
+
+impl PromotionCodeSummary {
+    /// Get the number of promo codes reserved for a free trial or a delayed payment
+    pub fn reserved(&self) -> usize {
+        self.total.saturating_sub(self.redeemed + self.unused)
+    }
+
+    /// Get the share of redeemed promo codes, between 0 and 1
+    pub fn redemption_rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.redeemed as f64 / self.total as f64
+        }
+    }
+}
+
+impl fmt::Display for PromotionCodeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} codes: {} redeemed, {} unused, {} reserved",
+            self.total,
+            self.redeemed,
+            self.unused,
+            self.reserved()
+        )
+    }
+}
+
+/// Row of a promo code CSV export
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PromotionCodeRow<'a> {
+    pub code: &'a str,
+    /// The state key, see [`PromotionCode::state_key`]
+    pub state: String,
+    pub assigned_email: Option<&'a str>,
+    pub claimed_date: Option<i64>,
+    pub claimed_by: Option<&'a str>,
+    pub promo_code_id: &'a str,
+}
+
+impl<'a> From<&'a PromotionCode> for PromotionCodeRow<'a> {
+    fn from(code: &'a PromotionCode) -> Self {
+        Self {
+            code: code.code(),
+            state: code.state_key(),
+            assigned_email: code.assigned_email(),
+            claimed_date: code.claimed_date(),
+            claimed_by: code.claimed_user().map(|user| user.email()),
+            promo_code_id: code.promotion_code_id(),
+        }
+    }
+}
+
+/// Write promo codes as CSV with a header row
+pub fn write_promotion_codes_csv<W: Write>(
+    codes: &[PromotionCode],
+    writer: W,
+) -> Result<(), crate::Error> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for code in codes {
+        writer.serialize(PromotionCodeRow::from(code))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Split `count` into chunks of at most `chunk_size`
+fn chunks(count: usize, chunk_size: usize) -> impl Iterator<Item = usize> {
+    let chunk_size = chunk_size.max(1);
+    (0..count)
+        .step_by(chunk_size)
+        .map(move |start| chunk_size.min(count - start))
+}
+
+impl PianoAPI {
+    /// Create a promotion with its terms and promo codes
+    ///
+    /// Creates the promotion, adds the terms, generates the promo codes in chunks and waits
+    /// until all of them are listed.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The promotion, its terms and the number of promo codes
+    ///
+    /// # Returns
+    ///
+    /// Returns the created promotion. Once the promotion is created, errors are
+    /// [`PromotionCampaignError`]s holding the progress of the campaign, including an
+    /// [`ErrorKind::TimedOut`] error if the codes are not listed within the timeout of the
+    /// request.
+    ///
+    /// ```rust,no_run
+    /// # use piano_handwritten_api::{publisher::promotion::campaign::*, PianoAPI};
+    /// # async fn example(api: &PianoAPI, req: &PromotionCampaignRequest<'_>) -> Result<(), piano_handwritten_api::Error> {
+    /// let promotion = match api.create_promotion_campaign(req).await {
+    ///     Err(error) => match error.downcast::<PromotionCampaignError>() {
+    ///         Ok(error) => api.resume_promotion_campaign(req, error.progress).await?,
+    ///         Err(error) => return Err(error),
+    ///     },
+    ///     promotion => promotion?,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_promotion_campaign(
+        &self,
+        req: &PromotionCampaignRequest<'_>,
+    ) -> Result<Promotion, crate::Error> {
+        let promotion = self.create_promotion(&req.promotion).await?;
+        let promotion = self
+            .resume_promotion_campaign(req, PromotionCampaignProgress::new(promotion))
+            .await?;
+        Ok(promotion)
+    }
+
+    /// Continue a promotion campaign from its progress
+    ///
+    /// Adds the terms after the first `terms_added`, generates the promo codes missing from
+    /// `codes_generated` and waits until all of them are listed. To resume a campaign whose
+    /// progress was lost, use the number of listed codes, see
+    /// [`PianoAPI::promotion_code_summary`].
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request the campaign was created with
+    /// * `progress` - The progress of the campaign, usually from a [`PromotionCampaignError`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn resume_promotion_campaign(
+        &self,
+        req: &PromotionCampaignRequest<'_>,
+        mut progress: PromotionCampaignProgress,
+    ) -> Result<Promotion, PromotionCampaignError> {
+        match self.run_promotion_campaign(req, &mut progress).await {
+            Ok(()) => Ok(progress.promotion),
+            Err(error) => Err(PromotionCampaignError { progress, error }),
+        }
+    }
+
+    async fn run_promotion_campaign(
+        &self,
+        req: &PromotionCampaignRequest<'_>,
+        progress: &mut PromotionCampaignProgress,
+    ) -> Result<(), crate::Error> {
+        let promotion_id = progress.promotion.promotion_id().to_string();
+        for term_id in req.term_ids.iter().skip(progress.terms_added) {
+            self.add_promotion_term(&AddPromotionTermRequest::new(&promotion_id, term_id))
+                .await?;
+            progress.terms_added += 1;
+        }
+        self.generate_chunks(
+            &promotion_id,
+            req.code_count.saturating_sub(progress.codes_generated),
+            req.chunk_size,
+            req.promotion.settings.promotion_code_prefix,
+            &mut progress.codes_generated,
+        )
+        .await?;
+        self.wait_for_promotion_codes(
+            &promotion_id,
+            req.code_count,
+            req.poll_interval,
+            req.timeout,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Generate promo codes with one request per chunk of at most `chunk_size` codes
+    ///
+    /// Chunks are generated one after the other. The codes may not be listed yet when this
+    /// method returns, see [`PianoAPI::wait_for_promotion_codes`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn generate_promotion_codes_in_chunks(
+        &self,
+        promotion_id: &str,
+        count: usize,
+        chunk_size: usize,
+        promotion_code_prefix: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        let mut generated = 0;
+        self.generate_chunks(
+            promotion_id,
+            count,
+            chunk_size,
+            promotion_code_prefix,
+            &mut generated,
+        )
+        .await
+    }
+
+    /// Generate promo codes in chunks, counting the generated codes as they are requested
+    async fn generate_chunks(
+        &self,
+        promotion_id: &str,
+        count: usize,
+        chunk_size: usize,
+        promotion_code_prefix: Option<&str>,
+        generated: &mut usize,
+    ) -> Result<(), crate::Error> {
+        for chunk in chunks(count, chunk_size) {
+            let amount = i32::try_from(chunk)?;
+            let mut req = GeneratePromotionRequest::new(promotion_id, amount);
+            req.promotion_code_prefix = promotion_code_prefix;
+            self.generate_promotion_codes(&req).await?;
+            *generated += chunk;
+        }
+        Ok(())
+    }
+
+    /// Wait until a promotion lists at least `expected` promo codes
+    ///
+    /// Polls [`PianoAPI::list_promotion_codes`] every `poll_interval` and returns the number
+    /// of listed codes. Returns an [`ErrorKind::TimedOut`] error if fewer codes are listed
+    /// after `timeout`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn wait_for_promotion_codes(
+        &self,
+        promotion_id: &str,
+        expected: usize,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<usize, crate::Error> {
+        let started = Instant::now();
+        loop {
+            let listed = self
+                .list_promotion_codes(
+                    &ListPromotionCodeRequest::new(promotion_id)
+                        .with_limit(1)
+                        .with_offset(0),
+                )
+                .await?
+                .total;
+            if listed >= expected {
+                return Ok(listed);
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(listed, expected, "promo codes are being generated");
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(Box::new(std::io::Error::new(
+                    ErrorKind::TimedOut,
+                    format!(
+                        "promotion {promotion_id} lists {listed} of {expected} promo codes after {timeout:?}"
+                    ),
+                )));
+            }
+            tokio::time::sleep(poll_interval.min(timeout - elapsed)).await;
+        }
+    }
+
+    /// List all the promo codes of a promotion
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_promotion_codes(
+        &self,
+        promotion_id: &str,
+    ) -> Result<Vec<PromotionCode>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListPromotionCodeRequest::new(promotion_id)
+                    .with_limit(100)
+                    .with_offset(offset)
+                    .with_order_by("create_date");
+                async move { self.list_promotion_codes(&params).await }
+            },
+            |page| page.promo_codes,
+        )
+        .await
+    }
+
+    /// Export all the promo codes of a promotion with their state as CSV
+    ///
+    /// See [`PromotionCodeRow`] for the columns.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of exported promo codes.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, writer)))]
+    pub async fn export_promotion_codes_csv<W: Write>(
+        &self,
+        promotion_id: &str,
+        writer: W,
+    ) -> Result<usize, crate::Error> {
+        let codes = self.list_all_promotion_codes(promotion_id).await?;
+        write_promotion_codes_csv(&codes, writer)?;
+        Ok(codes.len())
+    }
+
+    /// Count the redeemed and unused promo codes of a promotion
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn promotion_code_summary(
+        &self,
+        promotion_id: &str,
+    ) -> Result<PromotionCodeSummary, crate::Error> {
+        let (total, redeemed, unused) = future::try_join3(
+            self.count_promotion_codes(&CountPromotionCodeRequest::new(promotion_id)),
+            self.count_promotion_codes(
+                &CountPromotionCodeRequest::new(promotion_id).with_state("used"),
+            ),
+            self.count_promotion_codes(
+                &CountPromotionCodeRequest::new(promotion_id).with_state("active"),
+            ),
+        )
+        .await?;
+        Ok(PromotionCodeSummary {
+            total: total.max(0) as usize,
+            redeemed: redeemed.max(0) as usize,
+            unused: unused.max(0) as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_chunks() {
+        assert_eq!(
+            chunks(2500, 1000).collect::<Vec<_>>(),
+            vec![1000, 1000, 500]
+        );
+        assert_eq!(chunks(1000, 1000).collect::<Vec<_>>(), vec![1000]);
+        assert_eq!(chunks(0, 1000).count(), 0);
+        assert_eq!(chunks(3, 0).collect::<Vec<_>>(), vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn test_campaign_request() {
+        let request = PromotionCampaignRequest::new(CreatePromotionRequest::new("Spring"), 10)
+            .with_terms(&["TM1", "TM2"])
+            .with_chunk_size(0);
+        assert_eq!(request.term_ids, vec!["TM1", "TM2"]);
+        assert_eq!(
+            request.promotion.settings.term_dependency_type,
+            Some(TermDependencyType::Include)
+        );
+        assert_eq!(request.chunk_size, 1);
+    }
+
+    #[test]
+    fn test_campaign_error() {
+        let promotion = serde_json::from_value::<Promotion>(json!({
+            "promotion_id": "PR1",
+            "name": "Spring",
+            "create_date": 1700000000
+        }))
+        .expect("OK");
+        let mut progress = PromotionCampaignProgress::new(promotion);
+        progress.terms_added = 2;
+        progress.codes_generated = 1000;
+        let error: crate::Error = Box::new(PromotionCampaignError {
+            progress,
+            error: "rate limited".into(),
+        });
+        assert_eq!(
+            error.to_string(),
+            "promotion campaign PR1 failed after adding 2 terms and generating 1000 promo codes: rate limited"
+        );
+        let error = error
+            .downcast::<PromotionCampaignError>()
+            .expect("campaign error");
+        assert_eq!(error.progress.promotion.promotion_id(), "PR1");
+        assert_eq!(error.progress.codes_generated, 1000);
+    }
+
+    #[test]
+    fn test_write_promotion_codes_csv() {
+        let codes: Vec<PromotionCode> = serde_json::from_value(json!([
+            {
+                "promo_code_id": "PC1",
+                "promotion_id": "P1",
+                "code": "SPRING-1",
+                "state": "Used",
+                "state_value": "used",
+                "create_date": 1700000000,
+                "claimed_date": 1700000100,
+                "claimed_user": { "uid": "U1", "email": "jane@example.com", "create_date": 1700000000 }
+            },
+            {
+                "promo_code_id": "PC2",
+                "promotion_id": "P1",
+                "code": "SPRING-2",
+                "state": "Reserved for free trial",
+                "create_date": 1700000000
+            }
+        ]))
+        .expect("OK");
+        assert!(codes[0].is_used());
+        assert_eq!(codes[1].state_key(), "reserved_for_free_trial");
+
+        let mut csv = vec![];
+        write_promotion_codes_csv(&codes, &mut csv).expect("OK");
+        assert_eq!(
+            String::from_utf8(csv).expect("OK"),
+            "code,state,assigned_email,claimed_date,claimed_by,promo_code_id\n\
+             SPRING-1,used,,1700000100,jane@example.com,PC1\n\
+             SPRING-2,reserved_for_free_trial,,,,PC2\n"
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = PromotionCodeSummary {
+            total: 10,
+            redeemed: 4,
+            unused: 5,
+        };
+        assert_eq!(summary.reserved(), 1);
+        assert_eq!(summary.redemption_rate(), 0.4);
+        assert_eq!(
+            summary.to_string(),
+            "10 codes: 4 redeemed, 5 unused, 1 reserved"
+        );
+    }
+}
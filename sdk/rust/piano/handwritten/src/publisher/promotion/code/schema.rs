@@ -179,11 +179,42 @@ impl PromotionCode {
         &self.code
     }
 
+    /// Get the email the code is assigned to
+    pub fn assigned_email(&self) -> Option<&str> {
+        self.assigned_email.as_deref()
+    }
+
+    /// Get the reservation date
+    pub fn reserve_date(&self) -> Option<i64> {
+        self.reserve_date
+    }
+
     /// Get the state
     pub fn state(&self) -> &str {
         &self.state
     }
 
+    /// Get the human readable state
+    pub fn state_value(&self) -> Option<&str> {
+        self.state_value.as_deref()
+    }
+
+    /// Get the state key (`active`, `used`, `reserved_for_free_trial` or
+    /// `reserved_for_delayed_payment`)
+    ///
+    /// Falls back to the lowercased state when the response has no state value.
+    pub fn state_key(&self) -> String {
+        match &self.state_value {
+            Some(state_value) => state_value.to_string(),
+            None => self.state.to_lowercase().replace(' ', "_"),
+        }
+    }
+
+    /// Check whether the code has been redeemed
+    pub fn is_used(&self) -> bool {
+        self.state_key() == "used"
+    }
+
     /// Get the creation date
     pub fn create_date(&self) -> i64 {
         self.create_date
@@ -203,6 +234,26 @@ impl PromotionCode {
     pub fn update_by(&self) -> Option<&str> {
         self.update_by.as_deref()
     }
+
+    /// Check if deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Get the date the code was claimed
+    pub fn claimed_date(&self) -> Option<i64> {
+        self.claimed_date
+    }
+
+    /// Get the original price at the time the code was last claimed
+    pub fn last_original_price(&self) -> Option<&str> {
+        self.last_original_price.as_deref()
+    }
+
+    /// Get the user who claimed the code
+    pub fn claimed_user(&self) -> Option<&User> {
+        self.claimed_user.as_ref()
+    }
 }
 
 /// Response wrapper for promotion code operations
@@ -0,0 +1,864 @@
+//! Typed promotion definitions.
+//!
+//! A [`PromotionDefinition`] describes the discount, validity dates, usage limits and term
+//! applicability of a promotion. It is validated before anything is sent to Piano, so that a
+//! mistake is reported with an [`std::io::ErrorKind::InvalidInput`] error explaining what is wrong
+//! instead of the generic validation message of the API.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{
+//!     publisher::promotion::definition::{
+//!         Discount, PromotionDefinition, PromotionValidity, TermApplicability, UsageLimits,
+//!     },
+//!     PianoAPI,
+//! };
+//!
+//! # async fn example() -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! let definition = PromotionDefinition::new("Black Friday", Discount::percentage(30.0))
+//!     .with_validity(PromotionValidity::between(1764288000, 1764633600))
+//!     .with_usage(UsageLimits::total(5000))
+//!     .with_terms(TermApplicability::exclude(&["TMXXXXXXXXXX"]))
+//!     .with_promotion_code_prefix("BF");
+//! let promotion = api.create_promotion_from_definition(&definition).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    term::{AddPromotionTermRequest, DeletePromotionTermRequest, ListPromotionTermRequest},
+    AddPromotionFixedDiscountRequest, CreatePromotionRequest, Promotion, PromotionDiscountType,
+    PromotionSettings, TermDependencyType, UpdatePromotionRequest,
+};
+use crate::{fetch_all_pages, invalid_input, PianoAPI, PianoPaginated, PianoResponse};
+
+/// Discount granted by a promotion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Discount {
+    /// A percentage of the price, greater than 0 and up to 100
+    Percentage { percent: f64 },
+    /// A fixed amount in a currency, such as `USD`
+    FixedAmount { amount: f64, currency: String },
+    /// The first billing periods for free
+    FreeTrial { billing_periods: u32 },
+}
+
+impl Discount {
+    pub fn percentage(percent: f64) -> Self {
+        Self::Percentage { percent }
+    }
+
+    pub fn fixed_amount(amount: f64, currency: &str) -> Self {
+        Self::FixedAmount {
+            amount,
+            currency: currency.to_string(),
+        }
+    }
+
+    pub fn free_trial(billing_periods: u32) -> Self {
+        Self::FreeTrial { billing_periods }
+    }
+
+    /// Check that the discount can be granted
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        match self {
+            Self::Percentage { percent } => {
+                if !percent.is_finite() || *percent <= 0.0 || *percent > 100.0 {
+                    return Err(invalid_input(format!(
+                        "a percentage discount must be greater than 0 and up to 100, got {percent}"
+                    )));
+                }
+            }
+            Self::FixedAmount { amount, currency } => {
+                if !amount.is_finite() || *amount <= 0.0 {
+                    return Err(invalid_input(format!(
+                        "a fixed amount discount must be greater than 0, got {amount}"
+                    )));
+                }
+                if currency.len() != 3 || !currency.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+                    return Err(invalid_input(format!(
+                        "{currency:?} is not an ISO 4217 currency code"
+                    )));
+                }
+            }
+            Self::FreeTrial { billing_periods } => {
+                if *billing_periods == 0 {
+                    return Err(invalid_input(
+                        "a free trial must last at least one billing period".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check whether the discount makes the checkout free
+    pub fn is_free(&self) -> bool {
+        match self {
+            Self::Percentage { percent } => *percent >= 100.0,
+            Self::FixedAmount { .. } => false,
+            Self::FreeTrial { .. } => true,
+        }
+    }
+
+    fn settings(&self) -> PromotionSettings<'static> {
+        match self {
+            Self::Percentage { percent } => PromotionSettings {
+                discount_type: Some(PromotionDiscountType::Percentage),
+                percentage_discount: Some(*percent),
+                ..Default::default()
+            },
+            Self::FixedAmount { .. } => PromotionSettings {
+                discount_type: Some(PromotionDiscountType::Fixed),
+                ..Default::default()
+            },
+            Self::FreeTrial { billing_periods } => PromotionSettings {
+                discount_type: Some(PromotionDiscountType::Percentage),
+                percentage_discount: Some(100.0),
+                apply_to_all_billing_periods: Some(false),
+                billing_period_limit: Some(*billing_periods),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Dates between which a promotion can be used
+///
+/// A missing start date makes the promotion usable immediately, and a missing end date
+/// keeps it usable until it is deleted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromotionValidity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<i64>,
+}
+
+impl PromotionValidity {
+    /// Usable from `start_date` until `end_date`
+    pub fn between(start_date: i64, end_date: i64) -> Self {
+        Self {
+            start_date: Some(start_date),
+            end_date: Some(end_date),
+        }
+    }
+
+    /// Usable from `start_date` on
+    pub fn starting(start_date: i64) -> Self {
+        Self {
+            start_date: Some(start_date),
+            end_date: None,
+        }
+    }
+
+    /// Usable until `end_date`
+    pub fn until(end_date: i64) -> Self {
+        Self {
+            start_date: None,
+            end_date: Some(end_date),
+        }
+    }
+
+    /// Check whether the promotion can be used at the given timestamp
+    pub fn contains(&self, timestamp: i64) -> bool {
+        self.start_date
+            .is_none_or(|start_date| start_date <= timestamp)
+            && self.end_date.is_none_or(|end_date| timestamp < end_date)
+    }
+
+    /// Check that the dates are positive and the start date is before the end date
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        for date in [self.start_date, self.end_date].into_iter().flatten() {
+            if date < 0 {
+                return Err(invalid_input(format!(
+                    "{date} is not a valid promotion date"
+                )));
+            }
+        }
+        if let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date) {
+            if start_date >= end_date {
+                return Err(invalid_input(format!(
+                    "the promotion ends ({end_date}) before it starts ({start_date})"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Number of times a promotion can be used
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageLimits {
+    /// The number of uses of the promotion, unlimited if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+    /// The number of uses of the promotion by a user, unlimited if not set
+    ///
+    /// Piano has no per-user limit setting: only a limit of one use, which is ensured by
+    /// distinct single-use promo codes, is accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_user: Option<u32>,
+}
+
+impl UsageLimits {
+    /// No limit
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// At most `total` uses of the promotion
+    pub fn total(total: u32) -> Self {
+        Self {
+            total: Some(total),
+            per_user: None,
+        }
+    }
+
+    /// Set the number of uses of the promotion by a user
+    pub fn with_per_user(mut self, per_user: u32) -> Self {
+        self.per_user = Some(per_user);
+        self
+    }
+
+    /// Check that the limits allow at least one use and can be set in Piano
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        if self.total == Some(0) {
+            return Err(invalid_input(
+                "a promotion must allow at least one use".to_string(),
+            ));
+        }
+        match self.per_user {
+            Some(0) => Err(invalid_input(
+                "a promotion must allow at least one use per user".to_string(),
+            )),
+            Some(per_user) if self.total.is_some_and(|total| per_user > total) => {
+                Err(invalid_input(format!(
+                    "the per-user limit ({per_user}) exceeds the total limit ({})",
+                    self.total.unwrap_or_default()
+                )))
+            }
+            Some(per_user) if per_user > 1 => Err(invalid_input(format!(
+                "Piano cannot limit a promotion to {per_user} uses per user, only to one use with single-use promo codes"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Terms a promotion applies to
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "term_ids", rename_all = "snake_case")]
+pub enum TermApplicability {
+    /// Every term of the application
+    #[default]
+    All,
+    /// The listed terms only
+    Include(Vec<String>),
+    /// Every term of the application but the listed ones
+    ///
+    /// Piano has no exclusion list: the promotion includes the other terms of the
+    /// application at the time it is created or updated.
+    Exclude(Vec<String>),
+}
+
+impl TermApplicability {
+    pub fn include(term_ids: &[&str]) -> Self {
+        Self::Include(term_ids.iter().map(ToString::to_string).collect())
+    }
+
+    pub fn exclude(term_ids: &[&str]) -> Self {
+        Self::Exclude(term_ids.iter().map(ToString::to_string).collect())
+    }
+
+    /// Check that the listed terms are not empty and listed once
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        let (term_ids, list) = match self {
+            Self::All => return Ok(()),
+            Self::Include(term_ids) => (term_ids, "included"),
+            Self::Exclude(term_ids) => (term_ids, "excluded"),
+        };
+        if term_ids.is_empty() {
+            return Err(invalid_input(format!("the list of {list} terms is empty")));
+        }
+        let mut seen = HashSet::new();
+        for term_id in term_ids {
+            if term_id.trim().is_empty() {
+                return Err(invalid_input(format!(
+                    "the list of {list} terms contains an empty term ID"
+                )));
+            }
+            if !seen.insert(term_id) {
+                return Err(invalid_input(format!("term {term_id} is {list} twice")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the IDs of the terms to add to the promotion, given the terms of the application
+    ///
+    /// Returns `None` when the promotion applies to every term.
+    pub fn resolve(&self, app_term_ids: &[String]) -> Option<Vec<String>> {
+        match self {
+            Self::All => None,
+            Self::Include(term_ids) => Some(term_ids.clone()),
+            Self::Exclude(term_ids) => Some(
+                app_term_ids
+                    .iter()
+                    .filter(|term_id| !term_ids.contains(term_id))
+                    .cloned()
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Promotion with a typed discount, validity, usage limits and term applicability
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromotionDefinition {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub discount: Discount,
+    #[serde(default)]
+    pub validity: PromotionValidity,
+    #[serde(default)]
+    pub usage: UsageLimits,
+    #[serde(default)]
+    pub terms: TermApplicability,
+    #[serde(default)]
+    pub new_customers_only: bool,
+    /// Never allow the value of checkout to be zero
+    #[serde(default)]
+    pub never_allow_zero: bool,
+    /// The code shared by every customer, instead of generated promo codes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_promotion_code: Option<String>,
+    /// The prefix of the generated promo codes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion_code_prefix: Option<String>,
+}
+
+impl PromotionDefinition {
+    /// Create a promotion usable without limit on every term
+    pub fn new(name: &str, discount: Discount) -> Self {
+        Self {
+            name: name.to_string(),
+            description: None,
+            discount,
+            validity: PromotionValidity::default(),
+            usage: UsageLimits::default(),
+            terms: TermApplicability::default(),
+            new_customers_only: false,
+            never_allow_zero: false,
+            fixed_promotion_code: None,
+            promotion_code_prefix: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn with_validity(mut self, validity: PromotionValidity) -> Self {
+        self.validity = validity;
+        self
+    }
+
+    pub fn with_usage(mut self, usage: UsageLimits) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    pub fn with_terms(mut self, terms: TermApplicability) -> Self {
+        self.terms = terms;
+        self
+    }
+
+    pub fn with_new_customers_only(mut self, new_customers_only: bool) -> Self {
+        self.new_customers_only = new_customers_only;
+        self
+    }
+
+    pub fn with_never_allow_zero(mut self, never_allow_zero: bool) -> Self {
+        self.never_allow_zero = never_allow_zero;
+        self
+    }
+
+    pub fn with_fixed_promotion_code(mut self, fixed_promotion_code: &str) -> Self {
+        self.fixed_promotion_code = Some(fixed_promotion_code.to_string());
+        self
+    }
+
+    pub fn with_promotion_code_prefix(mut self, promotion_code_prefix: &str) -> Self {
+        self.promotion_code_prefix = Some(promotion_code_prefix.to_string());
+        self
+    }
+
+    /// Check the whole definition
+    ///
+    /// Returns an [`std::io::ErrorKind::InvalidInput`] error describing the first problem found.
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        if self.name.trim().is_empty() {
+            return Err(invalid_input("the promotion has no name".to_string()));
+        }
+        self.discount
+            .validate()
+            .and_then(|_| self.validity.validate())
+            .and_then(|_| self.usage.validate())
+            .and_then(|_| self.terms.validate())
+            .map_err(|error| invalid_input(format!("promotion {}: {error}", self.name)))?;
+        if self.never_allow_zero && self.discount.is_free() {
+            return Err(invalid_input(format!(
+                "promotion {}: a free discount cannot never allow a zero checkout",
+                self.name
+            )));
+        }
+        for (field, code) in [
+            ("fixed promotion code", &self.fixed_promotion_code),
+            ("promotion code prefix", &self.promotion_code_prefix),
+        ] {
+            if let Some(code) = code {
+                if code.is_empty() || !code.bytes().all(|byte| byte.is_ascii_graphic()) {
+                    return Err(invalid_input(format!(
+                        "promotion {}: {code:?} is not a valid {field}",
+                        self.name
+                    )));
+                }
+            }
+        }
+        if self.fixed_promotion_code.is_some() && self.promotion_code_prefix.is_some() {
+            return Err(invalid_input(format!(
+                "promotion {}: a promotion with a fixed code has no generated codes to prefix",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the settings of the promotion in Piano
+    pub fn settings(&self) -> PromotionSettings<'_> {
+        PromotionSettings {
+            start_date: self.validity.start_date,
+            end_date: self.validity.end_date,
+            unlimited_uses: Some(self.usage.total.is_none()),
+            uses_allowed: self.usage.total,
+            never_allow_zero: Some(self.never_allow_zero),
+            fixed_promotion_code: self.fixed_promotion_code.as_deref(),
+            promotion_code_prefix: self.promotion_code_prefix.as_deref(),
+            term_dependency_type: Some(match self.terms {
+                TermApplicability::All => TermDependencyType::All,
+                TermApplicability::Include(_) | TermApplicability::Exclude(_) => {
+                    TermDependencyType::Include
+                }
+            }),
+            ..self.discount.settings()
+        }
+    }
+
+    /// Validate the definition and build the request creating the promotion
+    ///
+    /// A fixed amount discount and the terms are added once the promotion is created, see
+    /// [`PianoAPI::create_promotion_from_definition`].
+    pub fn to_create_request(&self) -> Result<CreatePromotionRequest<'_>, crate::Error> {
+        self.validate()?;
+        let mut request = CreatePromotionRequest::new(&self.name)
+            .with_new_customers_only(self.new_customers_only)
+            .with_settings(self.settings());
+        request.description = self.description.as_deref();
+        Ok(request)
+    }
+
+    /// Validate the definition and build the request updating the given promotion
+    pub fn to_update_request<'a>(
+        &'a self,
+        promotion_id: &'a str,
+    ) -> Result<UpdatePromotionRequest<'a>, crate::Error> {
+        self.validate()?;
+        let mut request = UpdatePromotionRequest::new(promotion_id)
+            .with_name(&self.name)
+            .with_new_customers_only(self.new_customers_only)
+            .with_settings(self.settings());
+        request.description = self.description.as_deref();
+        Ok(request)
+    }
+}
+
+impl Promotion {
+    /// Get the discount of the promotion
+    ///
+    /// A 100% discount limited to the first billing periods is a free trial. A fixed amount
+    /// promotion returns its first currency. Returns `None` without a discount type.
+    pub fn discount(&self) -> Option<Discount> {
+        match self.discount_type()? {
+            PromotionDiscountType::Percentage => {
+                let percent = self.percentage_discount()?;
+                match self.billing_period_limit() {
+                    Some(billing_periods)
+                        if percent >= 100.0 && !self.apply_to_all_billing_periods() =>
+                    {
+                        Some(Discount::free_trial(billing_periods))
+                    }
+                    _ => Some(Discount::percentage(percent)),
+                }
+            }
+            PromotionDiscountType::Fixed => self
+                .fixed_discounts()
+                .first()
+                .map(|discount| Discount::fixed_amount(discount.amount_value, &discount.currency)),
+        }
+    }
+}
+
+/// Term of the application
+#[derive(Debug, Deserialize, Clone)]
+struct AppTerm {
+    term_id: String,
+}
+
+/// Response for application term list operations
+#[derive(Debug, Deserialize, Clone)]
+struct AppTermListResult {
+    #[serde(alias = "Term")]
+    terms: Vec<AppTerm>,
+}
+
+impl PianoAPI {
+    /// Validate a promotion definition and create the promotion
+    ///
+    /// Creates the promotion, adds its fixed amount discount and the terms it applies to.
+    /// Terms excluded by the definition are resolved against the terms of the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - The promotion to create
+    ///
+    /// # Returns
+    ///
+    /// Returns the created promotion, or an [`std::io::ErrorKind::InvalidInput`] error without
+    /// sending any request if the definition is invalid.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_promotion_from_definition(
+        &self,
+        definition: &PromotionDefinition,
+    ) -> Result<Promotion, crate::Error> {
+        let request = definition.to_create_request()?;
+        let term_ids = self.resolve_promotion_terms(definition).await?;
+        let promotion = self.create_promotion(&request).await?;
+        let promotion_id = promotion.promotion_id();
+        if let Discount::FixedAmount { amount, currency } = &definition.discount {
+            let currency = currency.to_uppercase();
+            self.add_promotion_fixed_discount(&AddPromotionFixedDiscountRequest::new(
+                promotion_id,
+                *amount,
+                &currency,
+            ))
+            .await?;
+        }
+        for term_id in term_ids.iter().flatten() {
+            self.add_promotion_term(&AddPromotionTermRequest::new(promotion_id, term_id))
+                .await?;
+        }
+        Ok(self.get_promotion(promotion_id).await?.unwrap_or(promotion))
+    }
+
+    /// Validate a promotion definition and update the promotion to match it
+    ///
+    /// Updates the promotion, replaces its fixed amount discounts and adds or deletes terms
+    /// so that the promotion applies to the terms of the definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `promotion_id` - The promotion ID
+    /// * `definition` - The expected promotion
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated promotion, or an [`std::io::ErrorKind::InvalidInput`] error without
+    /// sending any request if the definition is invalid.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_promotion_from_definition(
+        &self,
+        promotion_id: &str,
+        definition: &PromotionDefinition,
+    ) -> Result<Promotion, crate::Error> {
+        let request = definition.to_update_request(promotion_id)?;
+        let term_ids = self.resolve_promotion_terms(definition).await?;
+        let promotion = self.update_promotion(&request).await?;
+
+        let expected_discount = match &definition.discount {
+            Discount::FixedAmount { amount, currency } => Some((*amount, currency.to_uppercase())),
+            _ => None,
+        };
+        let mut has_expected_discount = false;
+        for discount in promotion.fixed_discounts() {
+            let expected = expected_discount
+                .as_ref()
+                .is_some_and(|(amount, currency)| {
+                    discount.amount_value == *amount
+                        && discount.currency.eq_ignore_ascii_case(currency)
+                });
+            if expected && !has_expected_discount {
+                has_expected_discount = true;
+            } else {
+                self.delete_promotion_fixed_discount(&discount.fixed_discount_id)
+                    .await?;
+            }
+        }
+        if let Some((amount, currency)) = expected_discount.filter(|_| !has_expected_discount) {
+            self.add_promotion_fixed_discount(&AddPromotionFixedDiscountRequest::new(
+                promotion_id,
+                amount,
+                &currency,
+            ))
+            .await?;
+        }
+
+        let term_ids = term_ids.unwrap_or_default();
+        let current_term_ids = self.list_all_promotion_term_ids(promotion_id).await?;
+        for term_id in term_ids.iter().filter(|id| !current_term_ids.contains(id)) {
+            self.add_promotion_term(&AddPromotionTermRequest::new(promotion_id, term_id))
+                .await?;
+        }
+        for term_id in current_term_ids.iter().filter(|id| !term_ids.contains(id)) {
+            self.delete_promotion_term(&DeletePromotionTermRequest::new(promotion_id, term_id))
+                .await?;
+        }
+        Ok(self.get_promotion(promotion_id).await?.unwrap_or(promotion))
+    }
+
+    async fn resolve_promotion_terms(
+        &self,
+        definition: &PromotionDefinition,
+    ) -> Result<Option<Vec<String>>, crate::Error> {
+        let app_term_ids = match definition.terms {
+            TermApplicability::Exclude(_) => self.list_all_app_term_ids().await?,
+            _ => vec![],
+        };
+        let term_ids = definition.terms.resolve(&app_term_ids);
+        if term_ids.as_ref().is_some_and(Vec::is_empty) {
+            return Err(invalid_input(format!(
+                "promotion {}: every term of the application is excluded",
+                definition.name
+            )));
+        }
+        Ok(term_ids)
+    }
+
+    async fn list_all_app_term_ids(&self) -> Result<Vec<String>, crate::Error> {
+        fetch_all_pages(
+            |offset| async move {
+                self.client
+                    .get(format!("{}/publisher/term/list", self.endpoint))
+                    .query(&[("aid", &self.app_id)])
+                    .query(&[("offset", offset), ("limit", 100)])
+                    .send()
+                    .await?
+                    .json::<PianoResponse<PianoPaginated<AppTermListResult>>>()
+                    .await?
+                    .value()
+            },
+            |page| page.terms.into_iter().map(|term| term.term_id),
+        )
+        .await
+    }
+
+    async fn list_all_promotion_term_ids(
+        &self,
+        promotion_id: &str,
+    ) -> Result<Vec<String>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListPromotionTermRequest::new(promotion_id)
+                    .with_limit(100)
+                    .with_offset(offset);
+                async move { self.list_promotion_terms(&params).await }
+            },
+            |page| {
+                page.terms
+                    .into_iter()
+                    .map(|term| term.term_id().to_string())
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let definition = PromotionDefinition::new("Spring", Discount::percentage(20.0))
+            .with_validity(PromotionValidity::between(100, 200))
+            .with_usage(UsageLimits::total(10).with_per_user(1))
+            .with_terms(TermApplicability::include(&["TM1", "TM2"]))
+            .with_promotion_code_prefix("SPRING");
+        assert!(definition.validate().is_ok());
+
+        for (invalid, message) in [
+            (
+                definition.clone().with_validity(PromotionValidity::between(200, 100)),
+                "promotion Spring: the promotion ends (100) before it starts (200)",
+            ),
+            (
+                PromotionDefinition::new("Spring", Discount::percentage(120.0)),
+                "promotion Spring: a percentage discount must be greater than 0 and up to 100, got 120",
+            ),
+            (
+                PromotionDefinition::new("Spring", Discount::fixed_amount(5.0, "dollars")),
+                "promotion Spring: \"dollars\" is not an ISO 4217 currency code",
+            ),
+            (
+                PromotionDefinition::new("Spring", Discount::free_trial(0)),
+                "promotion Spring: a free trial must last at least one billing period",
+            ),
+            (
+                definition.clone().with_usage(UsageLimits::total(0)),
+                "promotion Spring: a promotion must allow at least one use",
+            ),
+            (
+                definition.clone().with_usage(UsageLimits::total(1).with_per_user(2)),
+                "promotion Spring: the per-user limit (2) exceeds the total limit (1)",
+            ),
+            (
+                definition.clone().with_terms(TermApplicability::include(&["TM1", "TM1"])),
+                "promotion Spring: term TM1 is included twice",
+            ),
+            (
+                definition.clone().with_terms(TermApplicability::Exclude(vec![])),
+                "promotion Spring: the list of excluded terms is empty",
+            ),
+            (
+                definition.clone().with_fixed_promotion_code("SPRING24"),
+                "promotion Spring: a promotion with a fixed code has no generated codes to prefix",
+            ),
+            (
+                PromotionDefinition::new("Trial", Discount::free_trial(1)).with_never_allow_zero(true),
+                "promotion Trial: a free discount cannot never allow a zero checkout",
+            ),
+        ] {
+            assert_eq!(
+                invalid.validate().expect_err("invalid").to_string(),
+                message
+            );
+            assert!(invalid.to_create_request().is_err());
+        }
+    }
+
+    #[test]
+    fn test_to_create_request() {
+        let definition = PromotionDefinition::new("Trial", Discount::free_trial(2))
+            .with_validity(PromotionValidity::starting(100))
+            .with_usage(UsageLimits::total(50))
+            .with_terms(TermApplicability::exclude(&["TM1"]))
+            .with_new_customers_only(true);
+        let request = definition.to_create_request().expect("OK");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "name": "Trial",
+                "new_customers_only": true,
+                "start_date": 100,
+                "discount_type": "percentage",
+                "percentage_discount": 100.0,
+                "unlimited_uses": false,
+                "uses_allowed": 50,
+                "never_allow_zero": false,
+                "term_dependency_type": "include",
+                "apply_to_all_billing_periods": false,
+                "billing_period_limit": 2
+            })
+        );
+
+        let definition = PromotionDefinition::new("Fixed", Discount::fixed_amount(5.0, "usd"));
+        let request = definition.to_update_request("PR1").expect("OK");
+        assert_eq!(request.name, Some("Fixed"));
+        assert_eq!(
+            request.settings.discount_type,
+            Some(PromotionDiscountType::Fixed)
+        );
+        assert_eq!(request.settings.unlimited_uses, Some(true));
+        assert_eq!(
+            request.settings.term_dependency_type,
+            Some(TermDependencyType::All)
+        );
+    }
+
+    #[test]
+    fn test_resolve_terms_and_validity() {
+        let app_term_ids = vec!["TM1".to_string(), "TM2".to_string(), "TM3".to_string()];
+        assert_eq!(TermApplicability::All.resolve(&app_term_ids), None);
+        assert_eq!(
+            TermApplicability::exclude(&["TM2"]).resolve(&app_term_ids),
+            Some(vec!["TM1".to_string(), "TM3".to_string()])
+        );
+        assert_eq!(
+            TermApplicability::include(&["TM9"]).resolve(&app_term_ids),
+            Some(vec!["TM9".to_string()])
+        );
+
+        let validity = PromotionValidity::between(100, 200);
+        assert!(!validity.contains(99));
+        assert!(validity.contains(100));
+        assert!(!validity.contains(200));
+        assert!(PromotionValidity::default().contains(0));
+    }
+
+    #[test]
+    fn test_promotion_discount() {
+        let promotion = |value: serde_json::Value| {
+            let mut promotion = json!({
+                "promotion_id": "PR1",
+                "name": "Promotion",
+                "create_date": 1700000000
+            });
+            promotion
+                .as_object_mut()
+                .expect("object")
+                .extend(value.as_object().expect("object").clone());
+            serde_json::from_value::<Promotion>(promotion).expect("OK")
+        };
+        assert_eq!(
+            promotion(json!({ "discount_type": "percentage", "percentage_discount": 25.0, "apply_to_all_billing_periods": true, "billing_period_limit": 1 })).discount(),
+            Some(Discount::percentage(25.0))
+        );
+        assert_eq!(
+            promotion(json!({ "discount_type": "percentage", "percentage_discount": 100.0, "billing_period_limit": 3 })).discount(),
+            Some(Discount::free_trial(3))
+        );
+        assert_eq!(
+            promotion(json!({
+                "discount_type": "fixed",
+                "fixed_discount_list": [{ "fixed_discount_id": "FD1", "currency": "EUR", "amount": "€5.00", "amount_value": 5.0 }]
+            }))
+            .discount(),
+            Some(Discount::fixed_amount(5.0, "EUR"))
+        );
+        assert_eq!(promotion(json!({})).discount(), None);
+
+        let definition: PromotionDefinition = serde_json::from_value(json!({
+            "name": "Spring",
+            "discount": { "kind": "fixed_amount", "amount": 5.0, "currency": "USD" },
+            "terms": { "kind": "include", "term_ids": ["TM1"] }
+        }))
+        .expect("OK");
+        assert_eq!(
+            definition,
+            PromotionDefinition::new("Spring", Discount::fixed_amount(5.0, "USD"))
+                .with_terms(TermApplicability::include(&["TM1"]))
+        );
+    }
+}
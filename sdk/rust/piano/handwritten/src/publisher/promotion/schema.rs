@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 /// Request to create a promotion
+///
+/// See [`PromotionDefinition`](super::definition::PromotionDefinition) for a typed and
+/// validated promotion.
 #[derive(Debug, Serialize)]
 pub struct CreatePromotionRequest<'a> {
     /// The promotion name
@@ -8,6 +11,11 @@ pub struct CreatePromotionRequest<'a> {
     /// The promotion description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+    /// Whether the promotion allows new customers only
+    pub new_customers_only: bool,
+    /// The discount, validity, usage and term settings
+    #[serde(flatten)]
+    pub settings: PromotionSettings<'a>,
 }
 
 impl<'a> CreatePromotionRequest<'a> {
@@ -16,6 +24,8 @@ impl<'a> CreatePromotionRequest<'a> {
         Self {
             name,
             description: None,
+            new_customers_only: false,
+            settings: PromotionSettings::default(),
         }
     }
 
@@ -24,9 +34,35 @@ impl<'a> CreatePromotionRequest<'a> {
         self.description = Some(description);
         self
     }
+
+    /// Allow new customers only
+    pub fn with_new_customers_only(mut self, new_customers_only: bool) -> Self {
+        self.new_customers_only = new_customers_only;
+        self
+    }
+
+    /// Set the prefix of the generated codes
+    pub fn with_promotion_code_prefix(mut self, promotion_code_prefix: &'a str) -> Self {
+        self.settings.promotion_code_prefix = Some(promotion_code_prefix);
+        self
+    }
+
+    /// Set the type of dependency to terms
+    pub fn with_term_dependency_type(mut self, term_dependency_type: TermDependencyType) -> Self {
+        self.settings.term_dependency_type = Some(term_dependency_type);
+        self
+    }
+
+    /// Set the discount, validity, usage and term settings
+    pub fn with_settings(mut self, settings: PromotionSettings<'a>) -> Self {
+        self.settings = settings;
+        self
+    }
 }
 
 /// Request to update a promotion
+///
+/// Piano requires the name and the discount type of the promotion on update.
 #[derive(Debug, Serialize)]
 pub struct UpdatePromotionRequest<'a> {
     /// The promotion ID
@@ -37,6 +73,12 @@ pub struct UpdatePromotionRequest<'a> {
     /// The promotion description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+    /// Whether the promotion allows new customers only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_customers_only: Option<bool>,
+    /// The discount, validity, usage and term settings
+    #[serde(flatten)]
+    pub settings: PromotionSettings<'a>,
 }
 
 impl<'a> UpdatePromotionRequest<'a> {
@@ -46,6 +88,8 @@ impl<'a> UpdatePromotionRequest<'a> {
             promotion_id,
             name: None,
             description: None,
+            new_customers_only: None,
+            settings: PromotionSettings::default(),
         }
     }
 
@@ -60,6 +104,86 @@ impl<'a> UpdatePromotionRequest<'a> {
         self.description = Some(description);
         self
     }
+
+    /// Allow new customers only
+    pub fn with_new_customers_only(mut self, new_customers_only: bool) -> Self {
+        self.new_customers_only = Some(new_customers_only);
+        self
+    }
+
+    /// Set the discount, validity, usage and term settings
+    pub fn with_settings(mut self, settings: PromotionSettings<'a>) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+/// Discount, validity, usage and term settings of a promotion
+///
+/// The settings which are not set are left to Piano defaults on creation and unchanged on
+/// update. Fixed amount discounts are set per currency with
+/// [`PianoAPI::add_promotion_fixed_discount`](crate::PianoAPI::add_promotion_fixed_discount).
+#[derive(Debug, Serialize, Default, Clone, PartialEq)]
+pub struct PromotionSettings<'a> {
+    /// The start date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<i64>,
+    /// The end date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<i64>,
+    /// The discount type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_type: Option<PromotionDiscountType>,
+    /// The percentage discount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage_discount: Option<f64>,
+    /// Whether to allow unlimited uses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlimited_uses: Option<bool>,
+    /// The number of uses allowed by the promotion
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uses_allowed: Option<u32>,
+    /// Never allow the value of checkout to be zero
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub never_allow_zero: Option<bool>,
+    /// The fixed value for all the promotion codes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_promotion_code: Option<&'a str>,
+    /// The prefix for all the codes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code_prefix: Option<&'a str>,
+    /// The type of dependency to terms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term_dependency_type: Option<TermDependencyType>,
+    /// Whether to apply the discount to all billing periods or to the first ones only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_to_all_billing_periods: Option<bool>,
+    /// Whether the promotion can be applied on renewal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_be_applied_on_renewal: Option<bool>,
+    /// The number of billing periods the discount applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_period_limit: Option<u32>,
+}
+
+/// Discount type of a promotion
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PromotionDiscountType {
+    Fixed,
+    Percentage,
+}
+
+/// Terms a promotion applies to
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TermDependencyType {
+    /// All the terms of the application
+    All,
+    /// The terms added to the promotion
+    Include,
+    /// The terms unlocked by the promotion
+    Unlocked,
 }
 
 /// Request to delete a promotion
@@ -133,7 +257,11 @@ pub struct GeneratePromotionRequest<'a> {
     /// The promotion ID
     pub promotion_id: &'a str,
     /// Number of codes to generate
+    #[serde(rename = "amount")]
     pub count: i32,
+    /// The prefix for the generated codes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code_prefix: Option<&'a str>,
 }
 
 impl<'a> GeneratePromotionRequest<'a> {
@@ -142,8 +270,15 @@ impl<'a> GeneratePromotionRequest<'a> {
         Self {
             promotion_id,
             count,
+            promotion_code_prefix: None,
         }
     }
+
+    /// Set the prefix of the generated codes
+    pub fn with_promotion_code_prefix(mut self, promotion_code_prefix: &'a str) -> Self {
+        self.promotion_code_prefix = Some(promotion_code_prefix);
+        self
+    }
 }
 
 /// Promotion object
@@ -152,6 +287,29 @@ pub struct Promotion {
     promotion_id: String,
     name: String,
     description: Option<String>,
+    status: Option<String>,
+    #[serde(default)]
+    new_customers_only: bool,
+    discount_type: Option<PromotionDiscountType>,
+    percentage_discount: Option<f64>,
+    #[serde(default)]
+    fixed_discount_list: Vec<PromotionFixedDiscount>,
+    #[serde(default)]
+    unlimited_uses: bool,
+    uses_allowed: Option<u32>,
+    uses: Option<u32>,
+    #[serde(default)]
+    never_allow_zero: bool,
+    fixed_promotion_code: Option<String>,
+    promotion_code_prefix: Option<String>,
+    term_dependency_type: Option<TermDependencyType>,
+    start_date: Option<i64>,
+    end_date: Option<i64>,
+    #[serde(default)]
+    apply_to_all_billing_periods: bool,
+    #[serde(default)]
+    can_be_applied_on_renewal: bool,
+    billing_period_limit: Option<u32>,
     create_date: i64,
     create_by: Option<String>,
     update_date: Option<i64>,
@@ -174,6 +332,91 @@ impl Promotion {
         self.description.as_deref()
     }
 
+    /// Get the status (`active`, `expired` or `new`)
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Check if the promotion allows new customers only
+    pub fn new_customers_only(&self) -> bool {
+        self.new_customers_only
+    }
+
+    /// Get the discount type
+    pub fn discount_type(&self) -> Option<PromotionDiscountType> {
+        self.discount_type
+    }
+
+    /// Get the percentage discount
+    pub fn percentage_discount(&self) -> Option<f64> {
+        self.percentage_discount
+    }
+
+    /// Get the fixed amount discounts, one per currency
+    pub fn fixed_discounts(&self) -> &[PromotionFixedDiscount] {
+        &self.fixed_discount_list
+    }
+
+    /// Check if the promotion can be used an unlimited number of times
+    pub fn unlimited_uses(&self) -> bool {
+        self.unlimited_uses
+    }
+
+    /// Get the number of uses allowed
+    pub fn uses_allowed(&self) -> Option<u32> {
+        self.uses_allowed
+    }
+
+    /// Get the number of times the promotion has been used
+    pub fn uses(&self) -> Option<u32> {
+        self.uses
+    }
+
+    /// Check if the value of checkout can never be zero
+    pub fn never_allow_zero(&self) -> bool {
+        self.never_allow_zero
+    }
+
+    /// Get the fixed value of all the promotion codes
+    pub fn fixed_promotion_code(&self) -> Option<&str> {
+        self.fixed_promotion_code.as_deref()
+    }
+
+    /// Get the prefix of the promotion codes
+    pub fn promotion_code_prefix(&self) -> Option<&str> {
+        self.promotion_code_prefix.as_deref()
+    }
+
+    /// Get the type of dependency to terms
+    pub fn term_dependency_type(&self) -> Option<TermDependencyType> {
+        self.term_dependency_type
+    }
+
+    /// Get the start date
+    pub fn start_date(&self) -> Option<i64> {
+        self.start_date
+    }
+
+    /// Get the end date
+    pub fn end_date(&self) -> Option<i64> {
+        self.end_date
+    }
+
+    /// Check if the discount applies to all billing periods
+    pub fn apply_to_all_billing_periods(&self) -> bool {
+        self.apply_to_all_billing_periods
+    }
+
+    /// Check if the promotion can be applied on renewal
+    pub fn can_be_applied_on_renewal(&self) -> bool {
+        self.can_be_applied_on_renewal
+    }
+
+    /// Get the number of billing periods the discount applies to
+    pub fn billing_period_limit(&self) -> Option<u32> {
+        self.billing_period_limit
+    }
+
     /// Get the creation date
     pub fn create_date(&self) -> i64 {
         self.create_date
@@ -195,6 +438,45 @@ impl Promotion {
     }
 }
 
+/// Fixed amount discount of a promotion in one currency
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PromotionFixedDiscount {
+    pub fixed_discount_id: String,
+    pub currency: String,
+    /// The formatted amount
+    pub amount: String,
+    pub amount_value: f64,
+}
+
+/// Request to add a fixed amount discount to a promotion
+#[derive(Debug, Serialize)]
+pub struct AddPromotionFixedDiscountRequest<'a> {
+    /// The promotion ID
+    pub promotion_id: &'a str,
+    /// The discount amount
+    pub amount: f64,
+    /// The currency of the discount
+    pub currency: &'a str,
+}
+
+impl<'a> AddPromotionFixedDiscountRequest<'a> {
+    /// Create a new add fixed discount request
+    pub fn new(promotion_id: &'a str, amount: f64, currency: &'a str) -> Self {
+        Self {
+            promotion_id,
+            amount,
+            currency,
+        }
+    }
+}
+
+/// Response wrapper for fixed discount operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct PromotionFixedDiscountResult {
+    #[serde(alias = "PromotionFixedDiscount")]
+    pub promotion_fixed_discount: PromotionFixedDiscount,
+}
+
 /// Response wrapper for promotion operations
 #[derive(Debug, Deserialize, Clone)]
 pub(super) struct PromotionResult {
@@ -221,12 +503,6 @@ pub(super) struct PromotionExistsResult {
     pub exists: bool,
 }
 
-/// Response for generate promotion operations
-#[derive(Debug, Deserialize, Clone)]
-pub struct GeneratePromotionResult {
-    pub codes: Vec<String>,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,6 +522,17 @@ mod tests {
         let request = GeneratePromotionRequest::new("promo123", 100);
         assert_eq!(request.promotion_id, "promo123");
         assert_eq!(request.count, 100);
+
+        let request =
+            GeneratePromotionRequest::new("promo123", 100).with_promotion_code_prefix("SPRING");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "promotion_id": "promo123",
+                "amount": 100,
+                "promotion_code_prefix": "SPRING"
+            })
+        );
     }
 
     #[test]
@@ -304,6 +591,16 @@ mod tests {
             PianoResponse::Succeed(data) => {
                 assert_eq!(data.promotion.name(), "***MASKED***");
                 assert_eq!(data.promotion.promotion_id(), "***MASKED***");
+                assert_eq!(
+                    data.promotion.discount_type(),
+                    Some(PromotionDiscountType::Percentage)
+                );
+                assert_eq!(data.promotion.percentage_discount(), Some(100.0));
+                assert_eq!(data.promotion.uses_allowed(), Some(1000));
+                assert_eq!(
+                    data.promotion.term_dependency_type(),
+                    Some(TermDependencyType::All)
+                );
             }
             PianoResponse::Failure { code, message, .. } => {
                 panic!("Expected success but got failure: {} - {}", code, message);
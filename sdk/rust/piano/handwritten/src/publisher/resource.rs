@@ -1,9 +1,10 @@
 pub mod bundle;
 mod schema;
+pub mod sync;
 pub mod tag;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoResponse};
+use crate::{Empty, PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
     /// Get a resource by ID
@@ -142,12 +143,16 @@ impl PianoAPI {
         &self,
         req: &AttachResourceRequest<'a>,
     ) -> Result<(), crate::Error> {
-        self.client
+        let _ = self
+            .client
             .get(format!("{}/publisher/resource/attach", self.endpoint))
             .query(&[("aid", &self.app_id)])
             .query(req)
             .send()
-            .await?;
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
         Ok(())
     }
 
@@ -161,12 +166,16 @@ impl PianoAPI {
         &self,
         req: &DetachResourceRequest<'_>,
     ) -> Result<(), crate::Error> {
-        self.client
+        let _ = self
+            .client
             .get(format!("{}/publisher/resource/detach", self.endpoint))
             .query(&[("aid", &self.app_id)])
             .query(req)
             .send()
-            .await?;
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
         Ok(())
     }
 
@@ -82,11 +82,36 @@ impl BundleMember {
         &self.rid
     }
 
+    /// Get the application ID
+    pub fn aid(&self) -> &str {
+        &self.aid
+    }
+
     /// Get the name
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// Get the description
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Get the image URL
+    pub fn image_url(&self) -> Option<&str> {
+        self.image_url.as_deref()
+    }
+
+    /// Get the resource URL
+    pub fn resource_url(&self) -> Option<&str> {
+        self.resource_url.as_deref()
+    }
+
+    /// Get the external ID
+    pub fn external_id(&self) -> Option<&str> {
+        self.external_id.as_deref()
+    }
+
     /// Get the resource type
     pub fn resource_type(&self) -> &str {
         &self.resource_type
@@ -101,6 +126,26 @@ impl BundleMember {
     pub fn is_deleted(&self) -> bool {
         self.deleted
     }
+
+    /// Get the creation date
+    pub fn create_date(&self) -> i64 {
+        self.create_date
+    }
+
+    /// Get the update date
+    pub fn update_date(&self) -> Option<i64> {
+        self.update_date
+    }
+
+    /// Get the publish date
+    pub fn publish_date(&self) -> Option<i64> {
+        self.publish_date
+    }
+
+    /// Check if this is a Facebook Instant Articles resource
+    pub fn is_fbia_resource(&self) -> bool {
+        self.is_fbia_resource
+    }
 }
 
 /// Response for bundle member list operations
@@ -7,8 +7,12 @@ pub struct CreateResourceRequest<'a> {
     pub rid: &'a str,
     /// The resource name
     pub name: &'a str,
-    /// The resource type
+    /// The resource type (`standard`, `bundle` or `print`)
+    #[serde(rename = "type")]
     pub resource_type: &'a str,
+    /// The bundle type (`fixed`, `tagged` or `fixed_v2`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_type: Option<&'a str>,
     /// The resource description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
@@ -36,6 +40,7 @@ impl<'a> CreateResourceRequest<'a> {
             rid,
             name,
             resource_type,
+            bundle_type: None,
             description: None,
             resource_url: None,
             image_url: None,
@@ -45,6 +50,12 @@ impl<'a> CreateResourceRequest<'a> {
         }
     }
 
+    /// Set the bundle type of a bundle resource
+    pub fn with_bundle_type(mut self, bundle_type: &'a str) -> Self {
+        self.bundle_type = Some(bundle_type);
+        self
+    }
+
     /// Set the resource description
     pub fn with_description(mut self, description: &'a str) -> Self {
         self.description = Some(description);
@@ -198,7 +209,7 @@ pub struct ListResourceRequest<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_direction: Option<&'a str>,
     /// Filter by resource type
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
     pub resource_type: Option<&'a str>,
     /// Filter by disabled status
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,960 @@
+//! Synchronization of Piano resources with a declarative catalog.
+//!
+//! A [`ResourceCatalog`] lists the resources expected in Piano with their tags, and the
+//! members of fixed bundles. It can be written by hand or generated by a CMS, then loaded
+//! from JSON or, with the `toml` feature, from TOML.
+//!
+//! A [`ResourceSyncPlan`] lists the calls needed to make Piano match the catalog:
+//! resources to create or update, tags to attach or detach and bundle members to attach or
+//! detach. Resources are never deleted. Plans can be printed or serialized for a dry run,
+//! then applied with [`PianoAPI::apply_resource_sync_plan`], which reports the outcome of
+//! every change.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{publisher::resource::sync::*, PianoAPI, SyncOptions};
+//!
+//! # async fn example() -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//!
+//! let catalog = ResourceCatalog::load("catalog.json")?;
+//! let options = SyncOptions::new().with_dry_run(true);
+//! let report = api.sync_resources(&catalog, &options).await?;
+//! for result in report.results() {
+//!     println!("{} {:?}", result.action(), result.outcome());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod catalog;
+pub use self::catalog::*;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use futures_util::{stream, StreamExt, TryStreamExt};
+use serde::Serialize;
+
+use super::{
+    bundle::ListBundleMembersRequest,
+    tag::{
+        AttachResourceTagRequest, CreateResourceTagRequest, DetachResourceTagRequest,
+        ListResourceTagRequest, ResourceTag,
+    },
+    AttachResourceRequest, CreateResourceRequest, DetachResourceRequest, ListResourceRequest,
+    Resource, UpdateResourceRequest,
+};
+use crate::{fetch_all_pages, PianoAPI, SyncOptions};
+
+/// Resources, tags and bundle members currently in Piano
+///
+/// Only the tags of the resources and the members of the bundles of the catalog are
+/// needed to plan a synchronization.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceCatalogState {
+    resources: Vec<Resource>,
+    tags: Vec<ResourceTag>,
+    resource_tags: HashMap<String, Vec<ResourceTag>>,
+    bundle_members: HashMap<String, Vec<String>>,
+}
+
+impl ResourceCatalogState {
+    /// Create a state with the resources and the tags of the application
+    pub fn new(resources: Vec<Resource>, tags: Vec<ResourceTag>) -> Self {
+        Self {
+            resources,
+            tags,
+            ..Self::default()
+        }
+    }
+
+    /// Set the tags attached to a resource
+    pub fn with_resource_tags(mut self, rid: &str, tags: Vec<ResourceTag>) -> Self {
+        self.resource_tags.insert(rid.to_string(), tags);
+        self
+    }
+
+    /// Set the RIDs of the members of a bundle
+    pub fn with_bundle_members(mut self, bundle_rid: &str, members: Vec<String>) -> Self {
+        self.bundle_members.insert(bundle_rid.to_string(), members);
+        self
+    }
+
+    pub fn resources(&self) -> &[Resource] {
+        &self.resources
+    }
+    pub fn tags(&self) -> &[ResourceTag] {
+        &self.tags
+    }
+}
+
+/// Change to a resource, a tag or a bundle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ResourceSyncAction {
+    /// Create a resource missing from Piano
+    CreateResource(CatalogResource),
+    /// Update the fields of a resource which differ from the catalog
+    UpdateResource {
+        #[serde(flatten)]
+        resource: CatalogResource,
+        fields: Vec<String>,
+    },
+    /// Create a tag missing from Piano and attach it to a resource
+    CreateTag { rid: String, tag: String },
+    /// Attach an existing tag to a resource
+    ///
+    /// The ID is missing when the tag is created by another change of the plan.
+    AttachTag {
+        rid: String,
+        tag: String,
+        resource_tag_id: Option<String>,
+    },
+    /// Detach a tag missing from the catalog from a resource
+    DetachTag {
+        rid: String,
+        tag: String,
+        resource_tag_id: String,
+    },
+    /// Add a resource to a fixed bundle
+    AttachToBundle { bundle_rid: String, rid: String },
+    /// Remove a resource missing from the catalog from a fixed bundle
+    DetachFromBundle { bundle_rid: String, rid: String },
+}
+
+impl ResourceSyncAction {
+    /// Get the RID of the changed resource
+    pub fn rid(&self) -> &str {
+        match self {
+            ResourceSyncAction::CreateResource(resource)
+            | ResourceSyncAction::UpdateResource { resource, .. } => &resource.rid,
+            ResourceSyncAction::CreateTag { rid, .. }
+            | ResourceSyncAction::AttachTag { rid, .. }
+            | ResourceSyncAction::DetachTag { rid, .. }
+            | ResourceSyncAction::AttachToBundle { rid, .. }
+            | ResourceSyncAction::DetachFromBundle { rid, .. } => rid,
+        }
+    }
+
+    /// Get the kind of change
+    pub fn kind(&self) -> ResourceSyncActionKind {
+        match self {
+            ResourceSyncAction::CreateResource(_) => ResourceSyncActionKind::CreateResource,
+            ResourceSyncAction::UpdateResource { .. } => ResourceSyncActionKind::UpdateResource,
+            ResourceSyncAction::CreateTag { .. } => ResourceSyncActionKind::CreateTag,
+            ResourceSyncAction::AttachTag { .. } => ResourceSyncActionKind::AttachTag,
+            ResourceSyncAction::DetachTag { .. } => ResourceSyncActionKind::DetachTag,
+            ResourceSyncAction::AttachToBundle { .. } => ResourceSyncActionKind::AttachToBundle,
+            ResourceSyncAction::DetachFromBundle { .. } => ResourceSyncActionKind::DetachFromBundle,
+        }
+    }
+}
+
+impl fmt::Display for ResourceSyncAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceSyncAction::CreateResource(resource) => write!(
+                f,
+                "+ resource {} ({}, {})",
+                resource.rid, resource.name, resource.resource_type
+            ),
+            ResourceSyncAction::UpdateResource { resource, fields } => {
+                write!(f, "~ resource {} ({})", resource.rid, fields.join(", "))
+            }
+            ResourceSyncAction::CreateTag { rid, tag } => {
+                write!(f, "+ tag {tag} (new) on {rid}")
+            }
+            ResourceSyncAction::AttachTag { rid, tag, .. } => write!(f, "+ tag {tag} on {rid}"),
+            ResourceSyncAction::DetachTag { rid, tag, .. } => write!(f, "- tag {tag} on {rid}"),
+            ResourceSyncAction::AttachToBundle { bundle_rid, rid } => {
+                write!(f, "+ bundle {bundle_rid} member {rid}")
+            }
+            ResourceSyncAction::DetachFromBundle { bundle_rid, rid } => {
+                write!(f, "- bundle {bundle_rid} member {rid}")
+            }
+        }
+    }
+}
+
+/// Kind of change to a resource, a tag or a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceSyncActionKind {
+    CreateResource,
+    UpdateResource,
+    CreateTag,
+    AttachTag,
+    DetachTag,
+    AttachToBundle,
+    DetachFromBundle,
+}
+
+/// Difference with the catalog which the synchronization cannot fix
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "conflict", rename_all = "snake_case")]
+pub enum ResourceSyncConflict {
+    /// The type of an existing resource cannot be changed
+    TypeMismatch {
+        rid: String,
+        current: String,
+        desired: String,
+    },
+    /// The resource was deleted and its RID cannot be reused
+    DeletedResource { rid: String },
+    /// The bundle is neither in Piano nor in the catalog
+    UnknownBundle { rid: String },
+    /// The member of a bundle is neither in Piano nor in the catalog
+    UnknownMember { bundle_rid: String, rid: String },
+}
+
+impl fmt::Display for ResourceSyncConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceSyncConflict::TypeMismatch {
+                rid,
+                current,
+                desired,
+            } => write!(f, "! resource {rid} is a {current} resource, not {desired}"),
+            ResourceSyncConflict::DeletedResource { rid } => {
+                write!(f, "! resource {rid} is deleted")
+            }
+            ResourceSyncConflict::UnknownBundle { rid } => write!(f, "! bundle {rid} is unknown"),
+            ResourceSyncConflict::UnknownMember { bundle_rid, rid } => {
+                write!(f, "! bundle {bundle_rid} member {rid} is unknown")
+            }
+        }
+    }
+}
+
+/// Changes making Piano match a catalog
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceSyncPlan {
+    actions: Vec<ResourceSyncAction>,
+    conflicts: Vec<ResourceSyncConflict>,
+}
+
+impl ResourceSyncPlan {
+    /// Compute the changes making Piano match the catalog
+    ///
+    /// Resources with a conflict are left unchanged. Tags are matched by name.
+    pub fn new(
+        catalog: &ResourceCatalog,
+        state: &ResourceCatalogState,
+        options: &SyncOptions,
+    ) -> Self {
+        let existing = state
+            .resources
+            .iter()
+            .map(|resource| (resource.rid(), resource))
+            .collect::<HashMap<_, _>>();
+        let mut tag_ids = state
+            .tags
+            .iter()
+            .map(|tag| (tag.name(), Some(tag.resource_tag_id())))
+            .collect::<HashMap<_, _>>();
+        let mut plan = ResourceSyncPlan {
+            actions: vec![],
+            conflicts: vec![],
+        };
+        let mut tag_changes = vec![];
+        // Resources which exist once the plan is applied
+        let mut available = HashSet::new();
+        for resource in &catalog.resources {
+            let current_tags = match existing.get(resource.rid.as_str()) {
+                None => {
+                    plan.actions
+                        .push(ResourceSyncAction::CreateResource(resource.clone()));
+                    vec![]
+                }
+                Some(current) if current.is_deleted() => {
+                    plan.conflicts.push(ResourceSyncConflict::DeletedResource {
+                        rid: resource.rid.clone(),
+                    });
+                    continue;
+                }
+                Some(current)
+                    if !current
+                        .resource_type()
+                        .eq_ignore_ascii_case(&resource.resource_type) =>
+                {
+                    plan.conflicts.push(ResourceSyncConflict::TypeMismatch {
+                        rid: resource.rid.clone(),
+                        current: current.resource_type().to_string(),
+                        desired: resource.resource_type.clone(),
+                    });
+                    continue;
+                }
+                Some(current) => {
+                    let fields = changed_fields(current, resource);
+                    if !fields.is_empty() {
+                        plan.actions.push(ResourceSyncAction::UpdateResource {
+                            resource: resource.clone(),
+                            fields,
+                        });
+                    }
+                    state
+                        .resource_tags
+                        .get(&resource.rid)
+                        .cloned()
+                        .unwrap_or_default()
+                }
+            };
+            available.insert(resource.rid.as_str());
+
+            let desired_tags = resource
+                .tags
+                .iter()
+                .map(String::as_str)
+                .collect::<HashSet<_>>();
+            let current_names = current_tags
+                .iter()
+                .map(ResourceTag::name)
+                .collect::<HashSet<_>>();
+            let mut seen = HashSet::new();
+            for tag in &resource.tags {
+                if current_names.contains(tag.as_str()) || !seen.insert(tag.as_str()) {
+                    continue;
+                }
+                match tag_ids.get(tag.as_str()) {
+                    Some(resource_tag_id) => tag_changes.push(ResourceSyncAction::AttachTag {
+                        rid: resource.rid.clone(),
+                        tag: tag.clone(),
+                        resource_tag_id: resource_tag_id.map(ToString::to_string),
+                    }),
+                    None => {
+                        tag_ids.insert(tag.as_str(), None);
+                        tag_changes.push(ResourceSyncAction::CreateTag {
+                            rid: resource.rid.clone(),
+                            tag: tag.clone(),
+                        });
+                    }
+                }
+            }
+            if options.remove_missing {
+                for tag in &current_tags {
+                    if !desired_tags.contains(tag.name()) {
+                        tag_changes.push(ResourceSyncAction::DetachTag {
+                            rid: resource.rid.clone(),
+                            tag: tag.name().to_string(),
+                            resource_tag_id: tag.resource_tag_id().to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        plan.actions.extend(tag_changes);
+
+        let mut detachments = vec![];
+        let mut attachments = vec![];
+        for bundle in &catalog.bundles {
+            let declared = catalog.resource(&bundle.rid).is_some();
+            if !available.contains(bundle.rid.as_str())
+                && (declared || !existing.contains_key(bundle.rid.as_str()))
+            {
+                // Declared bundles are unavailable because of a conflict, already reported
+                if !declared {
+                    plan.conflicts.push(ResourceSyncConflict::UnknownBundle {
+                        rid: bundle.rid.clone(),
+                    });
+                }
+                continue;
+            }
+            let current = state
+                .bundle_members
+                .get(&bundle.rid)
+                .map(|members| members.iter().map(String::as_str).collect::<HashSet<_>>())
+                .unwrap_or_default();
+            for rid in &bundle.members {
+                if current.contains(rid.as_str()) {
+                    continue;
+                }
+                if available.contains(rid.as_str()) || existing.contains_key(rid.as_str()) {
+                    attachments.push(ResourceSyncAction::AttachToBundle {
+                        bundle_rid: bundle.rid.clone(),
+                        rid: rid.clone(),
+                    });
+                } else {
+                    plan.conflicts.push(ResourceSyncConflict::UnknownMember {
+                        bundle_rid: bundle.rid.clone(),
+                        rid: rid.clone(),
+                    });
+                }
+            }
+            if options.remove_missing {
+                let desired = bundle
+                    .members
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<HashSet<_>>();
+                let mut current = current.into_iter().collect::<Vec<_>>();
+                current.sort_unstable();
+                for rid in current {
+                    if !desired.contains(rid) {
+                        detachments.push(ResourceSyncAction::DetachFromBundle {
+                            bundle_rid: bundle.rid.clone(),
+                            rid: rid.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        plan.actions.extend(detachments);
+        plan.actions.extend(attachments);
+        plan
+    }
+
+    /// Get the changes in the order they are applied
+    pub fn actions(&self) -> &[ResourceSyncAction] {
+        &self.actions
+    }
+    pub fn conflicts(&self) -> &[ResourceSyncConflict] {
+        &self.conflicts
+    }
+    /// Check whether the plan has nothing to do
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty() && self.conflicts.is_empty()
+    }
+    /// Count the changes of the given kind
+    pub fn count(&self, kind: ResourceSyncActionKind) -> usize {
+        self.actions
+            .iter()
+            .filter(|action| action.kind() == kind)
+            .count()
+    }
+}
+
+impl fmt::Display for ResourceSyncPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for action in &self.actions {
+            writeln!(f, "{action}")?;
+        }
+        for conflict in &self.conflicts {
+            writeln!(f, "{conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the names of the declared fields of a resource which differ in Piano
+fn changed_fields(current: &Resource, desired: &CatalogResource) -> Vec<String> {
+    let mut fields = vec![];
+    if current.name() != desired.name {
+        fields.push("name".to_string());
+    }
+    let optional = [
+        ("description", current.description(), &desired.description),
+        (
+            "resource_url",
+            current.resource_url(),
+            &desired.resource_url,
+        ),
+        ("image_url", current.image_url(), &desired.image_url),
+        ("external_id", current.external_id(), &desired.external_id),
+    ];
+    for (field, current, desired) in optional {
+        if desired
+            .as_deref()
+            .is_some_and(|desired| Some(desired) != current)
+        {
+            fields.push(field.to_string());
+        }
+    }
+    if desired
+        .disabled
+        .is_some_and(|disabled| disabled != current.is_disabled())
+    {
+        fields.push("disabled".to_string());
+    }
+    fields
+}
+
+/// Outcome of a change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ResourceSyncOutcome {
+    /// The change was not applied because of a dry run
+    Planned,
+    /// The change was applied
+    Applied,
+    /// The call failed
+    Failed { error: String },
+}
+
+/// Outcome of one change of a synchronization
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResourceSyncResult {
+    #[serde(flatten)]
+    action: ResourceSyncAction,
+    #[serde(flatten)]
+    outcome: ResourceSyncOutcome,
+}
+
+impl ResourceSyncResult {
+    pub fn action(&self) -> &ResourceSyncAction {
+        &self.action
+    }
+    pub fn outcome(&self) -> &ResourceSyncOutcome {
+        &self.outcome
+    }
+}
+
+/// Outcome of a synchronization, with one result per change
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceSyncReport {
+    dry_run: bool,
+    results: Vec<ResourceSyncResult>,
+    conflicts: Vec<ResourceSyncConflict>,
+}
+
+impl ResourceSyncReport {
+    /// Create the report of a dry run, where every change is planned
+    pub fn dry_run(plan: &ResourceSyncPlan) -> Self {
+        let results = plan
+            .actions
+            .iter()
+            .map(|action| ResourceSyncResult {
+                action: action.clone(),
+                outcome: ResourceSyncOutcome::Planned,
+            })
+            .collect();
+        Self {
+            dry_run: true,
+            results,
+            conflicts: plan.conflicts.clone(),
+        }
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+    pub fn results(&self) -> &[ResourceSyncResult] {
+        &self.results
+    }
+    pub fn conflicts(&self) -> &[ResourceSyncConflict] {
+        &self.conflicts
+    }
+    /// Get the results of the failed calls
+    pub fn failures(&self) -> impl Iterator<Item = &ResourceSyncResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.outcome, ResourceSyncOutcome::Failed { .. }))
+    }
+}
+
+impl PianoAPI {
+    /// Read the resources, tags and bundle members of Piano needed to plan a synchronization
+    ///
+    /// Lists all the resources and tags of the application, then the tags of the resources
+    /// and the members of the bundles of the catalog, with up to `concurrency` calls at the
+    /// same time.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, catalog)))]
+    pub async fn resource_catalog_state(
+        &self,
+        catalog: &ResourceCatalog,
+        concurrency: usize,
+    ) -> Result<ResourceCatalogState, crate::Error> {
+        let concurrency = concurrency.max(1);
+        let (resources, tags) = futures_util::future::try_join(
+            self.list_all_resources(),
+            self.list_all_resource_tags(None),
+        )
+        .await?;
+        let existing = resources
+            .iter()
+            .map(|resource| resource.rid())
+            .collect::<HashSet<_>>();
+        let resource_tags = stream::iter(
+            catalog
+                .resources
+                .iter()
+                .filter(|resource| existing.contains(resource.rid.as_str())),
+        )
+        .map(|resource| async move {
+            let tags = self.list_all_resource_tags(Some(&resource.rid)).await?;
+            Ok::<_, crate::Error>((resource.rid.clone(), tags))
+        })
+        .buffered(concurrency)
+        .try_collect()
+        .await?;
+        let bundle_members = stream::iter(
+            catalog
+                .bundles
+                .iter()
+                .filter(|bundle| existing.contains(bundle.rid.as_str())),
+        )
+        .map(|bundle| async move {
+            let members = self.list_all_bundle_members(&bundle.rid).await?;
+            Ok::<_, crate::Error>((bundle.rid.clone(), members))
+        })
+        .buffered(concurrency)
+        .try_collect()
+        .await?;
+        Ok(ResourceCatalogState {
+            resources,
+            tags,
+            resource_tags,
+            bundle_members,
+        })
+    }
+
+    /// Compute the changes making Piano match a catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The resources and bundles expected in Piano
+    /// * `options` - The synchronization options
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, catalog)))]
+    pub async fn plan_resource_sync(
+        &self,
+        catalog: &ResourceCatalog,
+        options: &SyncOptions,
+    ) -> Result<ResourceSyncPlan, crate::Error> {
+        let state = self
+            .resource_catalog_state(catalog, options.concurrency)
+            .await?;
+        Ok(ResourceSyncPlan::new(catalog, &state, options))
+    }
+
+    /// Apply the changes of a plan
+    ///
+    /// Resources are created and updated first, then new tags are created, other tags are
+    /// attached and detached, and bundle members are detached before new ones are attached.
+    /// Up to `concurrency` calls of the same step are made at the same time. A failed call
+    /// does not stop the others and is reported in its result.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, plan)))]
+    pub async fn apply_resource_sync_plan(
+        &self,
+        plan: &ResourceSyncPlan,
+        concurrency: usize,
+    ) -> ResourceSyncReport {
+        use ResourceSyncActionKind::*;
+
+        let concurrency = concurrency.max(1);
+        let steps: [&[ResourceSyncActionKind]; 5] = [
+            &[CreateResource, UpdateResource],
+            &[CreateTag],
+            &[AttachTag, DetachTag],
+            &[DetachFromBundle],
+            &[AttachToBundle],
+        ];
+        let mut created_tags = HashMap::new();
+        let mut results = vec![];
+        for kinds in steps {
+            let actions = plan
+                .actions
+                .iter()
+                .filter(|action| kinds.contains(&action.kind()));
+            let known_tags = &created_tags;
+            let outcomes = stream::iter(actions)
+                .map(|action| async move {
+                    let outcome = self.apply_resource_sync_action(action, known_tags).await;
+                    (action, outcome)
+                })
+                .buffered(concurrency)
+                .collect::<Vec<_>>()
+                .await;
+            let mut tags = vec![];
+            for (action, outcome) in outcomes {
+                let outcome = match outcome {
+                    Ok(tag) => {
+                        tags.extend(tag);
+                        ResourceSyncOutcome::Applied
+                    }
+                    Err(error) => ResourceSyncOutcome::Failed {
+                        error: error.to_string(),
+                    },
+                };
+                results.push(ResourceSyncResult {
+                    action: action.clone(),
+                    outcome,
+                });
+            }
+            for tag in tags {
+                created_tags.insert(tag.name().to_string(), tag.resource_tag_id().to_string());
+            }
+        }
+        ResourceSyncReport {
+            dry_run: false,
+            results,
+            conflicts: plan.conflicts.clone(),
+        }
+    }
+
+    /// Make Piano match a catalog
+    ///
+    /// Computes the plan and applies it, unless the options ask for a dry run.
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The resources and bundles expected in Piano
+    /// * `options` - The synchronization options
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, catalog)))]
+    pub async fn sync_resources(
+        &self,
+        catalog: &ResourceCatalog,
+        options: &SyncOptions,
+    ) -> Result<ResourceSyncReport, crate::Error> {
+        let plan = self.plan_resource_sync(catalog, options).await?;
+        if options.dry_run {
+            return Ok(ResourceSyncReport::dry_run(&plan));
+        }
+        Ok(self
+            .apply_resource_sync_plan(&plan, options.concurrency)
+            .await)
+    }
+
+    /// Apply a change, returning the tag it created if any
+    async fn apply_resource_sync_action(
+        &self,
+        action: &ResourceSyncAction,
+        created_tags: &HashMap<String, String>,
+    ) -> Result<Option<ResourceTag>, crate::Error> {
+        match action {
+            ResourceSyncAction::CreateResource(resource) => {
+                let mut req = CreateResourceRequest::new(
+                    &resource.rid,
+                    &resource.name,
+                    &resource.resource_type,
+                );
+                req.bundle_type = resource.bundle_type.as_deref();
+                req.description = resource.description.as_deref();
+                req.resource_url = resource.resource_url.as_deref();
+                req.image_url = resource.image_url.as_deref();
+                req.external_id = resource.external_id.as_deref();
+                req.disabled = resource.disabled;
+                self.create_resource(&req).await?;
+            }
+            ResourceSyncAction::UpdateResource { resource, .. } => {
+                let mut req = UpdateResourceRequest::new(&resource.rid).with_name(&resource.name);
+                req.description = resource.description.as_deref();
+                req.resource_url = resource.resource_url.as_deref();
+                req.image_url = resource.image_url.as_deref();
+                req.external_id = resource.external_id.as_deref();
+                req.disabled = resource.disabled;
+                self.update_resource(&req).await?;
+            }
+            ResourceSyncAction::CreateTag { rid, tag } => {
+                let tag = self
+                    .create_resource_tag(&CreateResourceTagRequest::new(tag).with_rid(rid))
+                    .await?;
+                return Ok(Some(tag));
+            }
+            ResourceSyncAction::AttachTag {
+                rid,
+                tag,
+                resource_tag_id,
+            } => {
+                let resource_tag_id = resource_tag_id
+                    .as_deref()
+                    .or_else(|| created_tags.get(tag).map(String::as_str))
+                    .ok_or_else(|| format!("tag {tag} was not created"))?;
+                self.attach_resource_tag(&AttachResourceTagRequest::new(rid, resource_tag_id))
+                    .await?;
+            }
+            ResourceSyncAction::DetachTag {
+                rid,
+                resource_tag_id,
+                ..
+            } => {
+                self.detach_resource_tag(&DetachResourceTagRequest::new(rid, resource_tag_id))
+                    .await?;
+            }
+            ResourceSyncAction::AttachToBundle { bundle_rid, rid } => {
+                let included_rid = [rid.as_str()];
+                self.attach_resource(&AttachResourceRequest::new(bundle_rid, &included_rid))
+                    .await?;
+            }
+            ResourceSyncAction::DetachFromBundle { bundle_rid, rid } => {
+                self.detach_resource(&DetachResourceRequest::new(rid, bundle_rid))
+                    .await?;
+            }
+        }
+        Ok(None)
+    }
+
+    async fn list_all_resources(&self) -> Result<Vec<Resource>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListResourceRequest::new()
+                    .with_limit(100)
+                    .with_offset(offset);
+                async move { self.list_resources(&params).await }
+            },
+            |page| page.resources,
+        )
+        .await
+    }
+
+    async fn list_all_resource_tags(
+        &self,
+        rid: Option<&str>,
+    ) -> Result<Vec<ResourceTag>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let mut params = ListResourceTagRequest::new()
+                    .with_limit(100)
+                    .with_offset(offset);
+                if let Some(rid) = rid {
+                    params = params.with_rid(rid);
+                }
+                async move { self.list_resource_tags(&params).await }
+            },
+            |page| page.resource_tags,
+        )
+        .await
+    }
+
+    async fn list_all_bundle_members(&self, bundle_rid: &str) -> Result<Vec<String>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListBundleMembersRequest::new(bundle_rid)
+                    .with_limit(100)
+                    .with_offset(offset);
+                async move { self.list_bundle_members(&params).await }
+            },
+            |page| {
+                page.resources
+                    .into_iter()
+                    .map(|resource| resource.rid().to_string())
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn resource(rid: &str, name: &str, resource_type: &str) -> Resource {
+        serde_json::from_value(json!({
+            "rid": rid,
+            "aid": "AID",
+            "name": name,
+            "description": null,
+            "image_url": null,
+            "purchase_url": null,
+            "resource_url": null,
+            "external_id": null,
+            "disabled": false,
+            "deleted": false,
+            "type": resource_type,
+            "create_date": 1700000000,
+            "create_by": null,
+            "update_date": null,
+            "update_by": null,
+            "publish_date": null,
+            "bundle_type": null
+        }))
+        .expect("OK")
+    }
+
+    fn tag(resource_tag_id: &str, name: &str) -> ResourceTag {
+        serde_json::from_value(json!({
+            "resource_tag_id": resource_tag_id,
+            "name": name,
+            "type": "Standard"
+        }))
+        .expect("OK")
+    }
+
+    fn catalog() -> ResourceCatalog {
+        ResourceCatalog {
+            resources: vec![
+                CatalogResource::new("premium", "Premium articles").with_tags(&["news", "opinion"]),
+                CatalogResource::new("archive", "Archive").with_tags(&["opinion"]),
+                CatalogResource::fixed_bundle("all", "All access"),
+                CatalogResource::new("video", "Video"),
+            ],
+            bundles: vec![CatalogBundle::new(
+                "all",
+                &["premium", "archive", "missing"],
+            )],
+        }
+    }
+
+    #[test]
+    fn test_plan() {
+        let state = ResourceCatalogState::new(
+            vec![
+                resource("premium", "Premium", "standard"),
+                resource("all", "All access", "bundle"),
+                resource("video", "Video", "bundle"),
+                resource("podcast", "Podcast", "standard"),
+            ],
+            vec![tag("T1", "news"), tag("T2", "sports")],
+        )
+        .with_resource_tags("premium", vec![tag("T2", "sports")])
+        .with_bundle_members("all", vec!["podcast".to_string(), "premium".to_string()]);
+
+        let plan = ResourceSyncPlan::new(&catalog(), &state, &SyncOptions::new());
+        let actions = plan
+            .actions()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                "~ resource premium (name)",
+                "+ resource archive (Archive, standard)",
+                "+ tag news on premium",
+                "+ tag opinion (new) on premium",
+                "- tag sports on premium",
+                "+ tag opinion on archive",
+                "- bundle all member podcast",
+                "+ bundle all member archive",
+            ]
+        );
+        assert_eq!(
+            plan.conflicts(),
+            &[
+                ResourceSyncConflict::TypeMismatch {
+                    rid: "video".to_string(),
+                    current: "bundle".to_string(),
+                    desired: "standard".to_string(),
+                },
+                ResourceSyncConflict::UnknownMember {
+                    bundle_rid: "all".to_string(),
+                    rid: "missing".to_string(),
+                },
+            ]
+        );
+        assert_eq!(plan.count(ResourceSyncActionKind::AttachTag), 2);
+
+        let plan = ResourceSyncPlan::new(
+            &catalog(),
+            &state,
+            &SyncOptions::new().with_remove_missing(false),
+        );
+        assert_eq!(plan.count(ResourceSyncActionKind::DetachTag), 0);
+        assert_eq!(plan.count(ResourceSyncActionKind::DetachFromBundle), 0);
+    }
+
+    #[test]
+    fn test_dry_run_report() {
+        let state = ResourceCatalogState::new(vec![], vec![]);
+        let catalog = ResourceCatalog {
+            resources: vec![CatalogResource::new("premium", "Premium").with_tags(&["news"])],
+            bundles: vec![CatalogBundle::new("all", &["premium"])],
+        };
+        let plan = ResourceSyncPlan::new(&catalog, &state, &SyncOptions::new());
+        let report = ResourceSyncReport::dry_run(&plan);
+        assert!(report.is_dry_run());
+        assert_eq!(report.failures().count(), 0);
+        assert_eq!(
+            serde_json::to_value(&report.results()[1]).expect("OK"),
+            json!({ "action": "create_tag", "rid": "premium", "tag": "news", "outcome": "planned" })
+        );
+        assert_eq!(
+            serde_json::to_value(report.conflicts()).expect("OK"),
+            json!([{ "conflict": "unknown_bundle", "rid": "all" }])
+        );
+    }
+}
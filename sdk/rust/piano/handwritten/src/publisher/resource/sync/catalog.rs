@@ -0,0 +1,281 @@
+use std::{collections::HashSet, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::invalid_input;
+
+/// Resources, tags and bundle memberships expected in Piano
+///
+/// Only the resources and bundles listed in the catalog are managed: other resources of
+/// the application are left unchanged.
+///
+/// ```toml
+/// [[resources]]
+/// rid = "premium"
+/// name = "Premium articles"
+/// tags = ["news", "opinion"]
+///
+/// [[resources]]
+/// rid = "all-access"
+/// name = "All access"
+/// type = "bundle"
+/// bundle_type = "fixed"
+///
+/// [[bundles]]
+/// rid = "all-access"
+/// members = ["premium"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceCatalog {
+    #[serde(default)]
+    pub resources: Vec<CatalogResource>,
+    #[serde(default)]
+    pub bundles: Vec<CatalogBundle>,
+}
+
+impl ResourceCatalog {
+    /// Parse and validate a catalog in JSON
+    pub fn from_json_str(value: &str) -> Result<Self, crate::Error> {
+        let catalog = serde_json::from_str::<Self>(value)?;
+        catalog.validate()?;
+        Ok(catalog)
+    }
+
+    /// Parse and validate a catalog in TOML
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(value: &str) -> Result<Self, crate::Error> {
+        let catalog = toml::from_str::<Self>(value)?;
+        catalog.validate()?;
+        Ok(catalog)
+    }
+
+    /// Read and validate a catalog from a `.json` file, or a `.toml` file with the `toml`
+    /// feature
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, crate::Error> {
+        let path = path.as_ref();
+        let value = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json_str(&value),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml_str(&value),
+            _ => Err(invalid_input(format!(
+                "{} is not a supported catalog file",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Check that the catalog declares every resource and bundle once, with a RID and a name
+    ///
+    /// Returns an [`std::io::ErrorKind::InvalidInput`] error describing the first problem found.
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        let mut rids = HashSet::new();
+        for resource in &self.resources {
+            if resource.rid.trim().is_empty() {
+                return Err(invalid_input("a resource has no RID".to_string()));
+            }
+            if resource.name.trim().is_empty() {
+                return Err(invalid_input(format!(
+                    "resource {} has no name",
+                    resource.rid
+                )));
+            }
+            if !rids.insert(resource.rid.as_str()) {
+                return Err(invalid_input(format!(
+                    "resource {} is declared twice",
+                    resource.rid
+                )));
+            }
+        }
+        let mut bundle_rids = HashSet::new();
+        for bundle in &self.bundles {
+            if !bundle_rids.insert(bundle.rid.as_str()) {
+                return Err(invalid_input(format!(
+                    "bundle {} is declared twice",
+                    bundle.rid
+                )));
+            }
+            let mut members = HashSet::new();
+            for member in &bundle.members {
+                if member == &bundle.rid {
+                    return Err(invalid_input(format!(
+                        "bundle {} contains itself",
+                        bundle.rid
+                    )));
+                }
+                if !members.insert(member.as_str()) {
+                    return Err(invalid_input(format!(
+                        "bundle {} contains {member} twice",
+                        bundle.rid
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the declared resource with the given RID
+    pub fn resource(&self, rid: &str) -> Option<&CatalogResource> {
+        self.resources.iter().find(|resource| resource.rid == rid)
+    }
+}
+
+/// Resource declared in a catalog
+///
+/// The optional fields which are not set are left unchanged on existing resources.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogResource {
+    pub rid: String,
+    pub name: String,
+    /// The resource type (`standard`, `bundle` or `print`), `standard` by default
+    #[serde(rename = "type", default = "standard")]
+    pub resource_type: String,
+    /// The bundle type of a bundle (`fixed`, `tagged` or `fixed_v2`), set on creation only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bundle_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// The names of the tags of the resource
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn standard() -> String {
+    "standard".to_string()
+}
+
+impl CatalogResource {
+    /// Create a standard resource without tags
+    pub fn new(rid: &str, name: &str) -> Self {
+        Self {
+            rid: rid.to_string(),
+            name: name.to_string(),
+            resource_type: standard(),
+            bundle_type: None,
+            description: None,
+            resource_url: None,
+            image_url: None,
+            external_id: None,
+            disabled: None,
+            tags: vec![],
+        }
+    }
+
+    /// Create a fixed bundle
+    pub fn fixed_bundle(rid: &str, name: &str) -> Self {
+        Self {
+            resource_type: "bundle".to_string(),
+            bundle_type: Some("fixed".to_string()),
+            ..Self::new(rid, name)
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn with_resource_url(mut self, resource_url: &str) -> Self {
+        self.resource_url = Some(resource_url.to_string());
+        self
+    }
+
+    pub fn with_tags(mut self, tags: &[&str]) -> Self {
+        self.tags = tags.iter().map(ToString::to_string).collect();
+        self
+    }
+}
+
+/// Members expected in a fixed bundle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogBundle {
+    /// The RID of the bundle
+    pub rid: String,
+    /// The RIDs of the resources of the bundle
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+impl CatalogBundle {
+    pub fn new(rid: &str, members: &[&str]) -> Self {
+        Self {
+            rid: rid.to_string(),
+            members: members.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json_str() {
+        let catalog = ResourceCatalog::from_json_str(
+            r#"{
+                "resources": [
+                    { "rid": "premium", "name": "Premium", "tags": ["news"] },
+                    { "rid": "all", "name": "All access", "type": "bundle", "bundle_type": "fixed" }
+                ],
+                "bundles": [{ "rid": "all", "members": ["premium"] }]
+            }"#,
+        )
+        .expect("OK");
+        assert_eq!(
+            catalog.resources[0],
+            CatalogResource::new("premium", "Premium").with_tags(&["news"])
+        );
+        assert_eq!(
+            catalog.resource("all"),
+            Some(&CatalogResource::fixed_bundle("all", "All access"))
+        );
+        assert_eq!(
+            catalog.bundles,
+            vec![CatalogBundle::new("all", &["premium"])]
+        );
+
+        for invalid in [
+            r#"{ "resources": [{ "rid": "a", "name": "A" }, { "rid": "a", "name": "B" }] }"#,
+            r#"{ "resources": [{ "rid": "a", "name": " " }] }"#,
+            r#"{ "bundles": [{ "rid": "b", "members": ["a", "a"] }] }"#,
+            r#"{ "bundles": [{ "rid": "b", "members": ["b"] }] }"#,
+            r#"{ "resources": [{ "rid": "a", "name": "A", "tag": ["news"] }] }"#,
+        ] {
+            assert!(
+                ResourceCatalog::from_json_str(invalid).is_err(),
+                "{invalid} is valid"
+            );
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str() {
+        let catalog = ResourceCatalog::from_toml_str(
+            r#"
+            [[resources]]
+            rid = "premium"
+            name = "Premium"
+            tags = ["news"]
+
+            [[bundles]]
+            rid = "all"
+            members = ["premium"]
+            "#,
+        )
+        .expect("OK");
+        assert_eq!(catalog.resources[0].tags, vec!["news"]);
+        assert_eq!(catalog.bundles[0].members, vec!["premium"]);
+    }
+}
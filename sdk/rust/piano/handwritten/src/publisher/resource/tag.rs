@@ -1,7 +1,7 @@
 mod schema;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoResponse};
+use crate::{Empty, PianoAPI, PianoPaginated, PianoResponse};
 
 impl PianoAPI {
     /// Create a resource tag
@@ -104,12 +104,16 @@ impl PianoAPI {
         &self,
         req: &AttachResourceTagRequest<'_>,
     ) -> Result<(), crate::Error> {
-        self.client
+        let _ = self
+            .client
             .post(format!("{}/publisher/resource/tag/attach", self.endpoint))
             .query(&[("aid", &self.app_id)])
             .form(req)
             .send()
-            .await?;
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
         Ok(())
     }
 
@@ -123,12 +127,16 @@ impl PianoAPI {
         &self,
         req: &DetachResourceTagRequest<'_>,
     ) -> Result<(), crate::Error> {
-        self.client
+        let _ = self
+            .client
             .post(format!("{}/publisher/resource/tag/detach", self.endpoint))
             .query(&[("aid", &self.app_id)])
             .form(req)
             .send()
-            .await?;
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
         Ok(())
     }
 
@@ -5,6 +5,9 @@ use serde::{Deserialize, Serialize};
 pub struct CreateResourceTagRequest<'a> {
     /// The resource tag name
     pub name: &'a str,
+    /// The resource the tag is attached to once created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rid: Option<&'a str>,
     /// The resource tag description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
@@ -15,10 +18,17 @@ impl<'a> CreateResourceTagRequest<'a> {
     pub fn new(name: &'a str) -> Self {
         Self {
             name,
+            rid: None,
             description: None,
         }
     }
 
+    /// Attach the tag to a resource once created
+    pub fn with_rid(mut self, rid: &'a str) -> Self {
+        self.rid = Some(rid);
+        self
+    }
+
     /// Set the resource tag description
     pub fn with_description(mut self, description: &'a str) -> Self {
         self.description = Some(description);
@@ -58,6 +68,9 @@ pub struct ListResourceTagRequest {
     /// Search query
     #[serde(skip_serializing_if = "Option::is_none")]
     pub q: Option<String>,
+    /// List only the tags of this resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rid: Option<String>,
 }
 
 impl ListResourceTagRequest {
@@ -66,6 +79,12 @@ impl ListResourceTagRequest {
         Self::default()
     }
 
+    /// List only the tags of a resource
+    pub fn with_rid(mut self, rid: &str) -> Self {
+        self.rid = Some(rid.to_string());
+        self
+    }
+
     /// Set the limit
     pub fn with_limit(mut self, limit: usize) -> Self {
         self.limit = Some(limit);
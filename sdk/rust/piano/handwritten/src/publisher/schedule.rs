@@ -1,8 +1,14 @@
 pub mod period;
 mod schema;
+mod timeline;
 pub use self::schema::*;
+pub use self::timeline::*;
 
-use crate::{PianoAPI, PianoResponse};
+use std::io::ErrorKind;
+
+use futures_util::future;
+
+use crate::{fetch_all_pages, invalid_input, Empty, PianoAPI, PianoPaginated, PianoResponse};
 impl PianoAPI {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     pub async fn get_schedule(&self, schedule_id: &str) -> Result<Option<Schedule>, crate::Error> {
@@ -50,4 +56,228 @@ impl PianoAPI {
             .value()?;
         Ok(result.schedule)
     }
+
+    /// List the schedules of the application
+    ///
+    /// Deleted schedules are listed too; see [`Schedule::deleted`].
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The ordering, search and pagination parameters
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fschedule~2Flist) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_schedules(
+        &self,
+        params: &ListScheduleRequest<'_>,
+    ) -> Result<PianoPaginated<ListScheduleResult>, crate::Error> {
+        let result = self
+            .client
+            .post(format!("{}/publisher/schedule/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .form(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListScheduleResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// List all the schedules of the application
+    ///
+    /// Follows the pagination of [`PianoAPI::list_schedules`] until every schedule has
+    /// been read.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_schedules(&self) -> Result<Vec<Schedule>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListScheduleRequest::new().with_offset(offset);
+                async move { self.list_schedules(&params).await }
+            },
+            |page| page.schedules,
+        )
+        .await
+    }
+
+    /// Delete a schedule
+    ///
+    /// Piano keeps deleted schedules: they are still returned with [`Schedule::deleted`]
+    /// set, so deleting a schedule archives it. Contracts using the schedule are not
+    /// updated; use [`PianoAPI::retire_schedule`] to delete only unused schedules.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule_id` - The ID of the schedule
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fschedule~2Fdelete) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_schedule(&self, schedule_id: &str) -> Result<(), crate::Error> {
+        let _ = self
+            .client
+            .post(format!("{}/publisher/schedule/delete", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .form(&[("schedule_id", schedule_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// List the terms using a schedule
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The schedule ID with the ordering, search and pagination parameters
+    ///
+    /// # Reference
+    ///
+    /// See the [Piano API documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fschedule~2Fterms) for more details.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_schedule_terms(
+        &self,
+        params: &ListScheduleTermsRequest<'_>,
+    ) -> Result<PianoPaginated<ListScheduleTermsResult>, crate::Error> {
+        let result = self
+            .client
+            .post(format!("{}/publisher/schedule/terms", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .form(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListScheduleTermsResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// List all the terms using a schedule
+    ///
+    /// Follows the pagination of [`PianoAPI::list_schedule_terms`] until every term has
+    /// been read.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_all_schedule_terms(
+        &self,
+        schedule_id: &str,
+    ) -> Result<Vec<ScheduleTerm>, crate::Error> {
+        fetch_all_pages(
+            |offset| {
+                let params = ListScheduleTermsRequest::new(schedule_id).with_offset(offset);
+                async move { self.list_schedule_terms(&params).await }
+            },
+            |page| page.terms,
+        )
+        .await
+    }
+
+    /// Delete a schedule unless a contract or a term uses it
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule_id` - The ID of the schedule
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`ErrorKind::InvalidInput`] error naming the contracts and the terms
+    /// using the schedule without deleting it.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn retire_schedule(&self, schedule_id: &str) -> Result<(), crate::Error> {
+        let (contracts, terms) = future::try_join(
+            self.list_schedule_contracts(schedule_id),
+            self.list_all_schedule_terms(schedule_id),
+        )
+        .await?;
+        let mut users = Vec::new();
+        if !contracts.is_empty() {
+            let contract_ids = contracts
+                .iter()
+                .map(|contract| contract.contract_id())
+                .collect::<Vec<_>>()
+                .join(", ");
+            users.push(format!("contracts {contract_ids}"));
+        }
+        if !terms.is_empty() {
+            let term_ids = terms
+                .iter()
+                .map(ScheduleTerm::term_id)
+                .collect::<Vec<_>>()
+                .join(", ");
+            users.push(format!("terms {term_ids}"));
+        }
+        if !users.is_empty() {
+            return Err(invalid_input(format!(
+                "schedule {schedule_id} is used by {}",
+                users.join(" and ")
+            )));
+        }
+        self.delete_schedule(schedule_id).await
+    }
+
+    /// Add periods to a schedule, one after the other
+    ///
+    /// Stops at the first period which cannot be added.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule_id` - The ID of the schedule
+    /// * `periods` - The periods to add, usually planned with [`Schedule::plan_next_periods`]
+    ///
+    /// # Returns
+    ///
+    /// Returns the added periods in order.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn add_planned_periods(
+        &self,
+        schedule_id: &str,
+        periods: &[PlannedPeriod],
+    ) -> Result<Vec<period::Period>, crate::Error> {
+        let mut added = Vec::with_capacity(periods.len());
+        for period in periods {
+            added.push(
+                self.add_schedule_period(&period.to_add_request(schedule_id))
+                    .await?,
+            );
+        }
+        Ok(added)
+    }
+
+    /// Roll a schedule forward
+    ///
+    /// Adds `count` periods of the given cadence after the period of the schedule ending
+    /// last, so that access does not stop when it ends. The new periods are named after
+    /// their begin date; use [`Schedule::plan_next_periods`] and
+    /// [`PianoAPI::add_planned_periods`] to name them differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `schedule_id` - The ID of the schedule
+    /// * `cadence` - The length of each new period
+    /// * `count` - The number of periods to add
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`ErrorKind::NotFound`] error if the schedule does not exist and an
+    /// [`ErrorKind::InvalidInput`] error if it has no period to continue from.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn roll_schedule_forward(
+        &self,
+        schedule_id: &str,
+        cadence: Cadence,
+        count: u32,
+    ) -> Result<Vec<period::Period>, crate::Error> {
+        let schedule = self.get_schedule(schedule_id).await?.ok_or_else(|| {
+            Box::new(std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("schedule {schedule_id} not found"),
+            ))
+        })?;
+        let planned = schedule.plan_next_periods(cadence, count)?;
+        self.add_planned_periods(schedule_id, &planned).await
+    }
 }
@@ -47,7 +47,7 @@ impl PianoAPI {
         let _ = self
             .client
             .post(format!(
-                "{}/publisher/schedule/period/remove",
+                "{}/publisher/schedule/period/delete",
                 self.endpoint,
             ))
             .query(&[("aid", &self.app_id)])
@@ -24,17 +24,16 @@ pub(super) struct PeriodResult {
     pub period: Period,
 }
 
-#[allow(unused)]
 #[derive(Debug, Clone, Deserialize)]
 pub struct Period {
     name: String,
     period_id: String,
-    sell_date: u32,
-    begin_date: u32,
-    end_date: u32,
+    sell_date: i64,
+    begin_date: i64,
+    end_date: i64,
     deleted: bool,
-    create_date: u32,
-    update_date: u32,
+    create_date: i64,
+    update_date: i64,
     is_sale_started: bool,
     is_active: bool,
 }
@@ -42,4 +41,31 @@ impl Period {
     pub fn period_id(&self) -> &str {
         &self.period_id
     }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn sell_date(&self) -> i64 {
+        self.sell_date
+    }
+    pub fn begin_date(&self) -> i64 {
+        self.begin_date
+    }
+    pub fn end_date(&self) -> i64 {
+        self.end_date
+    }
+    pub fn deleted(&self) -> bool {
+        self.deleted
+    }
+    pub fn create_date(&self) -> i64 {
+        self.create_date
+    }
+    pub fn update_date(&self) -> i64 {
+        self.update_date
+    }
+    pub fn is_sale_started(&self) -> bool {
+        self.is_sale_started
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
 }
@@ -1,30 +1,220 @@
 use crate::publisher::schedule::period::Period;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+/// Request to list the schedules of the application
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fschedule~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListScheduleRequest<'a> {
+    /// Field to order by (`name` or `create`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListScheduleRequest<'a> {
+    /// Create a new list schedules request
+    pub fn new() -> Self {
+        Self {
+            order_by: None,
+            order_direction: None,
+            q: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Set the search query
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListScheduleRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Request to list the terms using a schedule
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fschedule~2Fterms)
+#[derive(Debug, Serialize)]
+pub struct ListScheduleTermsRequest<'a> {
+    /// The schedule ID
+    pub schedule_id: &'a str,
+    /// Field to order by (`name` or `create`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListScheduleTermsRequest<'a> {
+    /// Create a new list schedule terms request
+    pub fn new(schedule_id: &'a str) -> Self {
+        Self {
+            schedule_id,
+            order_by: None,
+            order_direction: None,
+            q: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Set the search query
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Response for schedule terms list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListScheduleTermsResult {
+    pub terms: Vec<ScheduleTerm>,
+}
+
+/// A term using a schedule
+///
+/// Only the fields identifying the term are read.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScheduleTerm {
+    term_id: String,
+    name: String,
+    #[serde(rename = "type")]
+    term_type: String,
+}
+impl ScheduleTerm {
+    pub fn term_id(&self) -> &str {
+        &self.term_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Get the type of the term, such as `payment`
+    pub fn term_type(&self) -> &str {
+        &self.term_type
+    }
+}
 
 #[derive(Debug, Deserialize, Clone)]
 pub(super) struct ScheduleResult {
     pub schedule: Schedule,
 }
+
+/// Response for schedule list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListScheduleResult {
+    #[serde(alias = "Schedule")]
+    pub schedules: Vec<Schedule>,
+}
 /// A "Schedule" is a set of fixed-time access periods
 /// used in association with a payment term.
-#[allow(unused)]
 #[derive(Debug, Deserialize, Clone)]
 pub struct Schedule {
     aid: String,
     name: String,
     schedule_id: String,
     deleted: bool,
-    create_date: u32,
-    update_date: u32,
+    create_date: i64,
+    update_date: i64,
     periods: Vec<Period>,
 }
 impl Schedule {
     pub fn schedule_id(&self) -> &str {
         &self.schedule_id
     }
+    pub fn aid(&self) -> &str {
+        &self.aid
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn deleted(&self) -> bool {
+        self.deleted
+    }
+    pub fn create_date(&self) -> i64 {
+        self.create_date
+    }
+    pub fn update_date(&self) -> i64 {
+        self.update_date
+    }
+    /// Get all the periods of the schedule, including the deleted ones
     pub fn periods(&self) -> &Vec<Period> {
         &self.periods
     }
+    /// Get the periods of the schedule which are not deleted
+    pub fn live_periods(&self) -> impl Iterator<Item = &Period> {
+        self.periods.iter().filter(|period| !period.deleted())
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +247,53 @@ mod tests {
         assert_eq!(schedule.periods().len(), 0);
     }
 
+    #[test]
+    fn test_list_schedule_request() {
+        let request = ListScheduleRequest::new()
+            .with_order_by("name")
+            .with_query("annual")
+            .with_offset(100);
+        let value = serde_json::to_value(&request).expect("OK");
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "order_by": "name",
+                "q": "annual",
+                "offset": 100,
+                "limit": 100
+            })
+        );
+
+        let result = serde_json::from_value::<ListScheduleResult>(serde_json::json!({
+            "Schedule": []
+        }))
+        .expect("OK");
+        assert!(result.schedules.is_empty());
+    }
+
+    #[test]
+    fn test_list_schedule_terms() {
+        let request = ListScheduleTermsRequest::new("SC1").with_limit(10);
+        let value = serde_json::to_value(&request).expect("OK");
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "schedule_id": "SC1",
+                "offset": 0,
+                "limit": 10
+            })
+        );
+
+        let result = serde_json::from_value::<ListScheduleTermsResult>(serde_json::json!({
+            "terms": [
+                { "term_id": "TM1", "aid": "AID", "type": "payment", "name": "Annual", "payment_currency": "EUR" }
+            ]
+        }))
+        .expect("OK");
+        assert_eq!(result.terms[0].term_id(), "TM1");
+        assert_eq!(result.terms[0].term_type(), "payment");
+    }
+
     #[test]
     fn sanity_check_get_schedule_codec() {
         let snapshot = include_str!("../licensing/schedule/get.schema.snapshot.json");
@@ -0,0 +1,556 @@
+use super::{
+    period::{AddPeriodRequest, Period},
+    Schedule,
+};
+use crate::{
+    invalid_input,
+    publisher::licensing::contract::{periods::SchedulePeriod, ContractPeriod},
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Dates of a period of a schedule
+///
+/// Implemented by the periods of schedules ([`Period`]), of contracts ([`ContractPeriod`])
+/// and of activated licensing schedules ([`SchedulePeriod`]). Dates are Unix timestamps in
+/// seconds. A period grants access from its begin date, included, to its end date,
+/// excluded.
+///
+/// With the `time` or `chrono` feature, the dates are also available as
+/// [`time::OffsetDateTime`] or [`chrono::DateTime<chrono::Utc>`]. These accessors return
+/// `None` for timestamps out of the range of the type.
+pub trait PeriodSpan {
+    fn period_id(&self) -> &str;
+    fn sell_date(&self) -> i64;
+    fn begin_date(&self) -> i64;
+    fn end_date(&self) -> i64;
+
+    /// Check whether the period grants access at a timestamp
+    fn contains(&self, timestamp: i64) -> bool {
+        self.begin_date() <= timestamp && timestamp < self.end_date()
+    }
+
+    #[cfg(feature = "time")]
+    fn sell_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        to_offset_date_time(self.sell_date())
+    }
+    #[cfg(feature = "time")]
+    fn begin_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        to_offset_date_time(self.begin_date())
+    }
+    #[cfg(feature = "time")]
+    fn end_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        to_offset_date_time(self.end_date())
+    }
+
+    #[cfg(feature = "chrono")]
+    fn sell_date_time_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        to_date_time_utc(self.sell_date())
+    }
+    #[cfg(feature = "chrono")]
+    fn begin_date_time_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        to_date_time_utc(self.begin_date())
+    }
+    #[cfg(feature = "chrono")]
+    fn end_date_time_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        to_date_time_utc(self.end_date())
+    }
+}
+
+/// Convert a Unix timestamp in seconds to a [`time::OffsetDateTime`] in UTC
+#[cfg(feature = "time")]
+pub fn to_offset_date_time(timestamp: i64) -> Option<time::OffsetDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(timestamp).ok()
+}
+
+/// Convert a Unix timestamp in seconds to a [`chrono::DateTime<chrono::Utc>`]
+#[cfg(feature = "chrono")]
+pub fn to_date_time_utc(timestamp: i64) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+}
+
+impl PeriodSpan for Period {
+    fn period_id(&self) -> &str {
+        Period::period_id(self)
+    }
+    fn sell_date(&self) -> i64 {
+        Period::sell_date(self)
+    }
+    fn begin_date(&self) -> i64 {
+        Period::begin_date(self)
+    }
+    fn end_date(&self) -> i64 {
+        Period::end_date(self)
+    }
+}
+
+impl PeriodSpan for ContractPeriod {
+    fn period_id(&self) -> &str {
+        ContractPeriod::period_id(self)
+    }
+    fn sell_date(&self) -> i64 {
+        ContractPeriod::sell_date(self)
+    }
+    fn begin_date(&self) -> i64 {
+        ContractPeriod::begin_date(self)
+    }
+    fn end_date(&self) -> i64 {
+        ContractPeriod::end_date(self)
+    }
+}
+
+impl PeriodSpan for SchedulePeriod {
+    fn period_id(&self) -> &str {
+        SchedulePeriod::period_id(self)
+    }
+    fn sell_date(&self) -> i64 {
+        SchedulePeriod::sell_date(self)
+    }
+    fn begin_date(&self) -> i64 {
+        SchedulePeriod::begin_date(self)
+    }
+    fn end_date(&self) -> i64 {
+        SchedulePeriod::end_date(self)
+    }
+}
+
+/// Find the period granting access at a timestamp, or else the first one starting after it
+///
+/// When overlapping periods contain the timestamp, the one which began last is returned.
+pub fn current_or_next_period<'a, P: PeriodSpan>(
+    periods: impl IntoIterator<Item = &'a P>,
+    timestamp: i64,
+) -> Option<&'a P> {
+    let mut current: Option<&P> = None;
+    let mut next: Option<&P> = None;
+    for period in periods {
+        if period.contains(timestamp) {
+            if current.map_or(true, |current| period.begin_date() > current.begin_date()) {
+                current = Some(period);
+            }
+        } else if period.begin_date() > timestamp
+            && next.map_or(true, |next| period.begin_date() < next.begin_date())
+        {
+            next = Some(period);
+        }
+    }
+    current.or(next)
+}
+
+/// Time without access between two periods
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodGap {
+    /// The period ending before the gap
+    pub previous_period_id: String,
+    /// The period beginning after the gap
+    pub next_period_id: String,
+    /// The end date of the previous period
+    pub start: i64,
+    /// The begin date of the next period
+    pub end: i64,
+}
+
+impl PeriodGap {
+    /// Get the length of the gap in seconds
+    pub fn duration(&self) -> i64 {
+        self.end - self.start
+    }
+}
+
+/// Time covered by two periods
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodOverlap {
+    pub first_period_id: String,
+    pub second_period_id: String,
+    /// The begin date of the second period
+    pub start: i64,
+    /// The earliest end date of both periods
+    pub end: i64,
+}
+
+/// Find the times without access between the first and the last periods
+///
+/// Periods ending exactly when the next one begins leave no gap. The gaps are ordered by
+/// date.
+pub fn find_period_gaps<'a, P: PeriodSpan + 'a>(
+    periods: impl IntoIterator<Item = &'a P>,
+) -> Vec<PeriodGap> {
+    let periods = sorted_by_begin_date(periods);
+    let mut gaps = vec![];
+    let Some((first, rest)) = periods.split_first() else {
+        return gaps;
+    };
+    // The period ending last among the periods already visited
+    let mut covering = *first;
+    for period in rest {
+        if period.begin_date() > covering.end_date() {
+            gaps.push(PeriodGap {
+                previous_period_id: covering.period_id().to_string(),
+                next_period_id: period.period_id().to_string(),
+                start: covering.end_date(),
+                end: period.begin_date(),
+            });
+        }
+        if period.end_date() > covering.end_date() {
+            covering = *period;
+        }
+    }
+    gaps
+}
+
+/// Find all the pairs of periods granting access at the same time
+///
+/// The pairs are ordered by the begin date of their first period.
+pub fn find_period_overlaps<'a, P: PeriodSpan + 'a>(
+    periods: impl IntoIterator<Item = &'a P>,
+) -> Vec<PeriodOverlap> {
+    let periods = sorted_by_begin_date(periods);
+    let mut overlaps = vec![];
+    for (i, first) in periods.iter().enumerate() {
+        for second in &periods[i + 1..] {
+            // Later periods begin even later, so none of them overlaps
+            if second.begin_date() >= first.end_date() {
+                break;
+            }
+            overlaps.push(PeriodOverlap {
+                first_period_id: first.period_id().to_string(),
+                second_period_id: second.period_id().to_string(),
+                start: second.begin_date(),
+                end: first.end_date().min(second.end_date()),
+            });
+        }
+    }
+    overlaps
+}
+
+fn sorted_by_begin_date<'a, P: PeriodSpan + 'a>(
+    periods: impl IntoIterator<Item = &'a P>,
+) -> Vec<&'a P> {
+    let mut periods = periods.into_iter().collect::<Vec<_>>();
+    periods.sort_by_key(|period| (period.begin_date(), period.end_date()));
+    periods
+}
+
+/// Length of the periods added when rolling a schedule forward
+///
+/// Dates are computed in UTC. Adding months keeps the day of the month of the first
+/// period, or the last day of shorter months: periods rolled monthly from January 31st
+/// begin on February 28th (or 29th), then March 31st.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cadence {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+}
+
+impl Cadence {
+    /// Move a timestamp forward by a number of steps of the cadence
+    pub fn advance(&self, timestamp: i64, steps: u32) -> i64 {
+        let steps = i64::from(steps);
+        match *self {
+            Cadence::Days(days) => timestamp + i64::from(days) * steps * SECONDS_PER_DAY,
+            Cadence::Weeks(weeks) => timestamp + i64::from(weeks) * steps * 7 * SECONDS_PER_DAY,
+            Cadence::Months(months) => add_months(timestamp, i64::from(months) * steps),
+            Cadence::Years(years) => add_months(timestamp, i64::from(years) * steps * 12),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        matches!(
+            self,
+            Cadence::Days(0) | Cadence::Weeks(0) | Cadence::Months(0) | Cadence::Years(0)
+        )
+    }
+}
+
+/// Period to add to a schedule
+///
+/// Named after its begin date (`YYYY-MM-DD`) by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedPeriod {
+    pub name: String,
+    pub sell_date: i64,
+    pub begin_date: i64,
+    pub end_date: i64,
+}
+
+impl PlannedPeriod {
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Build the request adding the period to a schedule
+    pub fn to_add_request<'a>(&'a self, schedule_id: &'a str) -> AddPeriodRequest<'a> {
+        AddPeriodRequest {
+            schedule_id,
+            name: &self.name,
+            sell_date: self.sell_date,
+            begin_date: self.begin_date,
+            end_date: self.end_date,
+        }
+    }
+}
+
+/// Plan the next periods of a schedule
+///
+/// The new periods follow each other from the end of the period ending last. Each of them
+/// goes on sale as long before its begin date as that period did.
+///
+/// Returns an [`std::io::ErrorKind::InvalidInput`] error if there is no period to continue from or
+/// if the cadence is zero.
+pub fn plan_next_periods<'a, P: PeriodSpan + 'a>(
+    periods: impl IntoIterator<Item = &'a P>,
+    cadence: Cadence,
+    count: u32,
+) -> Result<Vec<PlannedPeriod>, crate::Error> {
+    if cadence.is_zero() {
+        return Err(invalid_input(format!("{cadence:?} is not a valid cadence")));
+    }
+    let last = periods
+        .into_iter()
+        .max_by_key(|period| (period.end_date(), period.begin_date()))
+        .ok_or_else(|| invalid_input("there is no period to continue from".to_string()))?;
+    let sell_lead = (last.begin_date() - last.sell_date()).max(0);
+    let anchor = last.end_date();
+    Ok((0..count)
+        .map(|step| {
+            let begin_date = cadence.advance(anchor, step);
+            PlannedPeriod {
+                name: format_date(begin_date),
+                sell_date: begin_date - sell_lead,
+                begin_date,
+                end_date: cadence.advance(anchor, step + 1),
+            }
+        })
+        .collect())
+}
+
+impl Schedule {
+    /// Find the period granting access at a timestamp, or else the next one
+    ///
+    /// Deleted periods are ignored.
+    pub fn current_or_next_period(&self, timestamp: i64) -> Option<&Period> {
+        current_or_next_period(self.live_periods(), timestamp)
+    }
+    /// Find the times without access between the periods which are not deleted
+    pub fn find_gaps(&self) -> Vec<PeriodGap> {
+        find_period_gaps(self.live_periods())
+    }
+    /// Find the pairs of periods which are not deleted and grant access at the same time
+    pub fn find_overlaps(&self) -> Vec<PeriodOverlap> {
+        find_period_overlaps(self.live_periods())
+    }
+    /// Plan the next periods of the schedule from its periods which are not deleted
+    ///
+    /// See [`plan_next_periods`].
+    pub fn plan_next_periods(
+        &self,
+        cadence: Cadence,
+        count: u32,
+    ) -> Result<Vec<PlannedPeriod>, crate::Error> {
+        plan_next_periods(self.live_periods(), cadence, count)
+    }
+}
+
+fn add_months(timestamp: i64, months: i64) -> i64 {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    let seconds = timestamp.rem_euclid(SECONDS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let month_index = year * 12 + i64::from(month - 1) + months;
+    let year = month_index.div_euclid(12);
+    let month = month_index.rem_euclid(12) as u32 + 1;
+    let day = day.min(days_in_month(year, month));
+    days_from_civil(year, month, day) * SECONDS_PER_DAY + seconds
+}
+
+fn format_date(timestamp: i64) -> String {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Count the days since 1970-01-01 of a date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Get the date of the proleptic Gregorian calendar a number of days after 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn period(period_id: &str, begin_date: i64, end_date: i64) -> Period {
+        serde_json::from_value(json!({
+            "period_id": period_id,
+            "name": period_id,
+            "sell_date": begin_date - 100,
+            "begin_date": begin_date,
+            "end_date": end_date,
+            "deleted": false,
+            "create_date": 0,
+            "update_date": 0,
+            "is_sale_started": true,
+            "is_active": false
+        }))
+        .expect("OK")
+    }
+
+    fn ids<P: PeriodSpan>(period: Option<&P>) -> Option<&str> {
+        period.map(PeriodSpan::period_id)
+    }
+
+    #[test]
+    fn test_current_or_next_period() {
+        let periods = [period("P2", 200, 300), period("P1", 100, 200)];
+        assert_eq!(ids(current_or_next_period(&periods, 50)), Some("P1"));
+        assert_eq!(ids(current_or_next_period(&periods, 100)), Some("P1"));
+        assert_eq!(ids(current_or_next_period(&periods, 200)), Some("P2"));
+        assert_eq!(ids(current_or_next_period(&periods, 300)), None);
+    }
+
+    #[test]
+    fn test_gaps_and_overlaps() {
+        let periods = [
+            period("P1", 0, 100),
+            period("P2", 100, 200),
+            period("P3", 150, 400),
+            period("P4", 160, 170),
+            period("P5", 500, 600),
+        ];
+        assert_eq!(
+            find_period_gaps(&periods),
+            vec![PeriodGap {
+                previous_period_id: "P3".to_string(),
+                next_period_id: "P5".to_string(),
+                start: 400,
+                end: 500,
+            }]
+        );
+        let overlaps = find_period_overlaps(&periods)
+            .into_iter()
+            .map(|overlap| {
+                (
+                    overlap.first_period_id,
+                    overlap.second_period_id,
+                    overlap.end,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            overlaps,
+            vec![
+                ("P2".to_string(), "P3".to_string(), 200),
+                ("P2".to_string(), "P4".to_string(), 170),
+                ("P3".to_string(), "P4".to_string(), 170),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar() {
+        for days in [-719_468, -1, 0, 11_016, 19_782, 2_932_896] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        // 2024-01-31T12:00:00Z
+        let timestamp = 1_706_702_400;
+        assert_eq!(format_date(timestamp), "2024-01-31");
+        assert_eq!(
+            format_date(Cadence::Months(1).advance(timestamp, 1)),
+            "2024-02-29"
+        );
+        assert_eq!(
+            format_date(Cadence::Months(1).advance(timestamp, 2)),
+            "2024-03-31"
+        );
+        assert_eq!(
+            format_date(Cadence::Years(1).advance(timestamp, 1)),
+            "2025-01-31"
+        );
+        assert_eq!(
+            format_date(Cadence::Weeks(2).advance(timestamp, 1)),
+            "2024-02-14"
+        );
+        assert_eq!(
+            Cadence::Months(1).advance(timestamp, 1) % SECONDS_PER_DAY,
+            43_200
+        );
+    }
+
+    #[test]
+    fn test_plan_next_periods() {
+        // 2024-01-01T00:00:00Z to 2025-01-01T00:00:00Z
+        let periods = [period("P1", 1_704_067_200, 1_735_689_600)];
+        let planned = plan_next_periods(&periods, Cadence::Years(1), 2).expect("OK");
+        assert_eq!(planned.len(), 2);
+        assert_eq!(planned[0].name, "2025-01-01");
+        assert_eq!(planned[0].begin_date, 1_735_689_600);
+        assert_eq!(planned[0].sell_date, 1_735_689_500);
+        assert_eq!(planned[0].end_date, planned[1].begin_date);
+        assert_eq!(format_date(planned[1].end_date), "2027-01-01");
+
+        let request = planned[1].to_add_request("S1");
+        assert_eq!(request.schedule_id, "S1");
+        assert_eq!(request.name, "2026-01-01");
+
+        assert!(plan_next_periods(&periods, Cadence::Days(0), 1).is_err());
+        assert!(plan_next_periods::<Period>(&[], Cadence::Days(1), 1).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_offset_date_time() {
+        let period = period("P1", 1_704_067_200, 1_735_689_600);
+        let begin = period.begin_offset_date_time().expect("OK");
+        assert_eq!(begin.year(), 2024);
+        assert_eq!(begin.unix_timestamp(), 1_704_067_200);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date_time_utc() {
+        use chrono::Datelike;
+
+        let period = period("P1", 1_704_067_200, 1_735_689_600);
+        let end = period.end_date_time_utc().expect("OK");
+        assert_eq!(end.year(), 2025);
+        assert_eq!(end.timestamp(), 1_735_689_600);
+    }
+}
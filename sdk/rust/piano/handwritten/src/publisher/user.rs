@@ -30,7 +30,18 @@
 mod schema;
 pub use self::schema::*;
 
-use crate::{PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+/// User addresses and their change history
+pub mod address;
+/// User billing addresses
+pub mod billing_address;
+/// Bulk user import status and reports
+pub mod bulk_import;
+/// Emails sent to users
+pub mod email;
+/// Customer support notes attached to users
+pub mod note;
+
+use crate::{Empty, PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
 
 impl PianoAPI {
     /// Get user information by UID.
@@ -144,4 +155,66 @@ impl PianoAPI {
             .value()?;
         Ok(result)
     }
+
+    /// Register a new user.
+    ///
+    /// Unlike [`PianoAPI::create_user`], this goes through the regular registration flow.
+    ///
+    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fregister
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn register_user(
+        &self,
+        params: &RegisterUserRequest<'_>,
+    ) -> Result<User, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/register", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserResult>>()
+            .await?
+            .value()?;
+        Ok(result.user)
+    }
+
+    /// Disable a user.
+    ///
+    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fdisable
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn disable_user(&self, uid: &str) -> Result<(), crate::Error> {
+        self.client
+            .post(format!("{}/publisher/user/disable", self.endpoint))
+            .form(&[("aid", self.app_id.as_str()), ("uid", uid)])
+            .send()
+            .await?
+            .json::<PianoResponse<Empty>>()
+            .await?
+            .value()?;
+        Ok(())
+    }
+
+    /// Count the users of the application.
+    ///
+    /// Reference: https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fapp~2Fcount
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn count_app_users(&self, include_disabled: bool) -> Result<usize, crate::Error> {
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/app/count", self.endpoint))
+            .form(&[
+                ("aid", self.app_id.as_str()),
+                ("include_disabled", &include_disabled.to_string()),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<UserCountResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
 }
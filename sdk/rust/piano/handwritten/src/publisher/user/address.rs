@@ -0,0 +1,123 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Create a user address.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fcreate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_user_address(
+        &self,
+        params: &CreateUserAddressRequest<'_>,
+    ) -> Result<UserAddress, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/address/create", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserAddressResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_address)
+    }
+
+    /// Update a user address.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fupdate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_user_address(
+        &self,
+        params: &UpdateUserAddressRequest<'_>,
+    ) -> Result<UserAddress, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/address/update", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserAddressResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_address)
+    }
+
+    /// Delete a user address.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fdelete)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_user_address(
+        &self,
+        params: &DeleteUserAddressRequest<'_>,
+    ) -> Result<bool, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/address/delete", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserAddressBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// List the addresses of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faddress~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_user_addresses(
+        &self,
+        params: &ListUserAddressRequest<'_>,
+    ) -> Result<PianoPaginated<ListUserAddressResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/user/address/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListUserAddressResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// List the history of address changes in the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fhistory)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_user_address_history(
+        &self,
+        params: &ListUserAddressHistoryRequest,
+    ) -> Result<PianoPaginated<ListUserAddressHistoryResult>, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/address/history", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListUserAddressHistoryResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,782 @@
+use serde::{Deserialize, Serialize};
+
+use crate::publisher::user::User;
+
+/// Request to create a user address
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fcreate)
+#[derive(Debug, Serialize)]
+pub struct CreateUserAddressRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The country ID
+    pub country_id: &'a str,
+    /// The name of the city
+    pub city: &'a str,
+    /// The user's postal code
+    pub postal_code: &'a str,
+    /// The user's first address line
+    pub address1: &'a str,
+    /// The user's second address line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<&'a str>,
+    /// The ID of the country region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_id: Option<&'a str>,
+    /// The name of the country region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_name: Option<&'a str>,
+    /// The company name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<&'a str>,
+    /// The user's first name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    /// The user's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    /// The user's phone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+}
+
+impl<'a> CreateUserAddressRequest<'a> {
+    /// Create a new create user address request
+    pub fn new(
+        uid: &'a str,
+        country_id: &'a str,
+        city: &'a str,
+        postal_code: &'a str,
+        address1: &'a str,
+    ) -> Self {
+        Self {
+            uid,
+            country_id,
+            city,
+            postal_code,
+            address1,
+            address2: None,
+            region_id: None,
+            region_name: None,
+            company_name: None,
+            first_name: None,
+            last_name: None,
+            phone: None,
+        }
+    }
+
+    /// Set the second address line
+    pub fn with_address2(mut self, address2: &'a str) -> Self {
+        self.address2 = Some(address2);
+        self
+    }
+
+    /// Set the region ID
+    pub fn with_region_id(mut self, region_id: &'a str) -> Self {
+        self.region_id = Some(region_id);
+        self
+    }
+
+    /// Set the region name
+    pub fn with_region_name(mut self, region_name: &'a str) -> Self {
+        self.region_name = Some(region_name);
+        self
+    }
+
+    /// Set the company name
+    pub fn with_company_name(mut self, company_name: &'a str) -> Self {
+        self.company_name = Some(company_name);
+        self
+    }
+
+    /// Set the first name
+    pub fn with_first_name(mut self, first_name: &'a str) -> Self {
+        self.first_name = Some(first_name);
+        self
+    }
+
+    /// Set the last name
+    pub fn with_last_name(mut self, last_name: &'a str) -> Self {
+        self.last_name = Some(last_name);
+        self
+    }
+
+    /// Set the phone
+    pub fn with_phone(mut self, phone: &'a str) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+}
+
+/// Request to update a user address
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fupdate)
+#[derive(Debug, Serialize)]
+pub struct UpdateUserAddressRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The public ID of the user address
+    pub user_address_id: &'a str,
+    /// The country ID
+    pub country_id: &'a str,
+    /// The name of the city
+    pub city: &'a str,
+    /// The user's postal code
+    pub postal_code: &'a str,
+    /// The user's first address line
+    pub address1: &'a str,
+    /// The user's second address line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<&'a str>,
+    /// The ID of the country region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_id: Option<&'a str>,
+    /// The name of the country region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_name: Option<&'a str>,
+    /// The company name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<&'a str>,
+    /// The user's first name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    /// The user's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    /// The user's phone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    /// The additional address fields (as JSON string)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_fields: Option<&'a str>,
+}
+
+impl<'a> UpdateUserAddressRequest<'a> {
+    /// Create a new update user address request
+    pub fn new(
+        uid: &'a str,
+        user_address_id: &'a str,
+        country_id: &'a str,
+        city: &'a str,
+        postal_code: &'a str,
+        address1: &'a str,
+    ) -> Self {
+        Self {
+            uid,
+            user_address_id,
+            country_id,
+            city,
+            postal_code,
+            address1,
+            address2: None,
+            region_id: None,
+            region_name: None,
+            company_name: None,
+            first_name: None,
+            last_name: None,
+            phone: None,
+            additional_fields: None,
+        }
+    }
+
+    /// Set the second address line
+    pub fn with_address2(mut self, address2: &'a str) -> Self {
+        self.address2 = Some(address2);
+        self
+    }
+
+    /// Set the region ID
+    pub fn with_region_id(mut self, region_id: &'a str) -> Self {
+        self.region_id = Some(region_id);
+        self
+    }
+
+    /// Set the region name
+    pub fn with_region_name(mut self, region_name: &'a str) -> Self {
+        self.region_name = Some(region_name);
+        self
+    }
+
+    /// Set the company name
+    pub fn with_company_name(mut self, company_name: &'a str) -> Self {
+        self.company_name = Some(company_name);
+        self
+    }
+
+    /// Set the first name
+    pub fn with_first_name(mut self, first_name: &'a str) -> Self {
+        self.first_name = Some(first_name);
+        self
+    }
+
+    /// Set the last name
+    pub fn with_last_name(mut self, last_name: &'a str) -> Self {
+        self.last_name = Some(last_name);
+        self
+    }
+
+    /// Set the phone
+    pub fn with_phone(mut self, phone: &'a str) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    /// Set the additional address fields (as JSON string)
+    pub fn with_additional_fields(mut self, additional_fields: &'a str) -> Self {
+        self.additional_fields = Some(additional_fields);
+        self
+    }
+}
+
+/// Request to delete a user address
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fdelete)
+#[derive(Debug, Serialize)]
+pub struct DeleteUserAddressRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The public ID of the user address
+    pub user_address_id: &'a str,
+}
+
+impl<'a> DeleteUserAddressRequest<'a> {
+    /// Create a new delete user address request
+    pub fn new(uid: &'a str, user_address_id: &'a str) -> Self {
+        Self {
+            uid,
+            user_address_id,
+        }
+    }
+}
+
+/// Request to list the addresses of a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Faddress~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListUserAddressRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListUserAddressRequest<'a> {
+    /// Create a new list user address request
+    pub fn new(uid: &'a str) -> Self {
+        Self {
+            uid,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Request to list the history of address changes in the application
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Faddress~2Fhistory)
+#[derive(Debug, Serialize)]
+pub struct ListUserAddressHistoryRequest {
+    /// Date from (UNIX timestamp)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_from: Option<i64>,
+    /// Date to (UNIX timestamp)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_to: Option<i64>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl ListUserAddressHistoryRequest {
+    /// Create a new list address history request
+    pub fn new() -> Self {
+        Self {
+            date_from: None,
+            date_to: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Only return changes made at or after the given UNIX timestamp
+    pub fn with_date_from(mut self, date_from: i64) -> Self {
+        self.date_from = Some(date_from);
+        self
+    }
+
+    /// Only return changes made at or before the given UNIX timestamp
+    pub fn with_date_to(mut self, date_to: i64) -> Self {
+        self.date_to = Some(date_to);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListUserAddressHistoryRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Region of a user address
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddressRegion {
+    region_id: String,
+    #[serde(default)]
+    region_name: Option<String>,
+    #[serde(default)]
+    region_code: Option<String>,
+}
+
+impl AddressRegion {
+    /// Get the region ID
+    pub fn region_id(&self) -> &str {
+        &self.region_id
+    }
+
+    /// Get the region name
+    pub fn region_name(&self) -> Option<&str> {
+        self.region_name.as_deref()
+    }
+
+    /// Get the region code
+    pub fn region_code(&self) -> Option<&str> {
+        self.region_code.as_deref()
+    }
+}
+
+/// Country of a user address
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddressCountry {
+    country_id: String,
+    #[serde(default)]
+    country_name: Option<String>,
+    #[serde(default)]
+    country_code: Option<String>,
+    #[serde(default)]
+    regions: Vec<AddressRegion>,
+}
+
+impl AddressCountry {
+    /// Get the country ID
+    pub fn country_id(&self) -> &str {
+        &self.country_id
+    }
+
+    /// Get the country name
+    pub fn country_name(&self) -> Option<&str> {
+        self.country_name.as_deref()
+    }
+
+    /// Get the country code
+    pub fn country_code(&self) -> Option<&str> {
+        self.country_code.as_deref()
+    }
+
+    /// Get the regions of the country
+    pub fn regions(&self) -> &[AddressRegion] {
+        &self.regions
+    }
+}
+
+/// User address object
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserAddress {
+    user_address_id: String,
+    #[serde(default)]
+    region: Option<AddressRegion>,
+    #[serde(default)]
+    country: Option<AddressCountry>,
+    #[serde(default)]
+    city: Option<String>,
+    #[serde(default)]
+    postal_code: Option<String>,
+    #[serde(default)]
+    company_name: Option<String>,
+    #[serde(default)]
+    first_name: Option<String>,
+    #[serde(default)]
+    last_name: Option<String>,
+    #[serde(default)]
+    personal_name: Option<String>,
+    #[serde(default)]
+    address1: Option<String>,
+    #[serde(default)]
+    address2: Option<String>,
+    #[serde(default)]
+    phone: Option<String>,
+    #[serde(default)]
+    additional_fields: Option<String>,
+}
+
+impl UserAddress {
+    /// Get the public ID of the user address
+    pub fn user_address_id(&self) -> &str {
+        &self.user_address_id
+    }
+
+    /// Get the region
+    pub fn region(&self) -> Option<&AddressRegion> {
+        self.region.as_ref()
+    }
+
+    /// Get the country
+    pub fn country(&self) -> Option<&AddressCountry> {
+        self.country.as_ref()
+    }
+
+    /// Get the city
+    pub fn city(&self) -> Option<&str> {
+        self.city.as_deref()
+    }
+
+    /// Get the postal code
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    /// Get the company name
+    pub fn company_name(&self) -> Option<&str> {
+        self.company_name.as_deref()
+    }
+
+    /// Get the first name
+    pub fn first_name(&self) -> Option<&str> {
+        self.first_name.as_deref()
+    }
+
+    /// Get the last name
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
+    }
+
+    /// Get the personal name
+    pub fn personal_name(&self) -> Option<&str> {
+        self.personal_name.as_deref()
+    }
+
+    /// Get the first address line
+    pub fn address1(&self) -> Option<&str> {
+        self.address1.as_deref()
+    }
+
+    /// Get the second address line
+    pub fn address2(&self) -> Option<&str> {
+        self.address2.as_deref()
+    }
+
+    /// Get the phone
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+
+    /// Get the additional address fields (as JSON string)
+    pub fn additional_fields(&self) -> Option<&str> {
+        self.additional_fields.as_deref()
+    }
+}
+
+/// Type of a user address history revision
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressRevisionType {
+    Creation,
+    Update,
+}
+
+impl<'de> Deserialize<'de> for AddressRevisionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match u8::deserialize(deserializer)? {
+            0 => Ok(Self::Creation),
+            1 => Ok(Self::Update),
+            other => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(other as u64),
+                &"0 or 1",
+            )),
+        }
+    }
+}
+
+/// A revision in the history of a user address
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserAddressHistory {
+    user_address_id: String,
+    create_date: i64,
+    #[serde(default)]
+    update_date: Option<i64>,
+    revision: i64,
+    revision_type: AddressRevisionType,
+    #[serde(default)]
+    user: Option<User>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    first_name: Option<String>,
+    #[serde(default)]
+    last_name: Option<String>,
+    #[serde(default)]
+    company_name: Option<String>,
+    #[serde(default)]
+    address1: Option<String>,
+    #[serde(default)]
+    address2: Option<String>,
+    #[serde(default)]
+    city: Option<String>,
+    #[serde(default)]
+    postal_code: Option<String>,
+    #[serde(default)]
+    phone: Option<String>,
+    #[serde(default)]
+    region: Option<AddressRegion>,
+    #[serde(default)]
+    country: Option<AddressCountry>,
+    #[serde(default)]
+    additional_fields: Option<String>,
+    #[serde(default)]
+    create_by: Option<String>,
+    #[serde(default)]
+    update_by: Option<String>,
+}
+
+impl UserAddressHistory {
+    /// Get the public ID of the user address
+    pub fn user_address_id(&self) -> &str {
+        &self.user_address_id
+    }
+
+    /// Get the creation date
+    pub fn create_date(&self) -> i64 {
+        self.create_date
+    }
+
+    /// Get the update date
+    pub fn update_date(&self) -> Option<i64> {
+        self.update_date
+    }
+
+    /// Get the revision timestamp
+    pub fn revision(&self) -> i64 {
+        self.revision
+    }
+
+    /// Get the revision type
+    pub fn revision_type(&self) -> &AddressRevisionType {
+        &self.revision_type
+    }
+
+    /// Get the user owning the address
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Check if the address is deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Get the first name
+    pub fn first_name(&self) -> Option<&str> {
+        self.first_name.as_deref()
+    }
+
+    /// Get the last name
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
+    }
+
+    /// Get the company name
+    pub fn company_name(&self) -> Option<&str> {
+        self.company_name.as_deref()
+    }
+
+    /// Get the first address line
+    pub fn address1(&self) -> Option<&str> {
+        self.address1.as_deref()
+    }
+
+    /// Get the second address line
+    pub fn address2(&self) -> Option<&str> {
+        self.address2.as_deref()
+    }
+
+    /// Get the city
+    pub fn city(&self) -> Option<&str> {
+        self.city.as_deref()
+    }
+
+    /// Get the postal code
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    /// Get the phone
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+
+    /// Get the region
+    pub fn region(&self) -> Option<&AddressRegion> {
+        self.region.as_ref()
+    }
+
+    /// Get the country
+    pub fn country(&self) -> Option<&AddressCountry> {
+        self.country.as_ref()
+    }
+
+    /// Get the additional address fields (as JSON string)
+    pub fn additional_fields(&self) -> Option<&str> {
+        self.additional_fields.as_deref()
+    }
+
+    /// Get the creator
+    pub fn create_by(&self) -> Option<&str> {
+        self.create_by.as_deref()
+    }
+
+    /// Get the last updater
+    pub fn update_by(&self) -> Option<&str> {
+        self.update_by.as_deref()
+    }
+}
+
+/// Response wrapper for user address operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserAddressResult {
+    #[serde(alias = "UserAddress")]
+    pub user_address: UserAddress,
+}
+
+/// Response for user address list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListUserAddressResult {
+    #[serde(alias = "UserAddress")]
+    pub user_addresses: Vec<UserAddress>,
+}
+
+/// Response for user address history operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListUserAddressHistoryResult {
+    #[serde(alias = "UserAddressHistory")]
+    pub user_address_history: Vec<UserAddressHistory>,
+}
+
+/// Response wrapper for boolean operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserAddressBooleanResult {
+    pub data: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PianoPaginated, PianoResponse};
+
+    #[test]
+    fn test_create_user_address_request_builder() {
+        let request = CreateUserAddressRequest::new("user123", "C1", "Tokyo", "100-0001", "1-1")
+            .with_region_id("R1")
+            .with_company_name("Acme");
+
+        assert_eq!(request.uid, "user123");
+        assert_eq!(request.country_id, "C1");
+        assert_eq!(request.region_id, Some("R1"));
+        assert_eq!(request.company_name, Some("Acme"));
+        assert_eq!(request.address2, None);
+    }
+
+    #[test]
+    fn test_list_user_address_history_request_builder() {
+        let request = ListUserAddressHistoryRequest::new()
+            .with_date_from(1640995200)
+            .with_limit(10);
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({ "date_from": 1640995200, "offset": 0, "limit": 10 })
+        );
+    }
+
+    #[test]
+    fn test_list_user_address_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "ts": 1749016879,
+            "limit": 100,
+            "offset": 0,
+            "total": 1,
+            "count": 1,
+            "UserAddress": [{
+                "user_address_id": "UAXXXXXXXX",
+                "region": { "region_id": "RXXXXXXXXX", "region_name": "Tokyo", "region_code": "13" },
+                "country": { "country_id": "CXXXXXXXXX", "country_name": "Japan", "country_code": "JP", "regions": [] },
+                "city": "Chiyoda",
+                "postal_code": "100-0001",
+                "company_name": null,
+                "first_name": "Taro",
+                "last_name": "Yamada",
+                "personal_name": "Yamada Taro",
+                "address1": "1-1",
+                "address2": null,
+                "phone": null,
+                "additional_fields": null
+            }]
+        });
+
+        let result =
+            serde_json::from_value::<PianoResponse<PianoPaginated<ListUserAddressResult>>>(json)
+                .expect("Failed to deserialize user address list")
+                .value()
+                .expect("OK");
+        let address = &result.value.user_addresses[0];
+        assert_eq!(address.user_address_id(), "UAXXXXXXXX");
+        assert_eq!(address.country().and_then(|c| c.country_code()), Some("JP"));
+        assert_eq!(address.region().map(|r| r.region_id()), Some("RXXXXXXXXX"));
+    }
+
+    #[test]
+    fn test_user_address_history_deserialization() {
+        let json = serde_json::json!({
+            "user_address_id": "UAXXXXXXXX",
+            "create_date": 1640995200,
+            "update_date": 1641081600,
+            "revision": 1641081600,
+            "revision_type": 1,
+            "deleted": false,
+            "city": "Chiyoda"
+        });
+
+        let history: UserAddressHistory =
+            serde_json::from_value(json).expect("Failed to deserialize address history");
+        assert_eq!(history.revision_type(), &AddressRevisionType::Update);
+        assert_eq!(history.city(), Some("Chiyoda"));
+    }
+}
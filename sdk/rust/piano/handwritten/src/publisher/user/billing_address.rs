@@ -0,0 +1,164 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{publisher::payment::BillingAddress, PianoAPI, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Create a billing address for a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2Fcreate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_billing_address(
+        &self,
+        params: &CreateBillingAddressRequest<'_>,
+    ) -> Result<BillingAddress, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/billingAddress/create",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserBillingAddressResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_billing_address)
+    }
+
+    /// Delete a billing address of a user.
+    ///
+    /// Returns the deleted billing address.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2Fdelete)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_billing_address(
+        &self,
+        params: &BillingAddressRequest<'_>,
+    ) -> Result<BillingAddress, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/billingAddress/delete",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserBillingAddressResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_billing_address)
+    }
+
+    /// Get a billing address of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_billing_address(
+        &self,
+        params: &BillingAddressRequest<'_>,
+    ) -> Result<Option<BillingAddress>, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/billingAddress/get",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserBillingAddressResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.user_billing_address))
+    }
+
+    /// Get the billing address used for a user payment.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2FgetByPayment)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_billing_address_by_payment(
+        &self,
+        user_payment_id: &str,
+    ) -> Result<Option<BillingAddress>, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/billingAddress/getByPayment",
+                self.endpoint
+            ))
+            .form(&[
+                ("aid", self.app_id.as_str()),
+                ("user_payment_id", user_payment_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<UserBillingAddressResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.user_billing_address))
+    }
+
+    /// Get the billing address of a payment method (user payment info).
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2FgetByPaymentInfo)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_billing_address_by_payment_info(
+        &self,
+        user_payment_info_id: &str,
+    ) -> Result<Option<BillingAddress>, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/billingAddress/getByPaymentInfo",
+                self.endpoint
+            ))
+            .form(&[
+                ("aid", self.app_id.as_str()),
+                ("user_payment_info_id", user_payment_info_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<UserBillingAddressResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.user_billing_address))
+    }
+
+    /// List the billing addresses of a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_billing_addresses(
+        &self,
+        uid: &str,
+    ) -> Result<Vec<BillingAddress>, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/billingAddress/list",
+                self.endpoint
+            ))
+            .form(&[("aid", self.app_id.as_str()), ("uid", uid)])
+            .send()
+            .await?
+            .json::<PianoResponse<ListBillingAddressResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_billing_addresses)
+    }
+}
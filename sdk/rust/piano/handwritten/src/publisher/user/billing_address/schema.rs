@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use crate::publisher::payment::BillingAddress;
+
+/// Billing address provider type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BillingAddressProvider {
+    Experian,
+    Avalara,
+}
+
+impl Serialize for BillingAddressProvider {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Experian => serializer.serialize_u8(1),
+            Self::Avalara => serializer.serialize_u8(2),
+        }
+    }
+}
+
+/// Request to create a billing address for a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbillingAddress~2Fcreate)
+#[derive(Debug, Serialize)]
+pub struct CreateBillingAddressRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The country of the billing address (as JSON string)
+    pub country: &'a str,
+    /// The region of the billing address (as JSON string)
+    pub region: &'a str,
+    /// The zip code of the billing address
+    pub postal_code: &'a str,
+    /// The address provider type
+    #[serde(rename = "type")]
+    pub provider: BillingAddressProvider,
+    /// The city of the billing address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<&'a str>,
+    /// Line one of the billing address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<&'a str>,
+    /// Line two of the billing address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<&'a str>,
+    /// Line three of the billing address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line3: Option<&'a str>,
+}
+
+impl<'a> CreateBillingAddressRequest<'a> {
+    /// Create a new create billing address request
+    pub fn new(
+        uid: &'a str,
+        country: &'a str,
+        region: &'a str,
+        postal_code: &'a str,
+        provider: BillingAddressProvider,
+    ) -> Self {
+        Self {
+            uid,
+            country,
+            region,
+            postal_code,
+            provider,
+            city: None,
+            address_line1: None,
+            address_line2: None,
+            address_line3: None,
+        }
+    }
+
+    /// Set the city
+    pub fn with_city(mut self, city: &'a str) -> Self {
+        self.city = Some(city);
+        self
+    }
+
+    /// Set the first address line
+    pub fn with_address_line1(mut self, address_line1: &'a str) -> Self {
+        self.address_line1 = Some(address_line1);
+        self
+    }
+
+    /// Set the second address line
+    pub fn with_address_line2(mut self, address_line2: &'a str) -> Self {
+        self.address_line2 = Some(address_line2);
+        self
+    }
+
+    /// Set the third address line
+    pub fn with_address_line3(mut self, address_line3: &'a str) -> Self {
+        self.address_line3 = Some(address_line3);
+        self
+    }
+}
+
+/// Request identifying a billing address of a user, used by get and delete
+#[derive(Debug, Serialize)]
+pub struct BillingAddressRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The public ID of the address
+    pub address_pub_id: &'a str,
+}
+
+impl<'a> BillingAddressRequest<'a> {
+    /// Create a new billing address request
+    pub fn new(uid: &'a str, address_pub_id: &'a str) -> Self {
+        Self {
+            uid,
+            address_pub_id,
+        }
+    }
+}
+
+/// Response wrapper for billing address operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserBillingAddressResult {
+    #[serde(alias = "UserBillingAddress")]
+    pub user_billing_address: BillingAddress,
+}
+
+/// Response for billing address list operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ListBillingAddressResult {
+    #[serde(alias = "UserBillingAddress")]
+    pub user_billing_addresses: Vec<BillingAddress>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_billing_address_request() {
+        let request = CreateBillingAddressRequest::new(
+            "user123",
+            "US",
+            "NY",
+            "10001",
+            BillingAddressProvider::Avalara,
+        )
+        .with_city("New York")
+        .with_address_line1("1 Main St");
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "uid": "user123",
+                "country": "US",
+                "region": "NY",
+                "postal_code": "10001",
+                "type": 2,
+                "city": "New York",
+                "address_line1": "1 Main St"
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_billing_address_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "UserBillingAddress": [{
+                "address_pub_id": "ADXXXXXXXX",
+                "country": { "country_code": "US", "pub_id": "CXXXXXXXXX" },
+                "postal_code": "10001",
+                "type": "2",
+                "verified": "false"
+            }]
+        });
+
+        let result = serde_json::from_value::<crate::PianoResponse<ListBillingAddressResult>>(json)
+            .expect("Failed to deserialize billing address list")
+            .value()
+            .expect("OK");
+        assert_eq!(result.user_billing_addresses.len(), 1);
+        assert!(!result.user_billing_addresses[0].is_verified());
+    }
+}
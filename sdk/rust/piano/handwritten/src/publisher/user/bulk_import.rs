@@ -0,0 +1,225 @@
+mod schema;
+pub use self::schema::*;
+
+use std::{
+    io::ErrorKind,
+    time::{Duration, Instant},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{ByteStream, PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// List the bulk user imports which are still being processed.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbulkImport~2FlistProcessing)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_processing_bulk_imports(
+        &self,
+    ) -> Result<Vec<BulkUserImportProcessing>, crate::Error> {
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/bulkImport/listProcessing",
+                self.endpoint
+            ))
+            .form(&[("aid", &self.app_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<ListProcessingBulkImportResult>>()
+            .await?
+            .value()?;
+        Ok(result.bulk_user_imports)
+    }
+
+    /// List the completed bulk user imports.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbulkImport~2FlistCompleted)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_completed_bulk_imports(
+        &self,
+        params: &ListCompletedBulkImportRequest<'_>,
+    ) -> Result<PianoPaginated<ListCompletedBulkImportResult>, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/bulkImport/listCompleted",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListCompletedBulkImportResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// Delete a completed bulk user import along with its report.
+    ///
+    /// Imported users are not affected.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbulkImport~2Fdelete)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_bulk_import(
+        &self,
+        bulk_user_import_id: &str,
+    ) -> Result<bool, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: BulkImportIdRequest {
+                bulk_user_import_id,
+            },
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/bulkImport/delete",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<BulkImportBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Get the URL to download the report of a bulk user import.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbulkImport~2Fdownload)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_bulk_import_download_url(
+        &self,
+        bulk_user_import_id: &str,
+    ) -> Result<String, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: BulkImportIdRequest {
+                bulk_user_import_id,
+            },
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/user/bulkImport/download",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<BulkImportDownloadResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Download the CSV report of a bulk user import as a byte stream.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn download_bulk_import(
+        &self,
+        bulk_user_import_id: &str,
+    ) -> Result<ByteStream, crate::Error> {
+        let url = self
+            .get_bulk_import_download_url(bulk_user_import_id)
+            .await?;
+        self.download_stream(&url).await
+    }
+
+    /// Download the CSV report of a bulk user import and parse it into rows.
+    ///
+    /// Columns are matched to fields of `T` by header name. Use
+    /// `HashMap<String, String>` to read the rows without a dedicated type.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn download_bulk_import_rows<T: DeserializeOwned>(
+        &self,
+        bulk_user_import_id: &str,
+    ) -> Result<Vec<T>, crate::Error> {
+        let url = self
+            .get_bulk_import_download_url(bulk_user_import_id)
+            .await?;
+        self.download_csv(&url).await
+    }
+
+    /// Find a completed bulk user import by ID.
+    ///
+    /// Pages through completed imports, most recent first.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn find_completed_bulk_import(
+        &self,
+        bulk_user_import_id: &str,
+    ) -> Result<Option<BulkUserImport>, crate::Error> {
+        let mut params = ListCompletedBulkImportRequest::new()
+            .with_order_by("create_date")
+            .with_order_direction("desc");
+        loop {
+            let page = self.list_completed_bulk_imports(&params).await?;
+            let found = page
+                .value
+                .bulk_user_imports
+                .iter()
+                .find(|import| import.bulk_user_import_id() == bulk_user_import_id);
+            if let Some(import) = found {
+                return Ok(Some(import.clone()));
+            }
+            if !page.has_next() {
+                return Ok(None);
+            }
+            params = params.with_offset(page.offset + page.count);
+        }
+    }
+
+    /// Wait until a bulk user import has completed.
+    ///
+    /// Polls the processing and completed import lists every `poll_interval` and returns
+    /// the completed import. Returns an [`ErrorKind::TimedOut`] error if the import has not
+    /// completed within `timeout`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn wait_for_bulk_import_completion(
+        &self,
+        bulk_user_import_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<BulkUserImport, crate::Error> {
+        let started = Instant::now();
+        loop {
+            let processing = self.list_processing_bulk_imports().await?;
+            match processing
+                .iter()
+                .find(|import| import.bulk_user_import_id() == bulk_user_import_id)
+            {
+                Some(_import) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        processed = _import.processed_user_count(),
+                        total = _import.total_user_count(),
+                        "bulk user import in progress"
+                    );
+                }
+                None => {
+                    if let Some(import) =
+                        self.find_completed_bulk_import(bulk_user_import_id).await?
+                    {
+                        return Ok(import);
+                    }
+                }
+            }
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(Box::new(std::io::Error::new(
+                    ErrorKind::TimedOut,
+                    format!(
+                        "bulk user import {bulk_user_import_id} did not complete within {timeout:?}"
+                    ),
+                )));
+            }
+            tokio::time::sleep(poll_interval.min(timeout - elapsed)).await;
+        }
+    }
+}
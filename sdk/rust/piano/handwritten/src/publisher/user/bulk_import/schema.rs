@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to list completed bulk user imports
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2FbulkImport~2FlistCompleted)
+#[derive(Debug, Serialize)]
+pub struct ListCompletedBulkImportRequest<'a> {
+    /// Field to order by (`create_date`, `update_date` or `completion_date`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListCompletedBulkImportRequest<'a> {
+    /// Create a new list completed bulk imports request
+    pub fn new() -> Self {
+        Self {
+            order_by: None,
+            order_direction: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListCompletedBulkImportRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Request identifying a bulk user import
+#[derive(Debug, Serialize)]
+pub(super) struct BulkImportIdRequest<'a> {
+    pub bulk_user_import_id: &'a str,
+}
+
+/// Completed bulk user import
+#[derive(Debug, Deserialize, Clone)]
+pub struct BulkUserImport {
+    bulk_user_import_id: String,
+    bulk_user_import_created: i64,
+    #[serde(default)]
+    bulk_user_import_completed: Option<i64>,
+}
+
+impl BulkUserImport {
+    /// Get the bulk user import ID
+    pub fn bulk_user_import_id(&self) -> &str {
+        &self.bulk_user_import_id
+    }
+
+    /// Get the creation date
+    pub fn created(&self) -> i64 {
+        self.bulk_user_import_created
+    }
+
+    /// Get the completion date
+    pub fn completed(&self) -> Option<i64> {
+        self.bulk_user_import_completed
+    }
+}
+
+/// Bulk user import which is still being processed
+#[derive(Debug, Deserialize, Clone)]
+pub struct BulkUserImportProcessing {
+    bulk_user_import_id: String,
+    bulk_user_import_created: i64,
+    #[serde(default)]
+    bulk_user_import_total_user_count: usize,
+    #[serde(default)]
+    bulk_user_import_processed_user_count: usize,
+}
+
+impl BulkUserImportProcessing {
+    /// Get the bulk user import ID
+    pub fn bulk_user_import_id(&self) -> &str {
+        &self.bulk_user_import_id
+    }
+
+    /// Get the creation date
+    pub fn created(&self) -> i64 {
+        self.bulk_user_import_created
+    }
+
+    /// Get the number of users in the import
+    pub fn total_user_count(&self) -> usize {
+        self.bulk_user_import_total_user_count
+    }
+
+    /// Get the number of users processed so far
+    pub fn processed_user_count(&self) -> usize {
+        self.bulk_user_import_processed_user_count
+    }
+
+    /// Get the fraction of users processed so far, between 0.0 and 1.0
+    pub fn progress(&self) -> f64 {
+        if self.bulk_user_import_total_user_count == 0 {
+            return 0.0;
+        }
+        self.bulk_user_import_processed_user_count as f64
+            / self.bulk_user_import_total_user_count as f64
+    }
+}
+
+/// Response for completed bulk import list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListCompletedBulkImportResult {
+    #[serde(alias = "BulkUserImport")]
+    pub bulk_user_imports: Vec<BulkUserImport>,
+}
+
+/// Response for processing bulk import list operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct ListProcessingBulkImportResult {
+    #[serde(alias = "BulkUserImportProcessingRequestDto")]
+    pub bulk_user_imports: Vec<BulkUserImportProcessing>,
+}
+
+/// Response wrapper for the download URL
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct BulkImportDownloadResult {
+    pub data: String,
+}
+
+/// Response wrapper for boolean operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct BulkImportBooleanResult {
+    pub data: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PianoPaginated, PianoResponse};
+
+    #[test]
+    fn test_list_completed_bulk_import_request_builder() {
+        let request = ListCompletedBulkImportRequest::new()
+            .with_order_by("completion_date")
+            .with_order_direction("desc")
+            .with_limit(10);
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "order_by": "completion_date",
+                "order_direction": "desc",
+                "offset": 0,
+                "limit": 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_processing_bulk_import_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "BulkUserImportProcessingRequestDto": [{
+                "bulk_user_import_id": "BUIXXXXXXX",
+                "bulk_user_import_created": 1640995200,
+                "bulk_user_import_total_user_count": 200,
+                "bulk_user_import_processed_user_count": 50
+            }]
+        });
+
+        let result = serde_json::from_value::<PianoResponse<ListProcessingBulkImportResult>>(json)
+            .expect("Failed to deserialize processing imports")
+            .value()
+            .expect("OK");
+        let import = &result.bulk_user_imports[0];
+        assert_eq!(import.bulk_user_import_id(), "BUIXXXXXXX");
+        assert_eq!(import.progress(), 0.25);
+    }
+
+    #[test]
+    fn test_list_completed_bulk_import_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "limit": 100,
+            "offset": 0,
+            "total": 1,
+            "count": 1,
+            "BulkUserImport": [{
+                "bulk_user_import_id": "BUIXXXXXXX",
+                "bulk_user_import_created": 1640995200,
+                "bulk_user_import_completed": 1640998800
+            }]
+        });
+
+        let result = serde_json::from_value::<
+            PianoResponse<PianoPaginated<ListCompletedBulkImportResult>>,
+        >(json)
+        .expect("Failed to deserialize completed imports")
+        .value()
+        .expect("OK");
+        assert_eq!(
+            result.value.bulk_user_imports[0].completed(),
+            Some(1640998800)
+        );
+    }
+}
@@ -0,0 +1,44 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{PianoAPI, PianoPaginated, PianoResponse};
+
+impl PianoAPI {
+    /// Get a mail log entry by ID.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Femail~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_user_email(&self, mail_log_id: &str) -> Result<Option<MailLog>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/user/email/get", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("mail_log_id", mail_log_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<MailLogResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.mail_log))
+    }
+
+    /// List the emails sent to a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Femail~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_user_emails(
+        &self,
+        params: &ListUserEmailRequest<'_>,
+    ) -> Result<PianoPaginated<ListMailLogResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/user/email/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListMailLogResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+
+use crate::publisher::user::User;
+
+/// Request to list the emails sent to users
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Femail~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListUserEmailRequest<'a> {
+    /// Filter by user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+    /// Whether to include the email body in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_body: Option<bool>,
+    /// Field to order by (`mail_log_id`, `date_created` or `date_opened`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListUserEmailRequest<'a> {
+    /// Create a new list user email request for a user
+    pub fn new(uid: &'a str) -> Self {
+        Self {
+            uid: Some(uid),
+            include_email_body: None,
+            order_by: None,
+            order_direction: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set whether to include the email body
+    pub fn with_include_email_body(mut self, include_email_body: bool) -> Self {
+        self.include_email_body = Some(include_email_body);
+        self
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Delivery status of an email
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MailStatus {
+    Unknown,
+    Mock,
+    Sent,
+    Queued,
+    Scheduled,
+    Rejected,
+    Invalid,
+    Error,
+    Delivered,
+    Bounce,
+}
+
+/// Log entry of an email sent to a user
+#[derive(Debug, Deserialize, Clone)]
+pub struct MailLog {
+    email_id: String,
+    #[serde(default)]
+    user: Option<User>,
+    #[serde(default)]
+    sender: Option<String>,
+    #[serde(default)]
+    recipient: Option<String>,
+    #[serde(default)]
+    reply_to: Option<String>,
+    #[serde(default)]
+    create_date: Option<String>,
+    #[serde(default)]
+    open_date: Option<String>,
+    status: MailStatus,
+    #[serde(default)]
+    status_localized: Option<String>,
+    #[serde(default)]
+    reject_reason: Option<String>,
+    #[serde(default)]
+    email_name: Option<String>,
+    #[serde(default)]
+    subject: Option<String>,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+impl MailLog {
+    /// Get the email ID
+    pub fn email_id(&self) -> &str {
+        &self.email_id
+    }
+
+    /// Get the recipient user
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Get the sender
+    pub fn sender(&self) -> Option<&str> {
+        self.sender.as_deref()
+    }
+
+    /// Get the recipient
+    pub fn recipient(&self) -> Option<&str> {
+        self.recipient.as_deref()
+    }
+
+    /// Get the reply-to address
+    pub fn reply_to(&self) -> Option<&str> {
+        self.reply_to.as_deref()
+    }
+
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<&str> {
+        self.create_date.as_deref()
+    }
+
+    /// Get the date the email was opened
+    pub fn open_date(&self) -> Option<&str> {
+        self.open_date.as_deref()
+    }
+
+    /// Get the delivery status
+    pub fn status(&self) -> &MailStatus {
+        &self.status
+    }
+
+    /// Get the localized delivery status
+    pub fn status_localized(&self) -> Option<&str> {
+        self.status_localized.as_deref()
+    }
+
+    /// Get the reject reason
+    pub fn reject_reason(&self) -> Option<&str> {
+        self.reject_reason.as_deref()
+    }
+
+    /// Get the email (template) name
+    pub fn email_name(&self) -> Option<&str> {
+        self.email_name.as_deref()
+    }
+
+    /// Get the subject
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    /// Get the body, if requested
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+}
+
+/// Response wrapper for mail log operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct MailLogResult {
+    #[serde(alias = "MailLog")]
+    pub mail_log: MailLog,
+}
+
+/// Response for mail log list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListMailLogResult {
+    #[serde(alias = "MailLog")]
+    pub mail_logs: Vec<MailLog>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PianoPaginated, PianoResponse};
+
+    #[test]
+    fn test_list_user_email_request_builder() {
+        let request = ListUserEmailRequest::new("user123")
+            .with_include_email_body(false)
+            .with_order_by("date_created")
+            .with_offset(20);
+
+        assert_eq!(request.uid, Some("user123"));
+        assert_eq!(request.include_email_body, Some(false));
+        assert_eq!(request.order_by, Some("date_created"));
+        assert_eq!(request.offset, 20);
+        assert_eq!(request.limit, 100);
+    }
+
+    #[test]
+    fn test_list_mail_log_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "limit": 100,
+            "offset": 0,
+            "total": 1,
+            "count": 1,
+            "MailLog": [{
+                "email_id": "MLXXXXXXXX",
+                "sender": "no-reply@example.com",
+                "recipient": "user@example.com",
+                "create_date": "2024-11-06T02:54:24.000+00:00",
+                "open_date": null,
+                "status": "delivered",
+                "email_name": "Welcome",
+                "subject": "Welcome!"
+            }]
+        });
+
+        let result =
+            serde_json::from_value::<PianoResponse<PianoPaginated<ListMailLogResult>>>(json)
+                .expect("Failed to deserialize mail log list")
+                .value()
+                .expect("OK");
+        let log = &result.value.mail_logs[0];
+        assert_eq!(log.email_id(), "MLXXXXXXXX");
+        assert_eq!(log.status(), &MailStatus::Delivered);
+        assert_eq!(log.body(), None);
+    }
+}
@@ -0,0 +1,98 @@
+mod schema;
+pub use self::schema::*;
+
+use crate::{PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Create a note on a user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fnote~2Fcreate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_user_note(
+        &self,
+        params: &CreateUserNoteRequest<'_>,
+    ) -> Result<UserNote, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/note/create", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserNoteResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_note)
+    }
+
+    /// Update a user note.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fnote~2Fupdate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_user_note(
+        &self,
+        params: &UpdateUserNoteRequest<'_>,
+    ) -> Result<UserNote, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/user/note/update", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserNoteResult>>()
+            .await?
+            .value()?;
+        Ok(result.user_note)
+    }
+
+    /// Get a user note by ID.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Fnote~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_user_note(
+        &self,
+        user_note_id: &str,
+    ) -> Result<Option<UserNote>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/user/note/get", self.endpoint))
+            .query(&[
+                ("aid", self.app_id.as_str()),
+                ("user_note_id", user_note_id),
+            ])
+            .send()
+            .await?
+            .json::<PianoResponse<UserNoteResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.user_note))
+    }
+
+    /// List user notes in the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Fnote~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_user_notes(
+        &self,
+        params: &ListUserNoteRequest<'_>,
+    ) -> Result<PianoPaginated<ListUserNoteResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/user/note/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListUserNoteResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+}
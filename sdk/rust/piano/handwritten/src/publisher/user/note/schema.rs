@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+
+use crate::publisher::user::User;
+
+/// Request to create a note on a user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fnote~2Fcreate)
+#[derive(Debug, Serialize)]
+pub struct CreateUserNoteRequest<'a> {
+    /// The user ID
+    pub uid: &'a str,
+    /// The note content
+    pub content: &'a str,
+    /// Prevent editing or deleting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<bool>,
+}
+
+impl<'a> CreateUserNoteRequest<'a> {
+    /// Create a new create user note request
+    pub fn new(uid: &'a str, content: &'a str) -> Self {
+        Self {
+            uid,
+            content,
+            readonly: None,
+        }
+    }
+
+    /// Set whether the note is read-only
+    pub fn with_readonly(mut self, readonly: bool) -> Self {
+        self.readonly = Some(readonly);
+        self
+    }
+}
+
+/// Request to update a user note
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fuser~2Fnote~2Fupdate)
+#[derive(Debug, Serialize)]
+pub struct UpdateUserNoteRequest<'a> {
+    /// The note ID
+    pub user_note_id: &'a str,
+    /// The note content
+    pub content: &'a str,
+    /// Prevent editing or deleting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<bool>,
+}
+
+impl<'a> UpdateUserNoteRequest<'a> {
+    /// Create a new update user note request
+    pub fn new(user_note_id: &'a str, content: &'a str) -> Self {
+        Self {
+            user_note_id,
+            content,
+            readonly: None,
+        }
+    }
+
+    /// Set whether the note is read-only
+    pub fn with_readonly(mut self, readonly: bool) -> Self {
+        self.readonly = Some(readonly);
+        self
+    }
+}
+
+/// Request to list user notes in the application
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fuser~2Fnote~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListUserNoteRequest<'a> {
+    /// Filter by user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<&'a str>,
+    /// Field to order by (`user_note_id`, `create_date` or `update_date`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<&'a str>,
+    /// Order direction (`asc` or `desc`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_direction: Option<&'a str>,
+    /// Search value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<&'a str>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListUserNoteRequest<'a> {
+    /// Create a new list user note request
+    pub fn new() -> Self {
+        Self {
+            uid: None,
+            order_by: None,
+            order_direction: None,
+            q: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Filter by user ID
+    pub fn with_uid(mut self, uid: &'a str) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = Some(order_direction);
+        self
+    }
+
+    /// Set the search query
+    pub fn with_query(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListUserNoteRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Note attached to a user, e.g. by customer support
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserNote {
+    user_note_id: String,
+    content: String,
+    #[serde(default, rename = "type")]
+    note_type: Option<String>,
+    #[serde(default)]
+    user: Option<User>,
+    #[serde(default)]
+    create_date: Option<String>,
+    #[serde(default)]
+    create_by: Option<User>,
+    #[serde(default)]
+    update_date: Option<String>,
+    #[serde(default)]
+    update_by: Option<User>,
+    #[serde(default)]
+    readonly: bool,
+}
+
+impl UserNote {
+    /// Get the note ID
+    pub fn user_note_id(&self) -> &str {
+        &self.user_note_id
+    }
+
+    /// Get the note content
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Get the note type
+    pub fn note_type(&self) -> Option<&str> {
+        self.note_type.as_deref()
+    }
+
+    /// Get the user the note is attached to
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<&str> {
+        self.create_date.as_deref()
+    }
+
+    /// Get the creator
+    pub fn create_by(&self) -> Option<&User> {
+        self.create_by.as_ref()
+    }
+
+    /// Get the update date
+    pub fn update_date(&self) -> Option<&str> {
+        self.update_date.as_deref()
+    }
+
+    /// Get the last updater
+    pub fn update_by(&self) -> Option<&User> {
+        self.update_by.as_ref()
+    }
+
+    /// Check if the note is read-only
+    pub fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+}
+
+/// Response wrapper for user note operations
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserNoteResult {
+    #[serde(alias = "UserNote")]
+    pub user_note: UserNote,
+}
+
+/// Response for user note list operations
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListUserNoteResult {
+    #[serde(alias = "UserNote")]
+    pub user_notes: Vec<UserNote>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_user_note_request_builder() {
+        let request = ListUserNoteRequest::new()
+            .with_uid("user123")
+            .with_order_by("create_date")
+            .with_order_direction("desc")
+            .with_limit(20);
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({
+                "uid": "user123",
+                "order_by": "create_date",
+                "order_direction": "desc",
+                "offset": 0,
+                "limit": 20
+            })
+        );
+    }
+
+    #[test]
+    fn test_user_note_deserialization() {
+        let json = serde_json::json!({
+            "code": 0,
+            "UserNote": {
+                "user_note_id": "UNXXXXXXXX",
+                "content": "Refunded after duplicate charge",
+                "type": "text",
+                "create_date": "2024-11-06T02:54:24.000+00:00",
+                "readonly": true
+            }
+        });
+
+        let result = serde_json::from_value::<crate::PianoResponse<UserNoteResult>>(json)
+            .expect("Failed to deserialize user note")
+            .value()
+            .expect("OK");
+        assert_eq!(result.user_note.user_note_id(), "UNXXXXXXXX");
+        assert_eq!(result.user_note.note_type(), Some("text"));
+        assert!(result.user_note.is_readonly());
+    }
+}
@@ -890,6 +890,48 @@ impl<'a> SearchUserRequest<'a> {
     }
 }
 
+/// Request parameters for registering a new user.
+#[derive(Debug, Serialize, Default)]
+pub struct RegisterUserRequest<'a> {
+    /// User's email address (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    /// User's first name (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    /// User's last name (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+}
+
+impl<'a> RegisterUserRequest<'a> {
+    /// Create a new user registration request with the given email.
+    pub fn new(email: &'a str) -> Self {
+        Self {
+            email: Some(email),
+            ..Default::default()
+        }
+    }
+
+    /// Set the first name for the user.
+    pub fn with_first_name(mut self, first_name: &'a str) -> Self {
+        self.first_name = Some(first_name);
+        self
+    }
+
+    /// Set the last name for the user.
+    pub fn with_last_name(mut self, last_name: &'a str) -> Self {
+        self.last_name = Some(last_name);
+        self
+    }
+}
+
+/// Response for user count operations.
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserCountResult {
+    pub data: usize,
+}
+
 /// Response wrapper for single user operations.
 #[derive(Debug, Deserialize, Clone)]
 pub(super) struct UserResult {
@@ -988,7 +1030,6 @@ impl CustomField {
                     str.trim_start_matches("[")
                         .trim_end_matches("]")
                         .split(",")
-                        .into_iter()
                         .map(Self::unquote_str)
                         .collect::<Vec<_>>(),
                 ),
@@ -1209,6 +1250,16 @@ impl User {
 mod tests {
     use super::*;
     use crate::{PianoPaginated, PianoResponse};
+
+    #[test]
+    fn test_register_user_request_builder() {
+        let request = RegisterUserRequest::new("user@example.com").with_first_name("John");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            serde_json::json!({ "email": "user@example.com", "first_name": "John" })
+        );
+    }
+
     #[test]
     fn sanity_check_custom_field_decoding() {
         let value = serde_json::json!([
@@ -1308,7 +1359,7 @@ mod tests {
                 }
               }
         );
-        let one = serde_json::to_value(&CustomFieldQuery::single_select_list_contains_exact(
+        let one = serde_json::to_value(CustomFieldQuery::single_select_list_contains_exact(
             "occupation_status",
             "Full-time work",
         ))
@@ -1327,7 +1378,7 @@ mod tests {
                 }
             }
         );
-        let one = serde_json::to_value(&CustomFieldQuery::text_like("Text", "Test")).expect("OK");
+        let one = serde_json::to_value(CustomFieldQuery::text_like("Text", "Test")).expect("OK");
         assert_eq!(one, value)
     }
     #[test]
@@ -1343,7 +1394,7 @@ mod tests {
                     }
                 }
         );
-        let result = serde_json::to_value(&CustomFieldQuery::date_between(
+        let result = serde_json::to_value(CustomFieldQuery::date_between(
             "age",
             "2025-03-03",
             "2025-03-12",
@@ -0,0 +1,97 @@
+/// Gift voucher endpoints for Piano Publisher API.
+///
+/// Vouchers are bought by a gifter and redeemed by a recipient with a redemption code.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use piano_handwritten_api::{PianoAPI, publisher::voucher::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// // Resend the undelivered vouchers of a gifter
+/// let vouchers = api.list_vouchers(&ListVoucherRequest::new("gifter_uid")).await?;
+/// for voucher in &vouchers.value.vouchers {
+///     if voucher.state() == VoucherState::Failed && voucher.is_resendable() {
+///         let request = ResendVoucherRequest::new(
+///             voucher.pub_id(),
+///             voucher.recipient_email().unwrap_or_default(),
+///             1700000000,
+///         );
+///         api.resend_voucher(&request).await?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+mod schema;
+pub use self::schema::*;
+
+use crate::{PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// Get a voucher.
+    ///
+    /// Returns `None` if the voucher is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fvoucher~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_voucher(&self, pub_id: &str) -> Result<Option<Voucher>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/voucher/get", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("pub_id", pub_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<VoucherResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.voucher))
+    }
+
+    /// List the vouchers bought by a gifter.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fvoucher~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_vouchers(
+        &self,
+        params: &ListVoucherRequest<'_>,
+    ) -> Result<PianoPaginated<ListVoucherResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/voucher/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListVoucherResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// Resend a voucher with a new redemption code.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fvoucher~2Fresend)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn resend_voucher(
+        &self,
+        params: &ResendVoucherRequest<'_>,
+    ) -> Result<Voucher, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!("{}/publisher/voucher/resend", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<VoucherResult>>()
+            .await?
+            .value()?;
+        Ok(result.voucher)
+    }
+}
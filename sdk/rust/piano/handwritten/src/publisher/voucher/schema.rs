@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+
+use crate::publisher::user::address::UserAddress;
+
+/// State of a voucher
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VoucherState {
+    Assigned,
+    Sending,
+    Delivered,
+    Failed,
+    Redeeming,
+    Redeemed,
+    Revoked,
+}
+
+/// Request to list the vouchers bought by a gifter
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fvoucher~2Flist)
+#[derive(Debug, Serialize, Clone)]
+pub struct ListVoucherRequest<'a> {
+    /// The gifter's user ID
+    pub uid: &'a str,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum number of results to return
+    pub limit: usize,
+}
+
+impl<'a> ListVoucherRequest<'a> {
+    /// Create a new list voucher request
+    pub fn new(uid: &'a str) -> Self {
+        Self {
+            uid,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the offset
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the limit
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// Request to resend a voucher with a new redemption code
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fvoucher~2Fresend)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResendVoucherRequest<'a> {
+    /// The public ID of the voucher
+    #[serde(rename = "pub_id")]
+    pub pub_id: &'a str,
+    /// The recipient's email address
+    pub recipient_mail: &'a str,
+    /// When to send the voucher (UNIX timestamp)
+    pub send_date: i64,
+    /// The recipient's name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_name: Option<&'a str>,
+    /// The message to the recipient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<&'a str>,
+}
+
+impl<'a> ResendVoucherRequest<'a> {
+    /// Create a new resend voucher request
+    pub fn new(pub_id: &'a str, recipient_mail: &'a str, send_date: i64) -> Self {
+        Self {
+            pub_id,
+            recipient_mail,
+            send_date,
+            recipient_name: None,
+            message: None,
+        }
+    }
+
+    /// Set the recipient's name
+    pub fn with_recipient_name(mut self, recipient_name: &'a str) -> Self {
+        self.recipient_name = Some(recipient_name);
+        self
+    }
+
+    /// Set the message to the recipient
+    pub fn with_message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+/// Gift voucher
+#[derive(Debug, Deserialize, Clone)]
+pub struct Voucher {
+    pub_id: String,
+    #[serde(default)]
+    code: Option<String>,
+    state: VoucherState,
+    #[serde(default)]
+    recipient_name: Option<String>,
+    #[serde(default)]
+    recipient_email: Option<String>,
+    #[serde(default)]
+    recipient_message: Option<String>,
+    #[serde(default)]
+    send_date: Option<i64>,
+    #[serde(default)]
+    create_date: Option<i64>,
+    #[serde(default)]
+    expire_date: Option<i64>,
+    #[serde(default)]
+    redeemed: Option<i64>,
+    #[serde(default)]
+    revoke_date: Option<i64>,
+    #[serde(default)]
+    period: Option<String>,
+    #[serde(default)]
+    app_name: Option<String>,
+    #[serde(default)]
+    term_id: Option<String>,
+    #[serde(default)]
+    term_name: Option<String>,
+    #[serde(default)]
+    term_type: Option<String>,
+    #[serde(default)]
+    resource_name: Option<String>,
+    #[serde(default)]
+    price: Option<String>,
+    #[serde(default)]
+    transaction_id: Option<String>,
+    #[serde(default)]
+    is_revocable: bool,
+    #[serde(default)]
+    is_refundable: bool,
+    #[serde(default)]
+    is_resendable: bool,
+    #[serde(default)]
+    refund_amount: Option<String>,
+    #[serde(default)]
+    user_address: Option<UserAddress>,
+}
+
+impl Voucher {
+    /// Get the public ID of the voucher
+    pub fn pub_id(&self) -> &str {
+        &self.pub_id
+    }
+    /// Get the redemption code
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+    /// Get the state of the voucher
+    pub fn state(&self) -> VoucherState {
+        self.state
+    }
+    /// Get the recipient's name
+    pub fn recipient_name(&self) -> Option<&str> {
+        self.recipient_name.as_deref()
+    }
+    /// Get the recipient's email address
+    pub fn recipient_email(&self) -> Option<&str> {
+        self.recipient_email.as_deref()
+    }
+    /// Get the message to the recipient
+    pub fn recipient_message(&self) -> Option<&str> {
+        self.recipient_message.as_deref()
+    }
+    /// Get the date when the voucher was sent
+    pub fn send_date(&self) -> Option<i64> {
+        self.send_date
+    }
+    /// Get the creation date
+    pub fn create_date(&self) -> Option<i64> {
+        self.create_date
+    }
+    /// Get the expiration date
+    pub fn expire_date(&self) -> Option<i64> {
+        self.expire_date
+    }
+    /// Get the date when the voucher was redeemed
+    pub fn redeem_date(&self) -> Option<i64> {
+        self.redeemed
+    }
+    /// Get the date when the voucher was revoked
+    pub fn revoke_date(&self) -> Option<i64> {
+        self.revoke_date
+    }
+    /// Get the period of the voucher
+    pub fn period(&self) -> Option<&str> {
+        self.period.as_deref()
+    }
+    /// Get the application name
+    pub fn app_name(&self) -> Option<&str> {
+        self.app_name.as_deref()
+    }
+    /// Get the term ID
+    pub fn term_id(&self) -> Option<&str> {
+        self.term_id.as_deref()
+    }
+    /// Get the term name
+    pub fn term_name(&self) -> Option<&str> {
+        self.term_name.as_deref()
+    }
+    /// Get the term type
+    pub fn term_type(&self) -> Option<&str> {
+        self.term_type.as_deref()
+    }
+    /// Get the resource name
+    pub fn resource_name(&self) -> Option<&str> {
+        self.resource_name.as_deref()
+    }
+    /// Get the price
+    pub fn price(&self) -> Option<&str> {
+        self.price.as_deref()
+    }
+    /// Get the transaction ID
+    pub fn transaction_id(&self) -> Option<&str> {
+        self.transaction_id.as_deref()
+    }
+    /// Check if the voucher can be revoked
+    pub fn is_revocable(&self) -> bool {
+        self.is_revocable
+    }
+    /// Check if the voucher payment can be refunded
+    pub fn is_refundable(&self) -> bool {
+        self.is_refundable
+    }
+    /// Check if the voucher can be resent
+    pub fn is_resendable(&self) -> bool {
+        self.is_resendable
+    }
+    /// Get the refund amount
+    pub fn refund_amount(&self) -> Option<&str> {
+        self.refund_amount.as_deref()
+    }
+    /// Get the address of the gifter
+    pub fn user_address(&self) -> Option<&UserAddress> {
+        self.user_address.as_ref()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct VoucherResult {
+    #[serde(rename = "Voucher")]
+    pub voucher: Voucher,
+}
+
+/// Result of a list voucher request
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListVoucherResult {
+    #[serde(rename = "Voucher", default)]
+    pub vouchers: Vec<Voucher>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_resend_voucher_request() {
+        let request = ResendVoucherRequest::new("VC1", "friend@example.com", 1700000000)
+            .with_recipient_name("Friend");
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "pub_id": "VC1",
+                "recipientMail": "friend@example.com",
+                "sendDate": 1700000000,
+                "recipientName": "Friend",
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_voucher() {
+        let result: ListVoucherResult = serde_json::from_value(json!({
+            "Voucher": [{
+                "pub_id": "VC1",
+                "code": "ABCD-EFGH",
+                "state": "delivered",
+                "state_label": "Delivered",
+                "recipient_name": "Friend",
+                "recipient_email": "friend@example.com",
+                "recipient_message": "Enjoy",
+                "send_date": 1700000000,
+                "create_date": 1700000000,
+                "expires": "Never",
+                "expire_date": null,
+                "redeemed": null,
+                "revoke_date": null,
+                "period": "1 year",
+                "app_name": "News",
+                "term_name": "Gift",
+                "term_type": "gift",
+                "term_id": "TM1",
+                "resource_name": "Premium",
+                "price": "$100.00",
+                "transaction_id": "TX1",
+                "is_revocable": true,
+                "is_refundable": false,
+                "is_resendable": true,
+                "refund_amount": null,
+                "refund_amount_recalculated": false,
+                "user_address": null
+            }]
+        }))
+        .expect("OK");
+        let voucher = &result.vouchers[0];
+        assert_eq!(voucher.state(), VoucherState::Delivered);
+        assert_eq!(voucher.code(), Some("ABCD-EFGH"));
+        assert!(voucher.is_resendable());
+    }
+}
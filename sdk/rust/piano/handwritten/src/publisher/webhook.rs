@@ -0,0 +1,287 @@
+/// Webhook management endpoints for Piano Publisher API.
+///
+/// This module provides functionality to inspect webhook deliveries of the last 30 days,
+/// resend or skip them, and configure the webhook endpoint of the application. To decode
+/// the webhooks received by the endpoint, see the `webhook` module of the crate root.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use piano_handwritten_api::{PianoAPI, publisher::webhook::*};
+///
+/// # async fn example() -> Result<(), piano_handwritten_api::Error> {
+/// let api = PianoAPI::new("https://api-us.piano.io/api/v3", "your_app_id", "your_token");
+///
+/// // Resend the webhooks which failed during an outage, 20 at a time
+/// let request = ReplayWebhooksRequest::new(1700000000, 1700086400)
+///     .with_batch_size(20)
+///     .with_batch_interval(Duration::from_secs(1));
+/// let report = api.replay_failed_webhooks(&request).await?;
+/// println!("{} replayed, {} still failing", report.total(), report.failed.len());
+/// # Ok(())
+/// # }
+/// ```
+mod schema;
+pub use self::schema::*;
+
+use futures_util::{future, stream, Stream, TryStreamExt};
+
+use crate::{PianoAPI, PianoPaginated, PianoRequest, PianoResponse};
+
+impl PianoAPI {
+    /// List webhook events of the last 30 days.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_webhooks(
+        &self,
+        params: &ListWebhookRequest<'_>,
+    ) -> Result<PianoPaginated<ListWebhookResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListWebhookResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// List all webhook events matching the request as a stream.
+    ///
+    /// Pages are requested lazily, starting from the offset of `params`, until all
+    /// webhook events have been returned.
+    pub fn list_webhooks_stream<'a>(
+        &'a self,
+        params: &ListWebhookRequest<'a>,
+    ) -> impl Stream<Item = Result<WebhookEvent, crate::Error>> + 'a {
+        stream::try_unfold(Some(params.clone()), move |params| async move {
+            let Some(params) = params else {
+                return Ok::<_, crate::Error>(None);
+            };
+            let page = self.list_webhooks(&params).await?;
+            let next = page.next_offset().map(|offset| params.with_offset(offset));
+            let events = stream::iter(page.value.webhook_events.into_iter().map(Ok));
+            Ok(Some((events, next)))
+        })
+        .try_flatten()
+    }
+
+    /// Get a webhook event.
+    ///
+    /// Returns `None` if the webhook event is not found.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<Option<WebhookEvent>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/get", self.endpoint))
+            .query(&WebhookIdRequest { webhook_id })
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookEventResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|r| r.webhook_event))
+    }
+
+    /// List the webhook event types which can be sent.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fevents)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_webhook_event_types(
+        &self,
+        skip_deprecated_webhooks: bool,
+    ) -> Result<Vec<String>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/events", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(&[("skip_deprecated_webhooks", skip_deprecated_webhooks)])
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookEventTypesResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Get the health of the webhook endpoint of the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fstatus)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_webhook_status(&self) -> Result<WebhookStatus, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/status", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookStatusResult>>()
+            .await?
+            .value()?;
+        Ok(result.webhook_status)
+    }
+
+    /// Skip a webhook event, so that it is not retried anymore.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fskip)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn skip_webhook(&self, webhook_id: &str) -> Result<WebhookEvent, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/skip", self.endpoint))
+            .query(&WebhookIdRequest { webhook_id })
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookEventResult>>()
+            .await?
+            .value()?;
+        Ok(result.webhook_event)
+    }
+
+    /// List the delivery attempts of a webhook event.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fresponse~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_webhook_responses(
+        &self,
+        params: &ListWebhookResponseRequest<'_>,
+    ) -> Result<PianoPaginated<ListWebhookResponseResult>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/response/list", self.endpoint))
+            .query(params)
+            .send()
+            .await?
+            .json::<PianoResponse<PianoPaginated<ListWebhookResponseResult>>>()
+            .await?
+            .value()?;
+        Ok(result)
+    }
+
+    /// Resend a webhook event and return the delivery attempt.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fresponse~2Fresend)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn resend_webhook(&self, webhook_id: &str) -> Result<WebhookResponse, crate::Error> {
+        let result = self
+            .client
+            .get(format!(
+                "{}/publisher/webhook/response/resend",
+                self.endpoint
+            ))
+            .query(&WebhookIdRequest { webhook_id })
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookResponseResult>>()
+            .await?
+            .value()?;
+        Ok(result.webhook_response)
+    }
+
+    /// Get the webhook settings of the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fsettings)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_webhook_settings(&self) -> Result<WebhookSettings, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/publisher/webhook/settings", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookSettingsResult>>()
+            .await?
+            .value()?;
+        Ok(result.webhook_settings)
+    }
+
+    /// Update the webhook settings of the application.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fwebhook~2Fsettings~2Fupdate)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_webhook_settings(
+        &self,
+        params: &UpdateWebhookSettingsRequest<'_>,
+    ) -> Result<bool, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: params,
+        };
+        let result = self
+            .client
+            .post(format!(
+                "{}/publisher/webhook/settings/update",
+                self.endpoint
+            ))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<WebhookBooleanResult>>()
+            .await?
+            .value()?;
+        Ok(result.data)
+    }
+
+    /// Resend the failed webhook events created within a time window.
+    ///
+    /// Failed events are collected before any of them is resent, as resending changes their
+    /// status and would shift the pages of the listing. They are then resent in batches of
+    /// `batch_size` concurrent requests, optionally pausing between batches so that a
+    /// recovering webhook endpoint is not overwhelmed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn replay_failed_webhooks(
+        &self,
+        params: &ReplayWebhooksRequest<'_>,
+    ) -> Result<WebhookReplayReport, crate::Error> {
+        let mut list = ListWebhookRequest::new()
+            .with_status(WebhookEventStatus::Failed)
+            .with_order_by("create_date")
+            .with_order_direction("desc");
+        if let Some(event_types) = params.event_types {
+            list = list.with_event_types(event_types);
+        }
+        let webhook_ids: Vec<String> = self
+            .list_webhooks_stream(&list)
+            .try_take_while(|event| future::ready(Ok(event.create_date() >= params.from)))
+            .try_filter(|event| future::ready(params.contains(event.create_date())))
+            .map_ok(|event| event.webhook_id().to_string())
+            .try_collect()
+            .await?;
+
+        let mut report = WebhookReplayReport::default();
+        for (index, batch) in webhook_ids.chunks(params.batch_size.max(1)).enumerate() {
+            if index > 0 {
+                if let Some(batch_interval) = params.batch_interval {
+                    tokio::time::sleep(batch_interval).await;
+                }
+            }
+            let responses = future::join_all(
+                batch
+                    .iter()
+                    .map(|webhook_id| self.resend_webhook(webhook_id)),
+            )
+            .await;
+            for (webhook_id, response) in batch.iter().zip(responses) {
+                match response {
+                    Ok(response) if response.is_succeeded() => {
+                        report.succeeded.push(webhook_id.clone())
+                    }
+                    Ok(_) => report.failed.push(webhook_id.clone()),
+                    Err(error) => report.errors.push((webhook_id.clone(), error.to_string())),
+                }
+            }
+        }
+        Ok(report)
+    }
+}
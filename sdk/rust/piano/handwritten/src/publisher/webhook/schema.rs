@@ -0,0 +1,707 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::publisher::user::User;
+
+/// Delivery status of a webhook event
+///
+/// The list endpoint filters by the upper case status while events carry the lower case one.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all(serialize = "UPPERCASE", deserialize = "lowercase"))]
+pub enum WebhookEventStatus {
+    Inactive,
+    Pending,
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+/// Status of a single webhook delivery attempt
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookResponseStatus {
+    Succeeded,
+    Failed,
+}
+
+/// Health of the webhook endpoint of the application
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookHealth {
+    Disabled,
+    Failure,
+    Normal,
+}
+
+/// Request to list webhook events of the last 30 days
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Flist)
+#[derive(Debug, Serialize, Clone)]
+pub struct ListWebhookRequest<'a> {
+    /// Filter by delivery status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<WebhookEventStatus>,
+    /// Search events by keyword
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<&'a str>,
+    /// Field to order by (`webhook_id`, `status`, `retried`, `create_date`, `update_date`,
+    /// `user_email` or `event_type`)
+    pub order_by: &'a str,
+    /// Order direction (`asc` or `desc`)
+    pub order_direction: &'a str,
+    /// Filter by webhook event types (e.g. `access_granted`)
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub event_type: Option<&'a [&'a str]>,
+    /// Filter by webhook events (e.g. `new_purchase`)
+    #[serde(
+        rename = "type",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_str_slice"
+    )]
+    pub event: Option<&'a [&'a str]>,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListWebhookRequest<'a> {
+    /// Create a new list webhook events request, ordered by creation date descending
+    pub fn new() -> Self {
+        Self {
+            status: None,
+            keyword: None,
+            order_by: "create_date",
+            order_direction: "desc",
+            event_type: None,
+            event: None,
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the delivery status filter
+    pub fn with_status(mut self, status: WebhookEventStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the search keyword
+    pub fn with_keyword(mut self, keyword: &'a str) -> Self {
+        self.keyword = Some(keyword);
+        self
+    }
+
+    /// Set the field to order by
+    pub fn with_order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = order_by;
+        self
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = order_direction;
+        self
+    }
+
+    /// Set the webhook event types filter
+    pub fn with_event_types(mut self, event_types: &'a [&'a str]) -> Self {
+        self.event_type = Some(event_types);
+        self
+    }
+
+    /// Set the webhook events filter
+    pub fn with_events(mut self, events: &'a [&'a str]) -> Self {
+        self.event = Some(events);
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for ListWebhookRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Request to list the delivery attempts of a webhook event
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fpublisher~2Fwebhook~2Fresponse~2Flist)
+#[derive(Debug, Serialize)]
+pub struct ListWebhookResponseRequest<'a> {
+    /// The webhook ID
+    pub webhook_id: &'a str,
+    /// Field to order by (`create_date`)
+    pub order_by: &'a str,
+    /// Order direction (`asc` or `desc`)
+    pub order_direction: &'a str,
+    /// Offset from which to start returning results
+    pub offset: usize,
+    /// Maximum index of returned results
+    pub limit: usize,
+}
+
+impl<'a> ListWebhookResponseRequest<'a> {
+    /// Create a new list webhook responses request, latest attempts first
+    pub fn new(webhook_id: &'a str) -> Self {
+        Self {
+            webhook_id,
+            order_by: "create_date",
+            order_direction: "desc",
+            offset: 0,
+            limit: 100,
+        }
+    }
+
+    /// Set the order direction
+    pub fn with_order_direction(mut self, order_direction: &'a str) -> Self {
+        self.order_direction = order_direction;
+        self
+    }
+
+    /// Set the limit for pagination
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset for pagination
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Request to update the webhook settings of the application
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fpublisher~2Fwebhook~2Fsettings~2Fupdate)
+#[derive(Debug, Serialize)]
+pub struct UpdateWebhookSettingsRequest<'a> {
+    /// The webhook endpoint URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+    /// Whether the webhook endpoint is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The webhook configs, sent as JSON
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_configs"
+    )]
+    pub configs: Option<&'a [WebhookConfig]>,
+}
+
+impl<'a> UpdateWebhookSettingsRequest<'a> {
+    /// Create a new update webhook settings request
+    pub fn new() -> Self {
+        Self {
+            url: None,
+            enabled: None,
+            configs: None,
+        }
+    }
+
+    /// Set the webhook endpoint URL
+    pub fn with_url(mut self, url: &'a str) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Set whether the webhook endpoint is enabled
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Set the webhook configs
+    pub fn with_configs(mut self, configs: &'a [WebhookConfig]) -> Self {
+        self.configs = Some(configs);
+        self
+    }
+}
+
+impl Default for UpdateWebhookSettingsRequest<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options of [`PianoAPI::replay_failed_webhooks`](crate::PianoAPI::replay_failed_webhooks)
+#[derive(Debug, Clone)]
+pub struct ReplayWebhooksRequest<'a> {
+    /// Start of the time window (UNIX timestamp, inclusive)
+    pub from: i64,
+    /// End of the time window (UNIX timestamp, exclusive)
+    pub to: i64,
+    /// Only replay these webhook event types
+    pub event_types: Option<&'a [&'a str]>,
+    /// Number of webhooks resent concurrently
+    pub batch_size: usize,
+    /// Pause between two batches
+    pub batch_interval: Option<Duration>,
+}
+
+impl<'a> ReplayWebhooksRequest<'a> {
+    /// Create a new replay request for webhooks created between `from` and `to`
+    pub fn new(from: i64, to: i64) -> Self {
+        Self {
+            from,
+            to,
+            event_types: None,
+            batch_size: 10,
+            batch_interval: None,
+        }
+    }
+
+    /// Only replay these webhook event types
+    pub fn with_event_types(mut self, event_types: &'a [&'a str]) -> Self {
+        self.event_types = Some(event_types);
+        self
+    }
+
+    /// Set the number of webhooks resent concurrently
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the pause between two batches
+    pub fn with_batch_interval(mut self, batch_interval: Duration) -> Self {
+        self.batch_interval = Some(batch_interval);
+        self
+    }
+
+    /// Check if a timestamp falls within the time window
+    pub fn contains(&self, timestamp: i64) -> bool {
+        self.from <= timestamp && timestamp < self.to
+    }
+}
+
+/// Webhook event
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookEvent {
+    webhook_id: String,
+    status: WebhookEventStatus,
+    #[serde(default)]
+    status_localized: Option<String>,
+    #[serde(default)]
+    retried: Option<String>,
+    create_date: i64,
+    #[serde(default)]
+    update_date: Option<i64>,
+    #[serde(default)]
+    last_webhook_response: Option<WebhookResponse>,
+    #[serde(default)]
+    user: Option<User>,
+    #[serde(default, rename = "type")]
+    event_kind: Option<String>,
+    #[serde(default)]
+    type_localized: Option<String>,
+    #[serde(default)]
+    event: Option<String>,
+    #[serde(default)]
+    event_localized: Option<String>,
+    #[serde(default)]
+    event_type: Option<String>,
+    #[serde(default)]
+    responses_count: Option<i64>,
+}
+
+impl WebhookEvent {
+    /// Get the webhook ID
+    pub fn webhook_id(&self) -> &str {
+        &self.webhook_id
+    }
+
+    /// Get the delivery status
+    pub fn status(&self) -> WebhookEventStatus {
+        self.status
+    }
+
+    /// Get the localized delivery status
+    pub fn status_localized(&self) -> Option<&str> {
+        self.status_localized.as_deref()
+    }
+
+    /// Get the number of extra delivery attempts
+    pub fn retried(&self) -> usize {
+        self.retried
+            .as_deref()
+            .and_then(|retried| retried.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Get the creation date (UNIX timestamp)
+    pub fn create_date(&self) -> i64 {
+        self.create_date
+    }
+
+    /// Get the update date (UNIX timestamp)
+    pub fn update_date(&self) -> Option<i64> {
+        self.update_date
+    }
+
+    /// Get the last delivery attempt
+    pub fn last_webhook_response(&self) -> Option<&WebhookResponse> {
+        self.last_webhook_response.as_ref()
+    }
+
+    /// Get the user the event is about
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    /// Get the webhook type (e.g. `access_granted`)
+    pub fn webhook_type(&self) -> Option<&str> {
+        self.event_kind.as_deref()
+    }
+
+    /// Get the localized webhook type
+    pub fn type_localized(&self) -> Option<&str> {
+        self.type_localized.as_deref()
+    }
+
+    /// Get the webhook event (e.g. `new_purchase`)
+    pub fn event(&self) -> Option<&str> {
+        self.event.as_deref()
+    }
+
+    /// Get the localized webhook event
+    pub fn event_localized(&self) -> Option<&str> {
+        self.event_localized.as_deref()
+    }
+
+    /// Get the webhook event type
+    pub fn event_type(&self) -> Option<&str> {
+        self.event_type.as_deref()
+    }
+
+    /// Get the number of delivery attempts
+    pub fn responses_count(&self) -> i64 {
+        self.responses_count.unwrap_or_default()
+    }
+
+    /// Check if the delivery failed
+    pub fn is_failed(&self) -> bool {
+        self.status == WebhookEventStatus::Failed
+    }
+}
+
+/// Webhook delivery attempt
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookResponse {
+    status: WebhookResponseStatus,
+    #[serde(default)]
+    status_localized: Option<String>,
+    #[serde(default)]
+    response_headers: Option<String>,
+    #[serde(default)]
+    response_body: Option<String>,
+    create_date: i64,
+    #[serde(default)]
+    request_url: Option<String>,
+    #[serde(default)]
+    request_data: Option<String>,
+}
+
+impl WebhookResponse {
+    /// Get the delivery status
+    pub fn status(&self) -> WebhookResponseStatus {
+        self.status
+    }
+
+    /// Get the localized delivery status
+    pub fn status_localized(&self) -> Option<&str> {
+        self.status_localized.as_deref()
+    }
+
+    /// Get the headers returned by the webhook endpoint
+    pub fn response_headers(&self) -> Option<&str> {
+        self.response_headers.as_deref()
+    }
+
+    /// Get the body returned by the webhook endpoint
+    pub fn response_body(&self) -> Option<&str> {
+        self.response_body.as_deref()
+    }
+
+    /// Get the date of the attempt (UNIX timestamp)
+    pub fn create_date(&self) -> i64 {
+        self.create_date
+    }
+
+    /// Get the requested URL
+    pub fn request_url(&self) -> Option<&str> {
+        self.request_url.as_deref()
+    }
+
+    /// Get the sent data
+    pub fn request_data(&self) -> Option<&str> {
+        self.request_data.as_deref()
+    }
+
+    /// Check if the delivery succeeded
+    pub fn is_succeeded(&self) -> bool {
+        self.status == WebhookResponseStatus::Succeeded
+    }
+}
+
+/// Webhook settings of the application
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookSettings {
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    configs: Vec<WebhookConfig>,
+}
+
+impl WebhookSettings {
+    /// Get the webhook endpoint URL
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Check if the webhook endpoint is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Get the webhook configs
+    pub fn configs(&self) -> &[WebhookConfig] {
+        &self.configs
+    }
+}
+
+/// Whether a webhook event is sent
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct WebhookConfig {
+    /// The webhook event (e.g. `new_purchase`)
+    pub key: String,
+    /// The label of the webhook event
+    pub label: String,
+    /// Whether the webhook event is sent
+    pub enabled: bool,
+    /// The webhook type (e.g. `access_granted`)
+    #[serde(rename = "type")]
+    pub webhook_type: String,
+}
+
+/// Webhook endpoint health of the application
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookStatus {
+    status: WebhookHealth,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+impl WebhookStatus {
+    /// Get the health of the webhook endpoint
+    pub fn status(&self) -> WebhookHealth {
+        self.status
+    }
+
+    /// Get the description of the status
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+/// Outcome of [`PianoAPI::replay_failed_webhooks`](crate::PianoAPI::replay_failed_webhooks)
+#[derive(Debug, Clone, Default)]
+pub struct WebhookReplayReport {
+    /// Webhooks delivered successfully
+    pub succeeded: Vec<String>,
+    /// Webhooks resent but rejected again by the webhook endpoint
+    pub failed: Vec<String>,
+    /// Webhooks which could not be resent, with the error
+    pub errors: Vec<(String, String)>,
+}
+
+impl WebhookReplayReport {
+    /// Get the number of webhooks which were replayed
+    pub fn total(&self) -> usize {
+        self.succeeded.len() + self.failed.len() + self.errors.len()
+    }
+
+    /// Check if all webhooks were delivered successfully
+    pub fn is_all_succeeded(&self) -> bool {
+        self.failed.is_empty() && self.errors.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct WebhookIdRequest<'a> {
+    pub webhook_id: &'a str,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct WebhookEventResult {
+    #[serde(rename = "WebhookEvent")]
+    pub webhook_event: WebhookEvent,
+}
+
+/// Result of a list webhook events request
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListWebhookResult {
+    #[serde(rename = "WebhookEvent", default)]
+    pub webhook_events: Vec<WebhookEvent>,
+}
+
+/// Result of a list webhook responses request
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListWebhookResponseResult {
+    #[serde(rename = "WebhookResponse", default)]
+    pub webhook_responses: Vec<WebhookResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct WebhookResponseResult {
+    #[serde(rename = "WebhookResponse")]
+    pub webhook_response: WebhookResponse,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct WebhookSettingsResult {
+    pub webhook_settings: WebhookSettings,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct WebhookStatusResult {
+    pub webhook_status: WebhookStatus,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct WebhookEventTypesResult {
+    pub data: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct WebhookBooleanResult {
+    pub data: bool,
+}
+
+fn serialize_str_slice<S>(slice: &Option<&[&str]>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match slice {
+        Some(slice) => serializer.serialize_str(&slice.join(",")),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_configs<S>(
+    configs: &Option<&[WebhookConfig]>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match configs {
+        Some(configs) => {
+            let json = serde_json::to_string(configs).map_err(serde::ser::Error::custom)?;
+            serializer.serialize_str(&json)
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_list_webhook_request() {
+        let event_types = ["access_granted", "payment_refund"];
+        let request = ListWebhookRequest::new()
+            .with_status(WebhookEventStatus::Failed)
+            .with_event_types(&event_types)
+            .with_limit(50);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "status": "FAILED",
+                "order_by": "create_date",
+                "order_direction": "desc",
+                "event_type": "access_granted,payment_refund",
+                "offset": 0,
+                "limit": 50,
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_webhook_settings_request() {
+        let configs = [WebhookConfig {
+            key: "new_purchase".to_string(),
+            label: "New purchase".to_string(),
+            enabled: true,
+            webhook_type: "access_granted".to_string(),
+        }];
+        let request = UpdateWebhookSettingsRequest::new()
+            .with_enabled(true)
+            .with_configs(&configs);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({
+                "enabled": true,
+                "configs": r#"[{"key":"new_purchase","label":"New purchase","enabled":true,"type":"access_granted"}]"#,
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_webhook_event() {
+        let result: ListWebhookResult = serde_json::from_value(json!({
+            "WebhookEvent": [{
+                "webhook_id": "WH1",
+                "status": "failed",
+                "status_localized": "Failed",
+                "retried": "3",
+                "create_date": 1700000000,
+                "update_date": 1700000600,
+                "last_webhook_response": {
+                    "status": "failed",
+                    "status_localized": "Failed",
+                    "response_headers": "",
+                    "response_body": "Service Unavailable",
+                    "create_date": 1700000600,
+                    "request_url": "https://example.com/webhook",
+                    "request_data": "data=..."
+                },
+                "type": "access_granted",
+                "event": "new_purchase",
+                "event_type": "access_granted",
+                "responses_count": 4
+            }]
+        }))
+        .expect("OK");
+        let event = &result.webhook_events[0];
+        assert!(event.is_failed());
+        assert_eq!(event.retried(), 3);
+        assert_eq!(event.webhook_type(), Some("access_granted"));
+        assert_eq!(
+            event.last_webhook_response().map(|r| r.status()),
+            Some(WebhookResponseStatus::Failed)
+        );
+    }
+}
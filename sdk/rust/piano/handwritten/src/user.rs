@@ -0,0 +1,225 @@
+//! # Piano user API
+//!
+//! The `/user` endpoints are called on behalf of an end user: they authenticate with the
+//! user's token (e.g. a Piano ID access token) instead of the API token, so a backend can
+//! check the entitlements of the user who sent a request without any publisher privilege.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::{user::*, PianoAPI};
+//!
+//! # async fn example(user_token: &str) -> Result<(), piano_handwritten_api::Error> {
+//! let api = PianoAPI::new("https://sandbox.piano.io/api/v3", "your_app_id", "your_token");
+//! let user_api = api.user_api();
+//!
+//! // Load the profile of the user
+//! let user = user_api.get_user(user_token).await?;
+//!
+//! // List the resources the user has access to
+//! let accesses = user_api
+//!     .list_access(user_token, &ListUserAccessRequest::new())
+//!     .await?;
+//! for access in accesses.iter().filter(|access| access.is_granted()) {
+//!     println!("{} can access {}", user.uid(), access.resource().rid());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+mod schema;
+pub use self::schema::*;
+
+use crate::{
+    publisher::access::{Access, AccessUser},
+    PianoAPI, PianoRequest, PianoResponse,
+};
+
+/// Read the JWS of [`PianoUserAPI::check_access_jwt`], mapping an access denial to `None`
+fn access_jwt(response: PianoResponse<StringResult>) -> Result<Option<String>, crate::Error> {
+    match response {
+        // access denied
+        PianoResponse::Failure { code: 2, .. } => Ok(None),
+        response => Ok(Some(response.value()?.data).filter(|data| !data.is_empty())),
+    }
+}
+
+/// Client of the Piano user API, authenticating with the token of the end user.
+#[derive(Clone)]
+pub struct PianoUserAPI {
+    app_id: String,
+    endpoint: String,
+    user_provider: Option<String>,
+    client: reqwest::Client,
+}
+
+impl PianoUserAPI {
+    /// Create a user API client from the API base URL (e.g. `https://sandbox.piano.io/api/v3`)
+    /// and the application ID.
+    pub fn new(endpoint: &str, app_id: &str) -> Self {
+        Self {
+            app_id: app_id.to_string(),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            user_provider: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Create a user API client from the `PIANO_ENDPOINT` and `PIANO_APP_ID` environment
+    /// variables.
+    pub fn from_env() -> Self {
+        let endpoint = std::env::var("PIANO_ENDPOINT").expect("PIANO_ENDPOINT is not set");
+        let app_id = std::env::var("PIANO_APP_ID").expect("PIANO_APP_ID is not set");
+        Self::new(&endpoint, &app_id)
+    }
+
+    /// Set the user provider which issued the user tokens (e.g. `piano_id`)
+    pub fn with_user_provider(mut self, user_provider: &str) -> Self {
+        self.user_provider = Some(user_provider.to_string());
+        self
+    }
+
+    fn user_params<'a>(&'a self, user_token: &'a str) -> UserTokenParams<'a> {
+        UserTokenParams {
+            user_token,
+            user_provider: self.user_provider.as_deref(),
+        }
+    }
+
+    /// Get the access of the user to a resource as a JWS signed by Piano.
+    ///
+    /// Returns `None` if the user has no access to the resource; other failures, such as
+    /// an expired user token, are errors.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fuser~2Faccess~2Fcheck.jwt)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, user_token)))]
+    pub async fn check_access_jwt(
+        &self,
+        user_token: &str,
+        rid: &str,
+        cross_app: bool,
+    ) -> Result<Option<String>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/user/access/check.jwt", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("rid", rid)])
+            .query(&[("cross_app", cross_app)])
+            .query(&self.user_params(user_token))
+            .send()
+            .await?
+            .json::<PianoResponse<StringResult>>()
+            .await?;
+        access_jwt(result)
+    }
+
+    /// Get an access of the user by its ID.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fuser~2Faccess~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, user_token)))]
+    pub async fn get_access(
+        &self,
+        user_token: &str,
+        access_id: &str,
+    ) -> Result<Option<Access>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/user/access/get", self.endpoint))
+            .query(&[("aid", self.app_id.as_str()), ("access_id", access_id)])
+            .query(&self.user_params(user_token))
+            .send()
+            .await?
+            .json::<PianoResponse<AccessResult>>()
+            .await?
+            .maybe_value()?;
+        Ok(result.map(|result| result.access))
+    }
+
+    /// List the accesses of the user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fuser~2Faccess~2Flist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, user_token)))]
+    pub async fn list_access(
+        &self,
+        user_token: &str,
+        params: &ListUserAccessRequest,
+    ) -> Result<Vec<Access>, crate::Error> {
+        let result = self
+            .client
+            .get(format!("{}/user/access/list", self.endpoint))
+            .query(&[("aid", &self.app_id)])
+            .query(params)
+            .query(&self.user_params(user_token))
+            .send()
+            .await?
+            .json::<PianoResponse<AccessListResult>>()
+            .await?
+            .value()?;
+        Ok(result.accesses)
+    }
+
+    /// Get the profile of the user.
+    ///
+    /// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=post~2F~2Fuser~2Fget)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, user_token)))]
+    pub async fn get_user(&self, user_token: &str) -> Result<AccessUser, crate::Error> {
+        let req = PianoRequest {
+            aid: self.app_id.clone(),
+            inner: self.user_params(user_token),
+        };
+        let result = self
+            .client
+            .post(format!("{}/user/get", self.endpoint))
+            .form(&req)
+            .send()
+            .await?
+            .json::<PianoResponse<UserResult>>()
+            .await?
+            .value()?;
+        Ok(result.user)
+    }
+}
+
+impl std::fmt::Debug for PianoUserAPI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PianoUserAPI")
+            .field("app_id", &self.app_id)
+            .field("endpoint", &self.endpoint)
+            .field("user_provider", &self.user_provider)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PianoAPI {
+    /// Create a user API client for the application and endpoint of this client.
+    ///
+    /// The API token of this client is not sent by the user API client.
+    pub fn user_api(&self) -> PianoUserAPI {
+        PianoUserAPI::new(&self.endpoint, &self.app_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{access_jwt, PianoResponse, StringResult};
+
+    fn response(value: serde_json::Value) -> PianoResponse<StringResult> {
+        serde_json::from_value(value).expect("OK")
+    }
+
+    #[test]
+    fn test_access_jwt() {
+        let granted = response(json!({ "code": 0, "ts": 1700000000, "data": "eyJ.eyJ.sig" }));
+        assert_eq!(
+            access_jwt(granted).expect("OK").as_deref(),
+            Some("eyJ.eyJ.sig")
+        );
+
+        let denied = response(json!({ "code": 2, "ts": 1700000000, "message": "Access denied" }));
+        assert_eq!(access_jwt(denied).expect("OK"), None);
+
+        let expired =
+            response(json!({ "code": 401, "ts": 1700000000, "message": "Token expired" }));
+        assert!(access_jwt(expired).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::publisher::access::{Access, AccessUser};
+
+/// Request to list the accesses of the user
+///
+/// See: [Piano API Documentation](https://docs.piano.io/api?endpoint=get~2F~2Fuser~2Faccess~2Flist)
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ListUserAccessRequest {
+    /// Whether to expand bundled accesses in the response
+    pub expand_bundled: bool,
+    /// Whether to include cross application accesses
+    pub cross_app: bool,
+}
+
+impl ListUserAccessRequest {
+    /// Create a new list user access request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to expand bundled accesses
+    pub fn with_expand_bundled(mut self, expand_bundled: bool) -> Self {
+        self.expand_bundled = expand_bundled;
+        self
+    }
+
+    /// Set whether to include cross application accesses
+    pub fn with_cross_app(mut self, cross_app: bool) -> Self {
+        self.cross_app = cross_app;
+        self
+    }
+}
+
+#[derive(Serialize)]
+pub(super) struct UserTokenParams<'a> {
+    pub user_token: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_provider: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct StringResult {
+    pub data: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct AccessResult {
+    #[serde(alias = "Access")]
+    pub access: Access,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct AccessListResult {
+    #[serde(default, alias = "AccessDTO", alias = "data")]
+    pub accesses: Vec<Access>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub(super) struct UserResult {
+    #[serde(alias = "User")]
+    pub user: AccessUser,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_list_user_access_request() {
+        let request = ListUserAccessRequest::new().with_expand_bundled(true);
+        assert_eq!(
+            serde_json::to_value(&request).expect("OK"),
+            json!({ "expand_bundled": true, "cross_app": false })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_access_list() {
+        let result: AccessListResult = serde_json::from_value(json!({
+            "AccessDTO": [{
+                "access_id": "AC1",
+                "parent_access_id": "AC0",
+                "granted": true,
+                "user": { "uid": "PNI1", "email": "user@example.com" },
+                "resource": { "rid": "RID1", "aid": "AID", "name": "Premium" },
+                "expire_date": 1900000000,
+                "start_date": 1700000000,
+                "can_revoke_access": false,
+                "custom_data": null
+            }]
+        }))
+        .expect("OK");
+        assert_eq!(result.accesses.len(), 1);
+        assert_eq!(result.accesses[0].parent_access_id(), Some("AC0"));
+        assert_eq!(result.accesses[0].resource().name(), Some("Premium"));
+    }
+}
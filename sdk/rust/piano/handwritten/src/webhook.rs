@@ -0,0 +1,217 @@
+//! # Piano Webhooks
+//!
+//! Piano delivers webhooks with an encrypted `data` parameter. The payload is encrypted
+//! with AES-256 in ECB mode using the API private key, encoded as URL-safe base64 and
+//! followed by `~~~` and an HMAC-SHA256 signature of the encrypted payload.
+//!
+//! AES in ECB mode does not authenticate the payload, so the signature is required by
+//! [`decrypt`] and [`parse_event`]. Payloads without a signature can only be read with
+//! [`decrypt_unverified`].
+//!
+//! Payloads are deserialized into [`PianoWebhookEvent`], which has one variant per
+//! event type. Fields of the event payloads are all optional, as Piano omits the
+//! fields which do not apply to an event.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use piano_handwritten_api::webhook::{self, PianoWebhookEvent};
+//!
+//! # fn example(data: &str) -> Result<(), piano_handwritten_api::Error> {
+//! // `data` is the `data` query parameter of the webhook request
+//! let event = webhook::parse_event("your_private_key", data)?;
+//! match event {
+//!     PianoWebhookEvent::AccessGranted(event) => println!("access granted to {:?}", event.uid),
+//!     PianoWebhookEvent::PaymentRefund(event) => println!("refund of {:?}", event.amount),
+//!     other => println!("ignored {} event", other.event_type()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+mod schema;
+pub use self::schema::*;
+
+use std::io::ErrorKind;
+
+use aes::{
+    cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyInit},
+    Aes256,
+};
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Separator between the encrypted payload and its signature
+const SIGNATURE_DELIMITER: &str = "~~~";
+
+/// URL-safe base64, with or without padding
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Decrypt the `data` parameter of a webhook with the API private key.
+///
+/// The signature is verified before decryption and an [`ErrorKind::InvalidData`] error is
+/// returned if the payload is not signed or if the signature does not match.
+pub fn decrypt(private_key: &str, data: &str) -> Result<String, crate::Error> {
+    let (encrypted, signature) = data
+        .rsplit_once(SIGNATURE_DELIMITER)
+        .ok_or_else(|| invalid_data("webhook payload is not signed"))?;
+    verify_signature(private_key, encrypted, signature)?;
+    decrypt_payload(private_key, encrypted)
+}
+
+/// Decrypt the `data` parameter of a webhook without verifying its signature.
+///
+/// A signature following the payload is ignored. Without a signature, anyone who can
+/// reach the webhook endpoint can forge or alter payloads, so only use this for payloads
+/// which are known to be unsigned and which are authenticated by other means.
+pub fn decrypt_unverified(private_key: &str, data: &str) -> Result<String, crate::Error> {
+    let encrypted = data
+        .rsplit_once(SIGNATURE_DELIMITER)
+        .map_or(data, |(encrypted, _)| encrypted);
+    decrypt_payload(private_key, encrypted)
+}
+
+fn decrypt_payload(private_key: &str, encrypted: &str) -> Result<String, crate::Error> {
+    let mut ciphertext = BASE64.decode(encrypted.trim()).map_err(invalid_data)?;
+    let plaintext = ecb::Decryptor::<Aes256>::new(&cipher_key(private_key).into())
+        .decrypt_padded_mut::<Pkcs7>(&mut ciphertext)
+        .map_err(|_| invalid_data("unable to decrypt webhook payload"))?;
+    String::from_utf8(plaintext.to_vec()).map_err(invalid_data)
+}
+
+/// Decrypt the `data` parameter of a webhook and parse it into an event.
+///
+/// The payload must be signed, see [`decrypt`].
+pub fn parse_event(private_key: &str, data: &str) -> Result<PianoWebhookEvent, crate::Error> {
+    let payload = decrypt(private_key, data)?;
+    Ok(serde_json::from_str(&payload)?)
+}
+
+/// Decrypt the `data` parameter of a webhook and parse it into an event sent by the application.
+///
+/// Returns an [`ErrorKind::InvalidData`] error if the event carries the ID of another application.
+pub fn parse_event_for_app(
+    app_id: &str,
+    private_key: &str,
+    data: &str,
+) -> Result<PianoWebhookEvent, crate::Error> {
+    let event = parse_event(private_key, data)?;
+    match event.aid() {
+        Some(aid) if aid != app_id => Err(invalid_data(format!(
+            "webhook event was sent by application {aid}"
+        ))),
+        _ => Ok(event),
+    }
+}
+
+/// Piano uses the first 32 bytes of the private key, right-padded with `X`.
+fn cipher_key(private_key: &str) -> [u8; 32] {
+    let mut key = [b'X'; 32];
+    let bytes = private_key.as_bytes();
+    let len = bytes.len().min(32);
+    key[..len].copy_from_slice(&bytes[..len]);
+    key
+}
+
+fn verify_signature(
+    private_key: &str,
+    encrypted: &str,
+    signature: &str,
+) -> Result<(), crate::Error> {
+    let signature = BASE64.decode(signature.trim()).map_err(invalid_data)?;
+    let mut mac =
+        <Hmac<Sha256> as Mac>::new_from_slice(private_key.as_bytes()).map_err(invalid_data)?;
+    mac.update(encrypted.as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| invalid_data("webhook payload signature does not match"))
+}
+
+fn invalid_data<E>(error: E) -> crate::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    Box::new(std::io::Error::new(ErrorKind::InvalidData, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use aes::cipher::BlockEncryptMut;
+
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCD";
+
+    fn encrypt(private_key: &str, payload: &str) -> String {
+        let ciphertext = ecb::Encryptor::<Aes256>::new(&cipher_key(private_key).into())
+            .encrypt_padded_vec_mut::<Pkcs7>(payload.as_bytes());
+        BASE64.encode(ciphertext)
+    }
+
+    fn sign(private_key: &str, encrypted: &str) -> String {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(private_key.as_bytes()).expect("OK");
+        mac.update(encrypted.as_bytes());
+        format!(
+            "{encrypted}{SIGNATURE_DELIMITER}{}",
+            BASE64.encode(mac.finalize().into_bytes())
+        )
+    }
+
+    #[test]
+    fn test_cipher_key() {
+        assert_eq!(
+            &cipher_key(PRIVATE_KEY),
+            b"0123456789abcdefghijklmnopqrstuv"
+        );
+        assert_eq!(&cipher_key("short"), b"shortXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    }
+
+    #[test]
+    fn test_decrypt() {
+        let payload = r#"{"type":"test","aid":"AID","timestamp":1700000000}"#;
+        let data = encrypt(PRIVATE_KEY, payload);
+        let signed = sign(PRIVATE_KEY, &data);
+        assert_eq!(decrypt(PRIVATE_KEY, &signed).expect("OK"), payload);
+
+        let event = parse_event_for_app("AID", PRIVATE_KEY, &signed).expect("OK");
+        assert_eq!(
+            event,
+            PianoWebhookEvent::Test(TestEvent {
+                aid: Some("AID".to_string()),
+                timestamp: Some(1700000000),
+            })
+        );
+        assert!(parse_event_for_app("OTHER", PRIVATE_KEY, &signed).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_rejects_invalid_payload() {
+        let data = encrypt(PRIVATE_KEY, r#"{"type":"test"}"#);
+        let signed = sign("another_private_key", &data);
+        assert!(decrypt(PRIVATE_KEY, &signed).is_err());
+        assert!(decrypt("another_private_key", &sign("another_private_key", &data)).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_rejects_unsigned_payload() {
+        let payload = r#"{"type":"test","aid":"AID"}"#;
+        let data = encrypt(PRIVATE_KEY, payload);
+        assert!(decrypt(PRIVATE_KEY, &data).is_err());
+        assert!(parse_event(PRIVATE_KEY, &data).is_err());
+        assert!(parse_event_for_app("AID", PRIVATE_KEY, &data).is_err());
+
+        assert_eq!(decrypt_unverified(PRIVATE_KEY, &data).expect("OK"), payload);
+        assert_eq!(
+            decrypt_unverified(PRIVATE_KEY, &sign(PRIVATE_KEY, &data)).expect("OK"),
+            payload
+        );
+    }
+}
@@ -0,0 +1,1482 @@
+use serde::{
+    de::{self, Deserializer},
+    Deserialize,
+};
+
+/// Payload of the `test` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct TestEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub timestamp: Option<i64>,
+}
+
+/// Payload of the `user_payment_method` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UserPaymentMethodEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_info_id: Option<String>,
+    #[serde(default)]
+    pub user_subscriptions: Vec<String>,
+}
+
+/// Payload of the `unsupported` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UnsupportedEvent {}
+
+/// Payload of the `payment_refund` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct PaymentRefundEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub amount: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+}
+
+/// Payload of the `address_updated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct AddressUpdatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub address_id: Option<String>,
+    #[serde(default)]
+    pub subscription_id: Vec<String>,
+    #[serde(default)]
+    pub voucher_id: Vec<String>,
+}
+
+/// Payload of the `term_change` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct TermChangeEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_term_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_subscription_rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_subscription_access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_term_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_subscription_rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_subscription_access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub new_subscription_next_bill_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub date_of_access_change: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub date_of_billing_change: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub upi_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub is_in_grace: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_length: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub failure_counter: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub passive_churn_logic_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub decline_reason: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub changed_by: Option<String>,
+}
+
+/// Payload of the `content_algorithm` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct KeyingEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub content_id: Option<String>,
+}
+
+/// Payload of the `term_changed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct TermChangedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_term_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_term_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub date_of_access_change: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub date_of_billing_change: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_billing_plan: Option<String>,
+}
+
+/// Payload of the `subscription_auto_renew_changed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct SubscriptionAutoRenewChangedByEndUserEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub auto_renew: Option<String>,
+}
+
+/// Payload of the `user_address_updated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UserAddressUpdatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, rename = "subId", deserialize_with = "de_opt_string")]
+    pub sub_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub psc_subscriber_number: Option<String>,
+}
+
+/// Payload of the `voucher_redeemed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct VoucherRedeemedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_state: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_send_date_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_message: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_code: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_expires_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_issue_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_redeem_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub address_id: Option<String>,
+}
+
+/// Payload of the `voucher_delivered` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct VoucherDeliveredEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_state: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_send_date_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_message: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_code: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_expires_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_issue_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_redeem_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub address_id: Option<String>,
+}
+
+/// Payload of the `voucher_revoked` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct VoucherRevokedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_state: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_send_date_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_message: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_code: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_expires_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_issue_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_redeem_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub address_id: Option<String>,
+}
+
+/// Payload of the `voucher_purchased` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct VoucherPurchasedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_state: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_send_date_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_recipient_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_message: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_code: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub voucher_expires_timestamp: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_issue_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub voucher_redeem_term_conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub address_id: Option<String>,
+}
+
+/// Payload of the `payment_pending` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct PaymentPendingEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub triggered_by: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub status: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status_value: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub amount: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_info_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_customer_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub resource_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_from: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_to: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub merchant_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_method_key: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub source_int: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub subscription_period_counter: Option<i64>,
+}
+
+/// Payload of the `payment_failed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct PaymentFailedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub triggered_by: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub status: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status_value: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub amount: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_info_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_customer_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub resource_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_from: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_to: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub merchant_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_method_key: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub source_int: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub error_code: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub error_message: Option<String>,
+}
+
+/// Payload of the `payment_initiated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct PaymentInitiatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub triggered_by: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub status: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status_value: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub amount: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_info_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_customer_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub resource_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_from: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_to: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub merchant_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_method_key: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub source_int: Option<i64>,
+}
+
+/// Payload of the `payment_completed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct PaymentCompletedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub triggered_by: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub status: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status_value: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_f64")]
+    pub amount: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_payment_info_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ext_customer_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub resource_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_from: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_period_date_to: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub merchant_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_method_key: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub source_int: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub subscription_period_counter: Option<i64>,
+}
+
+/// Payload of the `user_updated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UserUpdatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub timestamp: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub updated_custom_fields: Option<String>,
+}
+
+/// Payload of the `user_email_confirmed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UserEmailConfirmedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub timestamp: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `user_created` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UserCreatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub timestamp: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `user_disabled` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct UserDisabledEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub timestamp: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `contract_updated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractUpdatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+}
+
+/// Payload of the `contract_created` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractCreatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+}
+
+/// Payload of the `contract_user_created` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractUserCreatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `licensee_invite_to_contract` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractUserInvitedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `contract_deleted` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractDeletedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+}
+
+/// Payload of the `subscription_ended` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct SubscriptionEndedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_type: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub create_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub next_bill_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub upi_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub auto_renew: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub is_in_grace: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_length: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub failure_counter: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub passive_churn_logic_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub decline_reason: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub ended_by: Option<String>,
+}
+
+/// Payload of the `subscription_renewal` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct SubscriptionRenewalEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_type: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub create_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub next_bill_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub upi_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub auto_renew: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub is_in_grace: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_length: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub failure_counter: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub passive_churn_logic_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub decline_reason: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub renewal_type: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub renewed_by: Option<String>,
+}
+
+/// Payload of the `subscription_updated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct SubscriptionUpdatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_type: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub create_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub next_bill_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub upi_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub auto_renew: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub is_in_grace: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_length: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub failure_counter: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub passive_churn_logic_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub decline_reason: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub updated_by: Option<String>,
+}
+
+/// Payload of the `subscription_created` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct SubscriptionCreatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_type: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub create_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub next_bill_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_billing_plan: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub active_access_period_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub upi_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub auto_renew: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_bool")]
+    pub is_in_grace: Option<bool>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_start_date: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub grace_period_length: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub failure_counter: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub passive_churn_logic_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub decline_reason: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub created_by: Option<String>,
+}
+
+/// Payload of the `access_granted` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct AccessGrantedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub expires: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub conversion_id: Option<String>,
+}
+
+/// Payload of the `access_revoked` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct AccessRevokedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub expires: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+}
+
+/// Payload of the `access_modified` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct AccessModifiedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub expires: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub conversion_id: Option<String>,
+}
+
+/// Payload of the `licensee_created` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct LicenseeCreatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub licensee_id: Option<String>,
+}
+
+/// Payload of the `licensee_updated` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct LicenseeUpdatedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub licensee_id: Option<String>,
+}
+
+/// Payload of the `term_change_finished` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct TermChangeFinishedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_term_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_term_name: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub previous_subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_subscription_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub date_of_access_change: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub date_of_billing_change: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub new_billing_plan: Option<String>,
+}
+
+/// Payload of the `contract_user_access_expired` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractUserAccessExpiredEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `contract_user_access_revoked` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractUserAccessRevokedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+}
+
+/// Payload of the `contract_redeemed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractRedeemedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub expires: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+}
+
+/// Payload of the `contract_renewed` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct ContractRenewedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub expires: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+}
+
+/// Payload of the `shared_subscription_child` webhook event
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct SharedSubscriptionChildAccessGrantedEvent {
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub aid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_i64")]
+    pub expires: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub term_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub rid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub access_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub user_email: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub contract_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub payment_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub conversion_id: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub parent_uid: Option<String>,
+    #[serde(default, deserialize_with = "de_opt_string")]
+    pub parent_subscription_id: Option<String>,
+}
+/// Webhook event sent by Piano, keyed by its `type`
+///
+/// Events of a type unknown to this version of the SDK are kept as [`PianoWebhookEvent::Unknown`]
+/// along with their raw payload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PianoWebhookEvent {
+    Test(TestEvent),
+    UserPaymentMethod(UserPaymentMethodEvent),
+    Unsupported(UnsupportedEvent),
+    PaymentRefund(PaymentRefundEvent),
+    AddressUpdated(AddressUpdatedEvent),
+    TermChange(TermChangeEvent),
+    Keying(KeyingEvent),
+    TermChanged(TermChangedEvent),
+    SubscriptionAutoRenewChangedByEndUser(SubscriptionAutoRenewChangedByEndUserEvent),
+    UserAddressUpdated(UserAddressUpdatedEvent),
+    VoucherRedeemed(VoucherRedeemedEvent),
+    VoucherDelivered(VoucherDeliveredEvent),
+    VoucherRevoked(VoucherRevokedEvent),
+    VoucherPurchased(VoucherPurchasedEvent),
+    PaymentPending(PaymentPendingEvent),
+    PaymentFailed(PaymentFailedEvent),
+    PaymentInitiated(PaymentInitiatedEvent),
+    PaymentCompleted(PaymentCompletedEvent),
+    UserUpdated(UserUpdatedEvent),
+    UserEmailConfirmed(UserEmailConfirmedEvent),
+    UserCreated(UserCreatedEvent),
+    UserDisabled(UserDisabledEvent),
+    ContractUpdated(ContractUpdatedEvent),
+    ContractCreated(ContractCreatedEvent),
+    ContractUserCreated(ContractUserCreatedEvent),
+    ContractUserInvited(ContractUserInvitedEvent),
+    ContractDeleted(ContractDeletedEvent),
+    SubscriptionEnded(SubscriptionEndedEvent),
+    SubscriptionRenewal(SubscriptionRenewalEvent),
+    SubscriptionUpdated(SubscriptionUpdatedEvent),
+    SubscriptionCreated(SubscriptionCreatedEvent),
+    AccessGranted(AccessGrantedEvent),
+    AccessRevoked(AccessRevokedEvent),
+    AccessModified(AccessModifiedEvent),
+    LicenseeCreated(LicenseeCreatedEvent),
+    LicenseeUpdated(LicenseeUpdatedEvent),
+    TermChangeFinished(TermChangeFinishedEvent),
+    ContractUserAccessExpired(ContractUserAccessExpiredEvent),
+    ContractUserAccessRevoked(ContractUserAccessRevokedEvent),
+    ContractRedeemed(ContractRedeemedEvent),
+    ContractRenewed(ContractRenewedEvent),
+    SharedSubscriptionChildAccessGranted(SharedSubscriptionChildAccessGrantedEvent),
+    /// Event of an unknown type
+    Unknown {
+        /// The `type` of the event
+        event_type: String,
+        /// The raw payload of the event
+        payload: serde_json::Value,
+    },
+}
+
+impl PianoWebhookEvent {
+    /// Get the `type` of the event
+    pub fn event_type(&self) -> &str {
+        match self {
+            PianoWebhookEvent::Test(_) => "test",
+            PianoWebhookEvent::UserPaymentMethod(_) => "user_payment_method",
+            PianoWebhookEvent::Unsupported(_) => "unsupported",
+            PianoWebhookEvent::PaymentRefund(_) => "payment_refund",
+            PianoWebhookEvent::AddressUpdated(_) => "address_updated",
+            PianoWebhookEvent::TermChange(_) => "term_change",
+            PianoWebhookEvent::Keying(_) => "content_algorithm",
+            PianoWebhookEvent::TermChanged(_) => "term_changed",
+            PianoWebhookEvent::SubscriptionAutoRenewChangedByEndUser(_) => {
+                "subscription_auto_renew_changed"
+            }
+            PianoWebhookEvent::UserAddressUpdated(_) => "user_address_updated",
+            PianoWebhookEvent::VoucherRedeemed(_) => "voucher_redeemed",
+            PianoWebhookEvent::VoucherDelivered(_) => "voucher_delivered",
+            PianoWebhookEvent::VoucherRevoked(_) => "voucher_revoked",
+            PianoWebhookEvent::VoucherPurchased(_) => "voucher_purchased",
+            PianoWebhookEvent::PaymentPending(_) => "payment_pending",
+            PianoWebhookEvent::PaymentFailed(_) => "payment_failed",
+            PianoWebhookEvent::PaymentInitiated(_) => "payment_initiated",
+            PianoWebhookEvent::PaymentCompleted(_) => "payment_completed",
+            PianoWebhookEvent::UserUpdated(_) => "user_updated",
+            PianoWebhookEvent::UserEmailConfirmed(_) => "user_email_confirmed",
+            PianoWebhookEvent::UserCreated(_) => "user_created",
+            PianoWebhookEvent::UserDisabled(_) => "user_disabled",
+            PianoWebhookEvent::ContractUpdated(_) => "contract_updated",
+            PianoWebhookEvent::ContractCreated(_) => "contract_created",
+            PianoWebhookEvent::ContractUserCreated(_) => "contract_user_created",
+            PianoWebhookEvent::ContractUserInvited(_) => "licensee_invite_to_contract",
+            PianoWebhookEvent::ContractDeleted(_) => "contract_deleted",
+            PianoWebhookEvent::SubscriptionEnded(_) => "subscription_ended",
+            PianoWebhookEvent::SubscriptionRenewal(_) => "subscription_renewal",
+            PianoWebhookEvent::SubscriptionUpdated(_) => "subscription_updated",
+            PianoWebhookEvent::SubscriptionCreated(_) => "subscription_created",
+            PianoWebhookEvent::AccessGranted(_) => "access_granted",
+            PianoWebhookEvent::AccessRevoked(_) => "access_revoked",
+            PianoWebhookEvent::AccessModified(_) => "access_modified",
+            PianoWebhookEvent::LicenseeCreated(_) => "licensee_created",
+            PianoWebhookEvent::LicenseeUpdated(_) => "licensee_updated",
+            PianoWebhookEvent::TermChangeFinished(_) => "term_change_finished",
+            PianoWebhookEvent::ContractUserAccessExpired(_) => "contract_user_access_expired",
+            PianoWebhookEvent::ContractUserAccessRevoked(_) => "contract_user_access_revoked",
+            PianoWebhookEvent::ContractRedeemed(_) => "contract_redeemed",
+            PianoWebhookEvent::ContractRenewed(_) => "contract_renewed",
+            PianoWebhookEvent::SharedSubscriptionChildAccessGranted(_) => {
+                "shared_subscription_child"
+            }
+            PianoWebhookEvent::Unknown { event_type, .. } => event_type,
+        }
+    }
+
+    /// Get the ID of the application which sent the event
+    pub fn aid(&self) -> Option<&str> {
+        match self {
+            PianoWebhookEvent::Test(event) => event.aid.as_deref(),
+            PianoWebhookEvent::UserPaymentMethod(event) => event.aid.as_deref(),
+            PianoWebhookEvent::Unsupported(_) => None,
+            PianoWebhookEvent::PaymentRefund(event) => event.aid.as_deref(),
+            PianoWebhookEvent::AddressUpdated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::TermChange(event) => event.aid.as_deref(),
+            PianoWebhookEvent::Keying(event) => event.aid.as_deref(),
+            PianoWebhookEvent::TermChanged(event) => event.aid.as_deref(),
+            PianoWebhookEvent::SubscriptionAutoRenewChangedByEndUser(event) => event.aid.as_deref(),
+            PianoWebhookEvent::UserAddressUpdated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::VoucherRedeemed(event) => event.aid.as_deref(),
+            PianoWebhookEvent::VoucherDelivered(event) => event.aid.as_deref(),
+            PianoWebhookEvent::VoucherRevoked(event) => event.aid.as_deref(),
+            PianoWebhookEvent::VoucherPurchased(event) => event.aid.as_deref(),
+            PianoWebhookEvent::PaymentPending(event) => event.aid.as_deref(),
+            PianoWebhookEvent::PaymentFailed(event) => event.aid.as_deref(),
+            PianoWebhookEvent::PaymentInitiated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::PaymentCompleted(event) => event.aid.as_deref(),
+            PianoWebhookEvent::UserUpdated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::UserEmailConfirmed(event) => event.aid.as_deref(),
+            PianoWebhookEvent::UserCreated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::UserDisabled(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractUpdated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractCreated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractUserCreated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractUserInvited(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractDeleted(event) => event.aid.as_deref(),
+            PianoWebhookEvent::SubscriptionEnded(event) => event.aid.as_deref(),
+            PianoWebhookEvent::SubscriptionRenewal(event) => event.aid.as_deref(),
+            PianoWebhookEvent::SubscriptionUpdated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::SubscriptionCreated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::AccessGranted(event) => event.aid.as_deref(),
+            PianoWebhookEvent::AccessRevoked(event) => event.aid.as_deref(),
+            PianoWebhookEvent::AccessModified(event) => event.aid.as_deref(),
+            PianoWebhookEvent::LicenseeCreated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::LicenseeUpdated(event) => event.aid.as_deref(),
+            PianoWebhookEvent::TermChangeFinished(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractUserAccessExpired(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractUserAccessRevoked(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractRedeemed(event) => event.aid.as_deref(),
+            PianoWebhookEvent::ContractRenewed(event) => event.aid.as_deref(),
+            PianoWebhookEvent::SharedSubscriptionChildAccessGranted(event) => event.aid.as_deref(),
+            PianoWebhookEvent::Unknown { payload, .. } => payload.get("aid")?.as_str(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PianoWebhookEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let payload = serde_json::Value::deserialize(deserializer)?;
+        let event_type = payload
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let event = match event_type.as_str() {
+            "test" => serde_json::from_value(payload).map(PianoWebhookEvent::Test),
+            "user_payment_method" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::UserPaymentMethod)
+            }
+            "unsupported" => serde_json::from_value(payload).map(PianoWebhookEvent::Unsupported),
+            "payment_refund" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::PaymentRefund)
+            }
+            "address_updated" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::AddressUpdated)
+            }
+            "term_change" => serde_json::from_value(payload).map(PianoWebhookEvent::TermChange),
+            "content_algorithm" => serde_json::from_value(payload).map(PianoWebhookEvent::Keying),
+            "term_changed" => serde_json::from_value(payload).map(PianoWebhookEvent::TermChanged),
+            "subscription_auto_renew_changed" => serde_json::from_value(payload)
+                .map(PianoWebhookEvent::SubscriptionAutoRenewChangedByEndUser),
+            "user_address_updated" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::UserAddressUpdated)
+            }
+            "voucher_redeemed" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::VoucherRedeemed)
+            }
+            "voucher_delivered" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::VoucherDelivered)
+            }
+            "voucher_revoked" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::VoucherRevoked)
+            }
+            "voucher_purchased" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::VoucherPurchased)
+            }
+            "payment_pending" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::PaymentPending)
+            }
+            "payment_failed" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::PaymentFailed)
+            }
+            "payment_initiated" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::PaymentInitiated)
+            }
+            "payment_completed" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::PaymentCompleted)
+            }
+            "user_updated" => serde_json::from_value(payload).map(PianoWebhookEvent::UserUpdated),
+            "user_email_confirmed" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::UserEmailConfirmed)
+            }
+            "user_created" => serde_json::from_value(payload).map(PianoWebhookEvent::UserCreated),
+            "user_disabled" => serde_json::from_value(payload).map(PianoWebhookEvent::UserDisabled),
+            "contract_updated" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractUpdated)
+            }
+            "contract_created" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractCreated)
+            }
+            "contract_user_created" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractUserCreated)
+            }
+            "licensee_invite_to_contract" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractUserInvited)
+            }
+            "contract_deleted" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractDeleted)
+            }
+            "subscription_ended" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::SubscriptionEnded)
+            }
+            "subscription_renewal" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::SubscriptionRenewal)
+            }
+            "subscription_updated" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::SubscriptionUpdated)
+            }
+            "subscription_created" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::SubscriptionCreated)
+            }
+            "access_granted" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::AccessGranted)
+            }
+            "access_revoked" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::AccessRevoked)
+            }
+            "access_modified" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::AccessModified)
+            }
+            "licensee_created" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::LicenseeCreated)
+            }
+            "licensee_updated" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::LicenseeUpdated)
+            }
+            "term_change_finished" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::TermChangeFinished)
+            }
+            "contract_user_access_expired" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractUserAccessExpired)
+            }
+            "contract_user_access_revoked" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractUserAccessRevoked)
+            }
+            "contract_redeemed" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractRedeemed)
+            }
+            "contract_renewed" => {
+                serde_json::from_value(payload).map(PianoWebhookEvent::ContractRenewed)
+            }
+            "shared_subscription_child" => serde_json::from_value(payload)
+                .map(PianoWebhookEvent::SharedSubscriptionChildAccessGranted),
+            _ => {
+                return Ok(PianoWebhookEvent::Unknown {
+                    event_type,
+                    payload,
+                })
+            }
+        };
+        event.map_err(de::Error::custom)
+    }
+}
+
+/// Piano sends scalar values either as JSON scalars or as strings, so fields accept both.
+fn de_opt_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(s)) => Some(s),
+            Some(other) => Some(other.to_string()),
+        },
+    )
+}
+
+fn de_opt_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match de_opt_string(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse().map(Some).map_err(de::Error::custom),
+    }
+}
+
+fn de_opt_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match de_opt_string(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse().map(Some).map_err(de::Error::custom),
+    }
+}
+
+fn de_opt_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match de_opt_string(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some("true") | Some("TRUE") | Some("1") => Ok(Some(true)),
+        Some("false") | Some("FALSE") | Some("0") => Ok(Some(false)),
+        Some(s) => Err(de::Error::custom(format!("invalid boolean: {s}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_access_granted_event() {
+        let event: PianoWebhookEvent = serde_json::from_value(serde_json::json!({
+            "version": "2",
+            "type": "access_granted",
+            "event": "access_granted",
+            "aid": "AID",
+            "uid": "uid1",
+            "rid": "RID1",
+            "expires": "1700000000",
+            "access_id": "A1"
+        }))
+        .expect("OK");
+        assert_eq!(event.event_type(), "access_granted");
+        assert_eq!(event.aid(), Some("AID"));
+        let PianoWebhookEvent::AccessGranted(event) = event else {
+            panic!("unexpected event: {event:?}");
+        };
+        assert_eq!(event.uid.as_deref(), Some("uid1"));
+        assert_eq!(event.expires, Some(1700000000));
+    }
+
+    #[test]
+    fn test_deserialize_payment_refund_event() {
+        let event: PianoWebhookEvent = serde_json::from_value(serde_json::json!({
+            "type": "payment_refund",
+            "aid": "AID",
+            "uid": "uid1",
+            "payment_id": "UP1",
+            "amount": 9.99
+        }))
+        .expect("OK");
+        assert_eq!(
+            event,
+            PianoWebhookEvent::PaymentRefund(PaymentRefundEvent {
+                aid: Some("AID".to_string()),
+                uid: Some("uid1".to_string()),
+                payment_id: Some("UP1".to_string()),
+                amount: Some(9.99),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_unknown_event() {
+        let payload = serde_json::json!({
+            "type": "something_new",
+            "aid": "AID",
+            "foo": "bar"
+        });
+        let event: PianoWebhookEvent = serde_json::from_value(payload.clone()).expect("OK");
+        assert_eq!(event.event_type(), "something_new");
+        assert_eq!(event.aid(), Some("AID"));
+        assert_eq!(
+            event,
+            PianoWebhookEvent::Unknown {
+                event_type: "something_new".to_string(),
+                payload
+            }
+        );
+    }
+}